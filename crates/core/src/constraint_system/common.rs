@@ -0,0 +1,421 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{error::Error, pcs::BatchCommitScheme};
+use crate::{
+	challenger::CanSample,
+	oracle::{
+		BatchId, CommittedId, CompositePolyOracle, MultilinearOracleSet, MultilinearPolyOracle,
+	},
+	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
+		channel::{ChannelClaim, ChannelProof, Flush},
+		gkr_gpa::GrandProductBatchProof,
+		greedy_evalcheck::GreedyEvalcheckProof,
+		logup::{LogUpClaim, LogUpProof},
+		permcheck::{PermcheckClaim, PermcheckProof},
+		sumcheck::{SumcheckBatchProof, SumcheckClaim},
+		zerocheck::ZerocheckBatchProof,
+	},
+};
+use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, Field, TowerField};
+use std::marker::PhantomData;
+
+/// A complete statement about a set of multilinear polynomial oracles.
+///
+/// The constraint system consists of the oracle set, the composite polynomials that must vanish on
+/// the boolean hypercube, the composite polynomials with claimed hypercube sums, the relations
+/// between oracles, and a polynomial commitment scheme for every committed batch. The relations
+/// are channel flushes, copy constraints, and lookups. It is constructed with a
+/// [`ConstraintSystemBuilder`], which checks that the parts are consistent with each other.
+pub struct ConstraintSystem<U, F, FW, PCS>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: Field,
+{
+	oracles: MultilinearOracleSet<F>,
+	zerocheck_constraints: Vec<CompositePolyOracle<F>>,
+	zerocheck_constraint_names: Vec<Option<String>>,
+	sumcheck_claims: Vec<SumcheckClaim<F>>,
+	channel_claim: Option<ChannelClaim<F>>,
+	copy_constraints: Vec<PermcheckClaim<F>>,
+	lookups: Vec<LogUpClaim<F>>,
+	multiplicity_batch_ids: Vec<BatchId>,
+	commit_schemes: Vec<PCS>,
+	binding_order: BatchBindingOrder,
+	_marker: PhantomData<(U, FW)>,
+}
+
+impl<U, F, FW, PCS> ConstraintSystem<U, F, FW, PCS>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: Field,
+{
+	pub fn oracles(&self) -> &MultilinearOracleSet<F> {
+		&self.oracles
+	}
+
+	pub fn zerocheck_constraints(&self) -> &[CompositePolyOracle<F>] {
+		&self.zerocheck_constraints
+	}
+
+	/// Returns the name of the zerocheck constraint at the given index, if it was given one.
+	pub fn zerocheck_constraint_name(&self, index: usize) -> Option<&str> {
+		self.zerocheck_constraint_names.get(index)?.as_deref()
+	}
+
+	pub fn sumcheck_claims(&self) -> &[SumcheckClaim<F>] {
+		&self.sumcheck_claims
+	}
+
+	/// Returns the claim that all channels balance, if the system has any flushes.
+	pub fn channel_claim(&self) -> Option<&ChannelClaim<F>> {
+		self.channel_claim.as_ref()
+	}
+
+	pub fn copy_constraints(&self) -> &[PermcheckClaim<F>] {
+		&self.copy_constraints
+	}
+
+	pub fn lookups(&self) -> &[LogUpClaim<F>] {
+		&self.lookups
+	}
+
+	/// Returns the IDs of the batches that commit to lookup multiplicities, in increasing order.
+	///
+	/// These batches are committed after the lookup weighting challenges are sampled, while all
+	/// other batches are committed before.
+	pub fn multiplicity_batch_ids(&self) -> &[BatchId] {
+		&self.multiplicity_batch_ids
+	}
+
+	/// Returns the commitment schemes, indexed by batch ID.
+	pub fn commit_schemes(&self) -> &[PCS] {
+		&self.commit_schemes
+	}
+
+	/// Returns the order in which the batched zerocheck and sumcheck protocols bind the variables
+	/// of claims over fewer variables.
+	pub fn binding_order(&self) -> BatchBindingOrder {
+		self.binding_order
+	}
+}
+
+/// Builder for a [`ConstraintSystem`].
+pub struct ConstraintSystemBuilder<U, F, FW, PCS>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: Field,
+{
+	oracles: MultilinearOracleSet<F>,
+	zerocheck_constraints: Vec<CompositePolyOracle<F>>,
+	zerocheck_constraint_names: Vec<Option<String>>,
+	sumcheck_claims: Vec<SumcheckClaim<F>>,
+	flushes: Vec<Flush<F>>,
+	copy_constraints: Vec<PermcheckClaim<F>>,
+	lookups: Vec<LogUpClaim<F>>,
+	commit_schemes: Vec<Option<PCS>>,
+	binding_order: BatchBindingOrder,
+	_marker: PhantomData<(U, FW)>,
+}
+
+impl<U, F, FW, PCS> ConstraintSystemBuilder<U, F, FW, PCS>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: Field,
+	PCS: BatchCommitScheme<U, F, FW>,
+{
+	pub fn new(oracles: MultilinearOracleSet<F>) -> Self {
+		Self {
+			oracles,
+			zerocheck_constraints: Vec::new(),
+			zerocheck_constraint_names: Vec::new(),
+			sumcheck_claims: Vec::new(),
+			flushes: Vec::new(),
+			copy_constraints: Vec::new(),
+			lookups: Vec::new(),
+			commit_schemes: Vec::new(),
+			binding_order: BatchBindingOrder::default(),
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn oracles(&self) -> &MultilinearOracleSet<F> {
+		&self.oracles
+	}
+
+	/// Mutable access to the oracle set, for adding oracles after the builder is created.
+	pub fn oracles_mut(&mut self) -> &mut MultilinearOracleSet<F> {
+		&mut self.oracles
+	}
+
+	/// Constrain a composite polynomial to evaluate to zero everywhere on the boolean hypercube.
+	pub fn assert_zero(&mut self, constraint: CompositePolyOracle<F>) -> &mut Self {
+		self.zerocheck_constraints.push(constraint);
+		self.zerocheck_constraint_names.push(None);
+		self
+	}
+
+	/// Like [`Self::assert_zero`], with a name that identifies the constraint in debug reports.
+	pub fn assert_zero_named(
+		&mut self,
+		name: impl Into<String>,
+		constraint: CompositePolyOracle<F>,
+	) -> &mut Self {
+		self.zerocheck_constraints.push(constraint);
+		self.zerocheck_constraint_names.push(Some(name.into()));
+		self
+	}
+
+	/// Constrain a composite polynomial to sum to the claimed value over the boolean hypercube.
+	pub fn assert_sum(&mut self, claim: SumcheckClaim<F>) -> &mut Self {
+		self.sumcheck_claims.push(claim);
+		self
+	}
+
+	/// Push the rows of a tuple of oracles to a channel, or pull them from it.
+	///
+	/// Every channel of the system must balance, see [`crate::protocols::channel`].
+	pub fn flush(&mut self, flush: Flush<F>) -> &mut Self {
+		self.flushes.push(flush);
+		self
+	}
+
+	/// Constrain the cells of the columns to be invariant under the wiring permutation `sigma`.
+	///
+	/// The transparent wiring oracles are added to the oracle set. See
+	/// [`PermcheckClaim::new_in`] for the layout of `sigma`, which is usually obtained from a
+	/// [`CopyConstraints`](crate::protocols::permcheck::CopyConstraints).
+	///
+	/// ## Throws
+	///
+	/// * `Error::Permcheck` if the columns or `sigma` are invalid
+	pub fn assert_copy_constraints(
+		&mut self,
+		columns: Vec<MultilinearPolyOracle<F>>,
+		sigma: Vec<usize>,
+	) -> Result<&mut Self, Error> {
+		let claim = PermcheckClaim::new_in(&mut self.oracles, columns, sigma)?;
+		self.copy_constraints.push(claim);
+		Ok(self)
+	}
+
+	/// Constrain every hypercube evaluation of the witness columns of a logUp claim to occur in
+	/// its table.
+	///
+	/// The weighted multiplicities oracle of the claim must be committed in a batch of the top
+	/// tower level that holds nothing but lookup multiplicities. These batches are committed
+	/// after the lookup weighting challenges are sampled, see [`crate::protocols::logup`].
+	pub fn assert_lookup(&mut self, claim: LogUpClaim<F>) -> &mut Self {
+		self.lookups.push(claim);
+		self
+	}
+
+	/// Set the polynomial commitment scheme for a committed batch.
+	pub fn set_commit_scheme(
+		&mut self,
+		batch_id: BatchId,
+		scheme: PCS,
+	) -> Result<&mut Self, Error> {
+		if batch_id >= self.oracles.committed_batches().len() {
+			return Err(Error::UnknownBatch { batch_id });
+		}
+		if batch_id >= self.commit_schemes.len() {
+			self.commit_schemes.resize_with(batch_id + 1, || None);
+		}
+		if self.commit_schemes[batch_id].is_some() {
+			return Err(Error::DuplicateCommitScheme { batch_id });
+		}
+		self.commit_schemes[batch_id] = Some(scheme);
+		Ok(self)
+	}
+
+	/// Set the binding order of the batched zerocheck and sumcheck protocols, back-loaded by
+	/// default.
+	pub fn set_binding_order(&mut self, binding_order: BatchBindingOrder) -> &mut Self {
+		self.binding_order = binding_order;
+		self
+	}
+
+	/// Build the constraint system.
+	///
+	/// ## Throws
+	///
+	/// * `Error::NoConstraints` if there are no constraints or relations
+	/// * `Error::Channel` if flushes to the same channel have different arities
+	/// * `Error::MultiplicitiesNotCommitted`, `Error::SharedMultiplicities`,
+	///   `Error::MixedMultiplicitiesBatch` or `Error::MultiplicitiesTowerLevelMismatch` if the
+	///   lookup multiplicities are not committed in batches of their own
+	/// * `Error::MissingCommitScheme` if a committed batch has no commitment scheme
+	/// * `Error::CommitSchemeNumberOfVariablesMismatch` or `Error::CommitSchemeTowerLevelMismatch`
+	///   if a commitment scheme does not match the shape of its batch
+	pub fn build(self) -> Result<ConstraintSystem<U, F, FW, PCS>, Error> {
+		let Self {
+			oracles,
+			zerocheck_constraints,
+			zerocheck_constraint_names,
+			sumcheck_claims,
+			flushes,
+			copy_constraints,
+			lookups,
+			mut commit_schemes,
+			binding_order,
+			_marker,
+		} = self;
+
+		if zerocheck_constraints.is_empty()
+			&& sumcheck_claims.is_empty()
+			&& flushes.is_empty()
+			&& copy_constraints.is_empty()
+			&& lookups.is_empty()
+		{
+			return Err(Error::NoConstraints);
+		}
+
+		let channel_claim = if flushes.is_empty() {
+			None
+		} else {
+			Some(ChannelClaim::new(flushes)?)
+		};
+
+		let batches = oracles.committed_batches();
+		let multiplicity_batch_ids = multiplicity_batch_ids(&oracles, &lookups)?;
+
+		commit_schemes.resize_with(batches.len(), || None);
+		let commit_schemes = batches
+			.into_iter()
+			.zip(commit_schemes)
+			.map(|(batch, scheme)| {
+				let batch_id = batch.id;
+				let scheme = scheme.ok_or(Error::MissingCommitScheme { batch_id })?;
+				if scheme.n_vars() != batch.n_vars {
+					return Err(Error::CommitSchemeNumberOfVariablesMismatch {
+						batch_id,
+						expected: scheme.n_vars(),
+						actual: batch.n_vars,
+					});
+				}
+				if scheme.tower_level() != batch.tower_level {
+					return Err(Error::CommitSchemeTowerLevelMismatch {
+						batch_id,
+						expected: scheme.tower_level(),
+						actual: batch.tower_level,
+					});
+				}
+				Ok(scheme)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(ConstraintSystem {
+			oracles,
+			zerocheck_constraints,
+			zerocheck_constraint_names,
+			sumcheck_claims,
+			channel_claim,
+			copy_constraints,
+			lookups,
+			multiplicity_batch_ids,
+			commit_schemes,
+			binding_order,
+			_marker,
+		})
+	}
+}
+
+/// Find the batches of the lookup multiplicities and check that they hold nothing else.
+///
+/// The weighted multiplicities depend on challenges sampled after all other oracles are
+/// committed, so they must be committed in batches of their own.
+fn multiplicity_batch_ids<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	lookups: &[LogUpClaim<F>],
+) -> Result<Vec<BatchId>, Error> {
+	let batches = oracles.committed_batches();
+	let mut is_multiplicity = batches
+		.iter()
+		.map(|batch| vec![false; batch.n_polys])
+		.collect::<Vec<_>>();
+
+	for lookup in lookups {
+		let multiplicities = lookup.multiplicities();
+		let MultilinearPolyOracle::Committed {
+			id: CommittedId { batch_id, index },
+			..
+		} = multiplicities
+		else {
+			return Err(Error::MultiplicitiesNotCommitted {
+				id: multiplicities.id(),
+			});
+		};
+		if std::mem::replace(&mut is_multiplicity[*batch_id][*index], true) {
+			return Err(Error::SharedMultiplicities {
+				id: multiplicities.id(),
+			});
+		}
+	}
+
+	let mut batch_ids = Vec::new();
+	for (batch, is_multiplicity) in batches.iter().zip(is_multiplicity) {
+		if !is_multiplicity.contains(&true) {
+			continue;
+		}
+		if is_multiplicity.contains(&false) {
+			return Err(Error::MixedMultiplicitiesBatch { batch_id: batch.id });
+		}
+		if batch.tower_level != F::TOWER_LEVEL {
+			return Err(Error::MultiplicitiesTowerLevelMismatch {
+				batch_id: batch.id,
+				expected: F::TOWER_LEVEL,
+			});
+		}
+		batch_ids.push(batch.id);
+	}
+	Ok(batch_ids)
+}
+
+/// Sample the weighting challenges of every witness column of every lookup.
+pub(super) fn sample_lookup_betas<F, CH>(
+	lookups: &[LogUpClaim<F>],
+	challenger: &mut CH,
+) -> Vec<Vec<Vec<F>>>
+where
+	F: Field,
+	CH: CanSample<F>,
+{
+	lookups
+		.iter()
+		.map(|lookup| {
+			lookup
+				.witnesses()
+				.iter()
+				.map(|column| challenger.sample_vec(column.n_vars()))
+				.collect()
+		})
+		.collect()
+}
+
+/// A proof for a [`ConstraintSystem`].
+#[derive(Debug)]
+pub struct Proof<F: Field, Comm, PCSProof> {
+	/// Commitments to the committed batches, in batch ID order.
+	pub commitments: Vec<Comm>,
+	/// Grand products of the flushes, present iff the system has flushes.
+	pub channel_proof: Option<ChannelProof<F>>,
+	/// Grand products of the copy constraints, in the order they were added.
+	pub permcheck_proofs: Vec<PermcheckProof<F>>,
+	/// Batched proof of the grand products of the flushes and copy constraints, present iff the
+	/// system has any of them.
+	pub grand_product_proof: Option<GrandProductBatchProof<F>>,
+	/// Proofs of the lookups, in the order they were added.
+	pub logup_proofs: Vec<LogUpProof<F>>,
+	/// Batched zerocheck proof, present iff the system has zerocheck constraints.
+	pub zerocheck_proof: Option<ZerocheckBatchProof<F>>,
+	/// Batched sumcheck proof, present iff the system has sumcheck claims.
+	pub sumcheck_proof: Option<SumcheckBatchProof<F>>,
+	pub evalcheck_proof: GreedyEvalcheckProof<F>,
+	/// Evaluation proofs of the committed batches, in batch ID order.
+	pub opening_proofs: Vec<PCSProof>,
+}
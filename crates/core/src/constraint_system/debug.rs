@@ -0,0 +1,160 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{common::ConstraintSystem, error::Error};
+use crate::{
+	oracle::{OracleDisplay, OracleId},
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, Field, TowerField};
+use rayon::prelude::*;
+use std::fmt::{self, Debug, Display};
+use tracing::{debug_span, instrument};
+
+/// A hypercube vertex at which a zerocheck constraint does not evaluate to zero.
+#[derive(Debug, Clone)]
+pub struct FailingRow<F> {
+	/// Index of the vertex on the boolean hypercube.
+	pub index: usize,
+	/// Values of the constraint's inner oracles at the vertex, in composition variable order.
+	pub inner_values: Vec<F>,
+	/// Value of the constraint at the vertex.
+	pub value: F,
+}
+
+/// A zerocheck constraint that the witness does not satisfy.
+#[derive(Debug, Clone)]
+pub struct UnsatisfiedConstraint<F> {
+	/// Index of the constraint in [`ConstraintSystem::zerocheck_constraints`].
+	pub constraint_index: usize,
+	pub name: Option<String>,
+	pub inner_oracle_ids: Vec<OracleId>,
+	/// Names of the inner oracles, as labeled in the oracle set.
+	pub inner_oracle_names: Vec<Option<String>>,
+	/// Total number of hypercube vertices at which the constraint is violated.
+	pub n_failing_rows: usize,
+	/// The first failing rows, in increasing index order.
+	pub failing_rows: Vec<FailingRow<F>>,
+}
+
+impl<F: Debug> Display for UnsatisfiedConstraint<F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.name {
+			Some(name) => write!(f, "constraint \"{name}\"")?,
+			None => write!(f, "constraint #{}", self.constraint_index)?,
+		}
+		writeln!(f, " is violated on {} rows", self.n_failing_rows)?;
+		for row in self.failing_rows.iter() {
+			write!(f, "  row {}:", row.index)?;
+			for ((&id, name), value) in self
+				.inner_oracle_ids
+				.iter()
+				.zip(self.inner_oracle_names.iter())
+				.zip(row.inner_values.iter())
+			{
+				let oracle = OracleDisplay {
+					id,
+					name: name.as_deref(),
+				};
+				write!(f, " oracle {oracle} = {value:?},")?;
+			}
+			writeln!(f, " constraint = {:?}", row.value)?;
+		}
+		if self.n_failing_rows > self.failing_rows.len() {
+			writeln!(f, "  ... {} more", self.n_failing_rows - self.failing_rows.len())?;
+		}
+		Ok(())
+	}
+}
+
+/// Check that the witness satisfies every zerocheck constraint of the constraint system.
+///
+/// This evaluates each constraint at every vertex of the boolean hypercube, so it is only meant
+/// for debugging. For each violated constraint, the first `max_failing_rows` failing rows are
+/// reported along with the values of the inner oracles. Returns an empty vector if all
+/// constraints are satisfied.
+///
+/// ## Throws
+///
+/// * `Error::Witness` if the witness of an inner oracle of a constraint is missing
+#[instrument(skip_all, name = "constraint_system::check_constraints")]
+pub fn check_constraints<U, F, FW, PCS>(
+	constraint_system: &ConstraintSystem<U, F, FW, PCS>,
+	witness: &MultilinearExtensionIndex<U, FW>,
+	max_failing_rows: usize,
+) -> Result<Vec<UnsatisfiedConstraint<F>>, Error>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField + From<FW>,
+	FW: Field,
+{
+	let oracles = constraint_system.oracles();
+	let mut unsatisfied = Vec::new();
+	for (constraint_index, constraint) in
+		constraint_system.zerocheck_constraints().iter().enumerate()
+	{
+		let name = constraint_system.zerocheck_constraint_name(constraint_index);
+		let _span = debug_span!("check_constraint", constraint_index, name).entered();
+
+		let inner_oracle_ids = constraint
+			.inner_polys()
+			.iter()
+			.map(|oracle| oracle.id())
+			.collect::<Vec<_>>();
+		let multilinears = inner_oracle_ids
+			.iter()
+			.map(|&id| {
+				witness
+					.get_multilin_poly(id)
+					.map_err(|err| err.with_oracle_names(oracles))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		let composition = constraint.composition();
+
+		let evaluate_row = |index: usize| -> Result<(Vec<F>, F), Error> {
+			let inner_values = multilinears
+				.iter()
+				.map(|multilin| Ok(F::from(multilin.evaluate_on_hypercube(index)?)))
+				.collect::<Result<Vec<_>, Error>>()?;
+			let value = composition.evaluate_scalar(&inner_values)?;
+			Ok((inner_values, value))
+		};
+
+		let failing_indices = (0..1 << constraint.n_vars())
+			.into_par_iter()
+			.map(|index| {
+				let (_, value) = evaluate_row(index)?;
+				Ok((value != F::ZERO).then_some(index))
+			})
+			.filter_map(Result::transpose)
+			.collect::<Result<Vec<_>, Error>>()?;
+		if failing_indices.is_empty() {
+			continue;
+		}
+
+		let failing_rows = failing_indices
+			.iter()
+			.take(max_failing_rows)
+			.map(|&index| {
+				let (inner_values, value) = evaluate_row(index)?;
+				Ok(FailingRow {
+					index,
+					inner_values,
+					value,
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		unsatisfied.push(UnsatisfiedConstraint {
+			constraint_index,
+			name: name.map(String::from),
+			inner_oracle_names: inner_oracle_ids
+				.iter()
+				.map(|&id| oracles.name(id).map(String::from))
+				.collect(),
+			inner_oracle_ids,
+			n_failing_rows: failing_indices.len(),
+			failing_rows,
+		});
+	}
+	Ok(unsatisfied)
+}
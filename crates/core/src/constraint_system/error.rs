@@ -0,0 +1,100 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::{BatchId, Error as OracleError, OracleId},
+	polynomial::Error as PolynomialError,
+	protocols::{channel, gkr_gpa, greedy_evalcheck, logup, permcheck, sumcheck, zerocheck},
+	witness::Error as WitnessError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("committed batch {batch_id} has no polynomial commitment scheme")]
+	MissingCommitScheme { batch_id: BatchId },
+	#[error("committed batch {batch_id} already has a polynomial commitment scheme")]
+	DuplicateCommitScheme { batch_id: BatchId },
+	#[error("committed batch {batch_id} does not exist")]
+	UnknownBatch { batch_id: BatchId },
+	#[error(
+		"commitment scheme for batch {batch_id} expects {expected} variables, batch has {actual}"
+	)]
+	CommitSchemeNumberOfVariablesMismatch {
+		batch_id: BatchId,
+		expected: usize,
+		actual: usize,
+	},
+	#[error(
+		"commitment scheme for batch {batch_id} expects tower level {expected}, batch has {actual}"
+	)]
+	CommitSchemeTowerLevelMismatch {
+		batch_id: BatchId,
+		expected: usize,
+		actual: usize,
+	},
+	#[error("the constraint system has no constraints or relations")]
+	NoConstraints,
+	#[error("lookup multiplicities oracle {id} is not a committed oracle")]
+	MultiplicitiesNotCommitted { id: OracleId },
+	#[error("lookup multiplicities oracle {id} is shared by several lookups")]
+	SharedMultiplicities { id: OracleId },
+	#[error("committed batch {batch_id} mixes lookup multiplicities with other oracles")]
+	MixedMultiplicitiesBatch { batch_id: BatchId },
+	#[error(
+		"committed batch {batch_id} of lookup multiplicities must have tower level {expected}"
+	)]
+	MultiplicitiesTowerLevelMismatch { batch_id: BatchId, expected: usize },
+	#[error("no evaluation claim was produced for committed batch {batch_id}")]
+	MissingBatchEvalClaim { batch_id: BatchId },
+	#[error("proof contains {actual} batch commitments, expected {expected}")]
+	IncorrectNumberOfCommitments { expected: usize, actual: usize },
+	#[error("proof contains {actual} batch opening proofs, expected {expected}")]
+	IncorrectNumberOfOpeningProofs { expected: usize, actual: usize },
+	#[error("proof is missing the zerocheck proof")]
+	MissingZerocheckProof,
+	#[error("proof contains an unexpected zerocheck proof")]
+	ExtraZerocheckProof,
+	#[error("proof is missing the sumcheck proof")]
+	MissingSumcheckProof,
+	#[error("proof contains an unexpected sumcheck proof")]
+	ExtraSumcheckProof,
+	#[error("proof is missing the channel proof")]
+	MissingChannelProof,
+	#[error("proof contains an unexpected channel proof")]
+	ExtraChannelProof,
+	#[error("proof contains {actual} permutation check proofs, expected {expected}")]
+	IncorrectNumberOfPermcheckProofs { expected: usize, actual: usize },
+	#[error("proof is missing the grand product proof")]
+	MissingGrandProductProof,
+	#[error("proof contains an unexpected grand product proof")]
+	ExtraGrandProductProof,
+	#[error("proof contains {actual} lookup proofs, expected {expected}")]
+	IncorrectNumberOfLookupProofs { expected: usize, actual: usize },
+	#[error("{actual} lookup mappings were given, expected {expected}")]
+	IncorrectNumberOfLookupMappings { expected: usize, actual: usize },
+	#[error("the witness does not satisfy the constraint system:\n{report}")]
+	UnsatisfiedConstraints { report: String },
+	#[error("commitment scheme value belongs to a different scheme variant")]
+	CommitSchemeVariantMismatch,
+	#[error("polynomial commitment error: {0}")]
+	PolyCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
+	#[error("oracle error: {0}")]
+	Oracle(#[from] OracleError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("witness error: {0}")]
+	Witness(#[from] WitnessError),
+	#[error("zerocheck error: {0}")]
+	Zerocheck(#[from] zerocheck::Error),
+	#[error("sumcheck error: {0}")]
+	Sumcheck(#[from] sumcheck::Error),
+	#[error("greedy evalcheck error: {0}")]
+	GreedyEvalcheck(#[from] greedy_evalcheck::Error),
+	#[error("channel error: {0}")]
+	Channel(#[from] channel::Error),
+	#[error("permutation check error: {0}")]
+	Permcheck(#[from] permcheck::Error),
+	#[error("logUp error: {0}")]
+	LogUp(#[from] logup::Error),
+	#[error("grand product error: {0}")]
+	GrandProduct(#[from] gkr_gpa::Error),
+}
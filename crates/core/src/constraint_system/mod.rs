@@ -0,0 +1,38 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! A constraint system bundling multilinear oracles with the constraints on them.
+//!
+//! A [`ConstraintSystem`] collects a [`crate::oracle::MultilinearOracleSet`], the composite
+//! polynomials that must vanish on the boolean hypercube, the composite polynomials with claimed
+//! hypercube sums, the channel flushes, copy constraints and lookups relating the oracles, and the
+//! polynomial commitment scheme of every committed batch. The [`prove()`] and [`verify()`]
+//! functions run the complete protocol: batch commitment, the relation protocols, batched
+//! zerocheck and sumcheck, greedy evalcheck, and one commitment opening per batch.
+//!
+//! Lookups are proven with [logUp](crate::protocols::logup), whose weighted multiplicities depend
+//! on challenges sampled after the other batches are committed. They are therefore committed in
+//! separate batches, in a second commitment round before the relation challenges are sampled.
+//! Channel flushes and copy constraints are reduced to grand product claims, which are proven in
+//! one batch with the [GKR grand product argument](crate::protocols::gkr_gpa). The
+//! [Lasso](crate::protocols::lasso) protocol, whose prover derives committed count columns of its
+//! own, is not part of the constraint system and is run on its own.
+//!
+//! When building a circuit, [`check_constraints()`] reports the hypercube rows on which the
+//! witness violates a zerocheck constraint. The same check runs before proving when
+//! [`ProveOptions::debug_max_failing_rows`] is set.
+
+mod common;
+mod debug;
+mod error;
+mod pcs;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use common::*;
+pub use debug::*;
+pub use error::*;
+pub use pcs::*;
+pub use prove::*;
+pub use verify::*;
@@ -0,0 +1,277 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::error::Error;
+use crate::{
+	challenger::{CanObserve, CanSample, CanSampleBits},
+	oracle::OracleId,
+	poly_commit::PolyCommitScheme,
+	polynomial::MultilinearExtensionBorrowed,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	ExtensionField, Field, TowerField,
+};
+use either::Either;
+use std::marker::PhantomData;
+
+/// A polynomial commitment scheme for a single committed batch of a constraint system.
+///
+/// [`PolyCommitScheme`] is generic over the packed field of the committed polynomials, so
+/// batches of different tower levels need different scheme types. This trait fixes the witness
+/// representation instead, so that a constraint system can hold the schemes of all its batches.
+/// Schemes of different types are combined with [`Either`], which implements this trait for any
+/// two schemes that share the commitment type.
+pub trait BatchCommitScheme<U, F, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: Field,
+	FW: Field,
+{
+	/// The commitment, which is observed by the challenger.
+	type Commitment: Clone;
+	/// The prover state retained between committing and proving the evaluation.
+	type Committed;
+	type Proof;
+
+	/// Number of variables of the committed polynomials.
+	fn n_vars(&self) -> usize;
+
+	/// Binary tower level of the committed polynomials.
+	fn tower_level(&self) -> usize;
+
+	/// Commit to the polynomials with the given oracle IDs.
+	fn commit(
+		&self,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+	) -> Result<(Self::Commitment, Self::Committed), Error>;
+
+	/// Generate an evaluation proof for the committed polynomials at a *random* challenge point.
+	fn prove_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		committed: &Self::Committed,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+		query: &[F],
+	) -> Result<Self::Proof, Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>;
+
+	/// Verify an evaluation proof at a *random* challenge point.
+	fn verify_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		commitment: &Self::Commitment,
+		query: &[F],
+		proof: Self::Proof,
+		values: &[F],
+	) -> Result<(), Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>;
+
+	/// Return the byte-size of a proof for a batch of `n_polys` polynomials.
+	fn proof_size(&self, n_polys: usize) -> usize;
+}
+
+/// Adapts a [`PolyCommitScheme`] over polynomials in the tower field `FS` to a
+/// [`BatchCommitScheme`].
+#[derive(Debug)]
+pub struct TowerBatchCommitScheme<FS, PCS> {
+	pcs: PCS,
+	_marker: PhantomData<FS>,
+}
+
+impl<FS, PCS> TowerBatchCommitScheme<FS, PCS> {
+	pub fn new(pcs: PCS) -> Self {
+		Self {
+			pcs,
+			_marker: PhantomData,
+		}
+	}
+
+	pub fn pcs(&self) -> &PCS {
+		&self.pcs
+	}
+}
+
+impl<U, F, FW, FS, PCS> BatchCommitScheme<U, F, FW> for TowerBatchCommitScheme<FS, PCS>
+where
+	U: UnderlierType + PackScalar<FW> + PackScalar<FS>,
+	F: ExtensionField<FS>,
+	FW: ExtensionField<FS>,
+	FS: TowerField,
+	PCS: PolyCommitScheme<PackedType<U, FS>, F>,
+{
+	type Commitment = PCS::Commitment;
+	type Committed = PCS::Committed;
+	type Proof = PCS::Proof;
+
+	fn n_vars(&self) -> usize {
+		self.pcs.n_vars()
+	}
+
+	fn tower_level(&self) -> usize {
+		FS::TOWER_LEVEL
+	}
+
+	fn commit(
+		&self,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+	) -> Result<(PCS::Commitment, PCS::Committed), Error> {
+		let polys = batch_polys::<U, FW, FS>(witness, oracle_ids)?;
+		self.pcs
+			.commit(&polys)
+			.map_err(|err| Error::PolyCommit(Box::new(err)))
+	}
+
+	fn prove_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		committed: &PCS::Committed,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+		query: &[F],
+	) -> Result<PCS::Proof, Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>,
+	{
+		let polys = batch_polys::<U, FW, FS>(witness, oracle_ids)?;
+		self.pcs
+			.prove_evaluation(challenger, committed, &polys, query)
+			.map_err(|err| Error::PolyCommit(Box::new(err)))
+	}
+
+	fn verify_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		commitment: &PCS::Commitment,
+		query: &[F],
+		proof: PCS::Proof,
+		values: &[F],
+	) -> Result<(), Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>,
+	{
+		self.pcs
+			.verify_evaluation(challenger, commitment, query, proof, values)
+			.map_err(|err| Error::PolyCommit(Box::new(err)))
+	}
+
+	fn proof_size(&self, n_polys: usize) -> usize {
+		self.pcs.proof_size(n_polys)
+	}
+}
+
+impl<U, F, FW, L, R> BatchCommitScheme<U, F, FW> for Either<L, R>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: Field,
+	FW: Field,
+	L: BatchCommitScheme<U, F, FW>,
+	R: BatchCommitScheme<U, F, FW, Commitment = L::Commitment>,
+{
+	type Commitment = L::Commitment;
+	type Committed = Either<L::Committed, R::Committed>;
+	type Proof = Either<L::Proof, R::Proof>;
+
+	fn n_vars(&self) -> usize {
+		match self {
+			Either::Left(scheme) => scheme.n_vars(),
+			Either::Right(scheme) => scheme.n_vars(),
+		}
+	}
+
+	fn tower_level(&self) -> usize {
+		match self {
+			Either::Left(scheme) => scheme.tower_level(),
+			Either::Right(scheme) => scheme.tower_level(),
+		}
+	}
+
+	fn commit(
+		&self,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+	) -> Result<(Self::Commitment, Self::Committed), Error> {
+		match self {
+			Either::Left(scheme) => {
+				let (commitment, committed) = scheme.commit(witness, oracle_ids)?;
+				Ok((commitment, Either::Left(committed)))
+			}
+			Either::Right(scheme) => {
+				let (commitment, committed) = scheme.commit(witness, oracle_ids)?;
+				Ok((commitment, Either::Right(committed)))
+			}
+		}
+	}
+
+	fn prove_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		committed: &Self::Committed,
+		witness: &MultilinearExtensionIndex<U, FW>,
+		oracle_ids: &[OracleId],
+		query: &[F],
+	) -> Result<Self::Proof, Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>,
+	{
+		match (self, committed) {
+			(Either::Left(scheme), Either::Left(committed)) => scheme
+				.prove_evaluation(challenger, committed, witness, oracle_ids, query)
+				.map(Either::Left),
+			(Either::Right(scheme), Either::Right(committed)) => scheme
+				.prove_evaluation(challenger, committed, witness, oracle_ids, query)
+				.map(Either::Right),
+			_ => Err(Error::CommitSchemeVariantMismatch),
+		}
+	}
+
+	fn verify_evaluation<CH>(
+		&self,
+		challenger: &mut CH,
+		commitment: &Self::Commitment,
+		query: &[F],
+		proof: Self::Proof,
+		values: &[F],
+	) -> Result<(), Error>
+	where
+		CH: CanObserve<F> + CanSample<F> + CanSampleBits<usize>,
+	{
+		match (self, proof) {
+			(Either::Left(scheme), Either::Left(proof)) => {
+				scheme.verify_evaluation(challenger, commitment, query, proof, values)
+			}
+			(Either::Right(scheme), Either::Right(proof)) => {
+				scheme.verify_evaluation(challenger, commitment, query, proof, values)
+			}
+			_ => Err(Error::CommitSchemeVariantMismatch),
+		}
+	}
+
+	fn proof_size(&self, n_polys: usize) -> usize {
+		match self {
+			Either::Left(scheme) => scheme.proof_size(n_polys),
+			Either::Right(scheme) => scheme.proof_size(n_polys),
+		}
+	}
+}
+
+fn batch_polys<'a, U, FW, FS>(
+	witness: &'a MultilinearExtensionIndex<U, FW>,
+	oracle_ids: &[OracleId],
+) -> Result<Vec<MultilinearExtensionBorrowed<'a, PackedType<U, FS>>>, Error>
+where
+	U: UnderlierType + PackScalar<FW> + PackScalar<FS>,
+	FW: ExtensionField<FS>,
+	FS: TowerField,
+{
+	oracle_ids
+		.iter()
+		.map(|&oracle_id| Ok(witness.get::<FS>(oracle_id)?))
+		.collect()
+}
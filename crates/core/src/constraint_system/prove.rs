@@ -0,0 +1,544 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	common::{sample_lookup_betas, ConstraintSystem, Proof},
+	debug::check_constraints,
+	error::Error,
+	pcs::BatchCommitScheme,
+};
+use crate::{
+	challenger::{CanObserve, CanSample, CanSampleBits},
+	oracle::{BatchId, CompositePolyOracle, MultilinearOracleSet, OracleId},
+	polynomial::{
+		composition::IsomorphicComposition, EvaluationDomainFactory, MultilinearComposite,
+	},
+	protocols::{
+		channel::{self, ChannelProveOutput, ChannelWitness, FlushMultiplicity, FlushWitness},
+		gkr_gpa::{self, GrandProductBatchProveOutput},
+		greedy_evalcheck::{self, GreedyEvalcheckProveOutput},
+		logup::{self, LogUpProveOutput, LogUpWitness},
+		permcheck::{self, PermcheckProveOutput, PermcheckWitness},
+		sumcheck::{self, SumcheckBatchProveOutput, SumcheckProver},
+		zerocheck::{self, ZerocheckBatchProveOutput, ZerocheckClaim, ZerocheckProver},
+	},
+	witness::{
+		CanonicalTowerExtension, MultilinearExtensionIndex, MultilinearWitness,
+		MultilinearWitnessIndex, TowerUnderlier,
+	},
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, PackedField, PackedFieldIndexable, TowerField,
+};
+use tracing::instrument;
+
+type CompositeWitness<'a, U, F, FW> = MultilinearComposite<
+	PackedType<U, FW>,
+	IsomorphicComposition<F>,
+	MultilinearWitness<'a, PackedType<U, FW>>,
+>;
+
+/// Options for [`prove_with_options()`].
+#[derive(Debug, Clone, Default)]
+pub struct ProveOptions {
+	/// Enables the debug-checking mode.
+	///
+	/// If set, the prover first checks that the witness satisfies every zerocheck constraint with
+	/// [`check_constraints()`]. On failure it returns `Error::UnsatisfiedConstraints` with a report
+	/// of at most this many failing rows per constraint, instead of producing an invalid proof.
+	pub debug_max_failing_rows: Option<usize>,
+}
+
+/// Prove that the witness satisfies the constraint system.
+///
+/// The witness must contain the multilinear extensions of all committed oracles, in their batch
+/// tower fields, as well as of all other oracles that the constraints and relations refer to
+/// directly, except for the lookup multiplicities. Those are computed by the prover from
+/// `lookup_mappings`, which holds for every lookup, in the order they were added, and for each of
+/// its witness columns the table index of every row.
+///
+/// The prover commits to all batches but the lookup multiplicity batches, samples the lookup
+/// weighting challenges, and commits to the weighted multiplicities. It then samples the relation
+/// challenges and reduces the flushes and copy constraints to grand product claims, which are
+/// proven in one batch, and proves the lookups. Next it runs the batched zerocheck and sumcheck
+/// protocols over the constraints, reduces all resulting evaluation claims with the greedy
+/// evalcheck protocol, and finally proves one opening per committed batch.
+///
+/// `switchover_fn` is passed to the sumcheck-based provers; see
+/// [`ZerocheckProver::new`] for details.
+///
+/// This is [`prove_with_options()`] with the default [`ProveOptions`].
+pub fn prove<U, F, FW, DomainField, PCS, CH>(
+	constraint_system: &ConstraintSystem<U, F, FW, PCS>,
+	witness: MultilinearExtensionIndex<U, FW>,
+	lookup_mappings: &[Vec<Vec<usize>>],
+	challenger: CH,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+) -> Result<Proof<F, PCS::Commitment, PCS::Proof>, Error>
+where
+	U: TowerUnderlier + PackScalar<FW>,
+	F: TowerField + From<FW>,
+	FW: CanonicalTowerExtension + From<F> + ExtensionField<DomainField>,
+	PackedType<U, FW>: PackedFieldIndexable,
+	DomainField: TowerField,
+	PCS: BatchCommitScheme<U, F, FW>,
+	CH: CanObserve<F> + CanObserve<PCS::Commitment> + CanSample<F> + CanSampleBits<usize>,
+{
+	prove_with_options(
+		constraint_system,
+		witness,
+		lookup_mappings,
+		challenger,
+		domain_factory,
+		switchover_fn,
+		ProveOptions::default(),
+	)
+}
+
+/// Prove that the witness satisfies the constraint system, with additional prover options.
+///
+/// See [`prove()`] for the requirements on the witness.
+///
+/// ## Throws
+///
+/// * `Error::IncorrectNumberOfLookupMappings` if `lookup_mappings` does not have one entry per
+///   lookup
+/// * `Error::UnsatisfiedConstraints` if debug checks are enabled in `options` and the witness
+///   violates a zerocheck constraint
+#[instrument(skip_all, name = "constraint_system::prove")]
+pub fn prove_with_options<U, F, FW, DomainField, PCS, CH>(
+	constraint_system: &ConstraintSystem<U, F, FW, PCS>,
+	witness: MultilinearExtensionIndex<U, FW>,
+	lookup_mappings: &[Vec<Vec<usize>>],
+	mut challenger: CH,
+	domain_factory: impl EvaluationDomainFactory<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	options: ProveOptions,
+) -> Result<Proof<F, PCS::Commitment, PCS::Proof>, Error>
+where
+	U: TowerUnderlier + PackScalar<FW>,
+	F: TowerField + From<FW>,
+	FW: CanonicalTowerExtension + From<F> + ExtensionField<DomainField>,
+	PackedType<U, FW>: PackedFieldIndexable,
+	DomainField: TowerField,
+	PCS: BatchCommitScheme<U, F, FW>,
+	CH: CanObserve<F> + CanObserve<PCS::Commitment> + CanSample<F> + CanSampleBits<usize>,
+{
+	let lookups = constraint_system.lookups();
+	if lookup_mappings.len() != lookups.len() {
+		return Err(Error::IncorrectNumberOfLookupMappings {
+			expected: lookups.len(),
+			actual: lookup_mappings.len(),
+		});
+	}
+
+	if let Some(max_failing_rows) = options.debug_max_failing_rows {
+		let unsatisfied = check_constraints(constraint_system, &witness, max_failing_rows)?;
+		if !unsatisfied.is_empty() {
+			let report = unsatisfied
+				.iter()
+				.map(|constraint| constraint.to_string())
+				.collect::<String>();
+			return Err(Error::UnsatisfiedConstraints { report });
+		}
+	}
+
+	let mut oracles = constraint_system.oracles().clone();
+	let commit_schemes = constraint_system.commit_schemes();
+	let binding_order = constraint_system.binding_order();
+	let batch_oracle_ids = (0..commit_schemes.len())
+		.map(|batch_id| oracles.committed_oracle_ids(batch_id).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let (multiplicity_batch_ids, trace_batch_ids): (Vec<_>, Vec<_>) = (0..commit_schemes.len())
+		.partition(|batch_id| {
+			constraint_system
+				.multiplicity_batch_ids()
+				.contains(batch_id)
+		});
+	let mut batch_commitments = Vec::with_capacity(commit_schemes.len());
+	batch_commitments.resize_with(commit_schemes.len(), || None);
+
+	// Commit to all batches except the lookup multiplicities
+	commit_batches(
+		commit_schemes,
+		&trace_batch_ids,
+		&batch_oracle_ids,
+		&witness,
+		&mut batch_commitments,
+		&mut challenger,
+	)?;
+
+	// Compute the weighted lookup multiplicities for the weighting challenges and commit to them
+	let lookup_betas = sample_lookup_betas(lookups, &mut challenger);
+	let logup_witnesses = lookups
+		.iter()
+		.zip(lookup_mappings)
+		.map(|(lookup, mappings)| {
+			let table = scalar_witness(constraint_system, &witness, lookup.table().id())?;
+			let columns = lookup
+				.witnesses()
+				.iter()
+				.map(|column| scalar_witness(constraint_system, &witness, column.id()))
+				.collect::<Result<Vec<_>, _>>()?;
+			let mappings = mappings.iter().map(Vec::as_slice).collect::<Vec<_>>();
+			Ok(LogUpWitness::new(table, columns, mappings)?)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let multiplicities = lookups
+		.iter()
+		.zip(&logup_witnesses)
+		.zip(&lookup_betas)
+		.map(|((lookup, logup_witness), betas)| {
+			let betas = betas
+				.iter()
+				.map(|beta| beta.iter().map(|&x| FW::from(x)).collect())
+				.collect::<Vec<Vec<_>>>();
+			let multiplicities = logup_witness.weighted_multiplicities(&betas)?;
+			let underliers = multiplicities
+				.evals()
+				.chunks(PackedType::<U, FW>::WIDTH)
+				.map(|chunk| {
+					PackedType::<U, FW>::from_scalars(chunk.iter().copied()).to_underlier()
+				})
+				.collect::<Vec<_>>();
+			Ok((lookup.multiplicities().id(), underliers))
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+	let witness = witness.update_owned::<FW, _>(multiplicities)?;
+
+	commit_batches(
+		commit_schemes,
+		&multiplicity_batch_ids,
+		&batch_oracle_ids,
+		&witness,
+		&mut batch_commitments,
+		&mut challenger,
+	)?;
+
+	let (commitments, committed): (Vec<_>, Vec<_>) = batch_commitments
+		.into_iter()
+		.map(|commitment| commitment.expect("all batches are committed above"))
+		.unzip();
+
+	// Sample the relation challenges once all batches are committed
+	let gamma = challenger.sample();
+	let alpha = challenger.sample();
+
+	let mut evalcheck_claims = Vec::new();
+
+	// Reduce the flushes and copy constraints to grand product claims. Greedy evalcheck reduces
+	// claims on the linear combination oracles added by the reductions to claims on their inner
+	// oracles, so their witnesses need not be kept.
+	let mut relation_witness_index = MultilinearWitnessIndex::new();
+	let mut grand_product_claims = Vec::new();
+	let mut grand_product_witnesses = Vec::new();
+
+	let channel_proof = match constraint_system.channel_claim() {
+		None => None,
+		Some(channel_claim) => {
+			let flush_witnesses = channel_claim
+				.flushes()
+				.iter()
+				.map(|flush| {
+					let columns = flush
+						.oracles()
+						.iter()
+						.map(|oracle| scalar_witness(constraint_system, &witness, oracle.id()))
+						.collect::<Result<Vec<_>, _>>()?;
+					let multiplicity_bits = match flush.multiplicity() {
+						FlushMultiplicity::Constant(_) => Vec::new(),
+						FlushMultiplicity::Bits(bits) => bits
+							.iter()
+							.map(|bit| scalar_witness(constraint_system, &witness, bit.id()))
+							.collect::<Result<Vec<_>, _>>()?,
+					};
+					Ok(FlushWitness::new(columns, multiplicity_bits)?)
+				})
+				.collect::<Result<Vec<_>, Error>>()?;
+
+			let ChannelProveOutput {
+				proof,
+				grand_product_claims: channel_claims,
+				grand_product_witnesses: channel_witnesses,
+			} = channel::prove(
+				&mut oracles,
+				&mut relation_witness_index,
+				channel_claim,
+				ChannelWitness::new(flush_witnesses),
+				gamma,
+				alpha,
+			)?;
+
+			challenger.observe_slice(&proof.products);
+			grand_product_claims.extend(channel_claims);
+			grand_product_witnesses.extend(channel_witnesses);
+			Some(proof)
+		}
+	};
+
+	let permcheck_proofs = constraint_system
+		.copy_constraints()
+		.iter()
+		.map(|claim| {
+			let columns = claim
+				.columns()
+				.iter()
+				.map(|column| scalar_witness(constraint_system, &witness, column.id()))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let PermcheckProveOutput {
+				proof,
+				grand_product_claims: permcheck_claims,
+				grand_product_witnesses: permcheck_witnesses,
+			} = permcheck::prove(
+				&mut oracles,
+				&mut relation_witness_index,
+				claim,
+				PermcheckWitness::new(columns)?,
+				gamma,
+				alpha,
+			)?;
+
+			challenger.observe_slice(&proof.identity_products);
+			challenger.observe_slice(&proof.sigma_products);
+			grand_product_claims.extend(permcheck_claims);
+			grand_product_witnesses.extend(permcheck_witnesses);
+			Ok(proof)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let grand_product_proof = if grand_product_claims.is_empty() {
+		None
+	} else {
+		let GrandProductBatchProveOutput {
+			evalcheck_claims: grand_product_evalcheck_claims,
+			proof,
+		} = gkr_gpa::batch_prove::<_, _, DomainField, _>(
+			grand_product_witnesses,
+			grand_product_claims,
+			domain_factory.clone(),
+			switchover_fn.clone(),
+			&mut challenger,
+		)?;
+
+		evalcheck_claims.extend(grand_product_evalcheck_claims);
+		Some(proof)
+	};
+
+	// Prove the lookups
+	let logup_proofs = lookups
+		.iter()
+		.zip(&logup_witnesses)
+		.zip(&lookup_betas)
+		.map(|((lookup, logup_witness), betas)| {
+			let LogUpProveOutput {
+				evalcheck_claims: logup_evalcheck_claims,
+				proof,
+			} = logup::prove::<_, _, DomainField, _, _>(
+				lookup,
+				logup_witness,
+				betas,
+				gamma,
+				domain_factory.clone(),
+				switchover_fn.clone(),
+				&mut challenger,
+			)?;
+
+			evalcheck_claims.extend(logup_evalcheck_claims);
+			Ok(proof)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	// Prove the zerocheck constraints
+	let zerocheck_constraints = constraint_system.zerocheck_constraints();
+	let zerocheck_proof = if zerocheck_constraints.is_empty() {
+		None
+	} else {
+		let max_n_vars = zerocheck_constraints
+			.iter()
+			.map(|constraint| constraint.n_vars())
+			.max()
+			.unwrap_or(0);
+		let zc_challenges = challenger.sample_vec(max_n_vars.saturating_sub(1));
+
+		let domains = zerocheck_constraints
+			.iter()
+			.map(|constraint| domain_factory.create(constraint.max_individual_degree() + 1))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let provers = zerocheck_constraints
+			.iter()
+			.zip(&domains)
+			.map(|(constraint, domain)| {
+				let prover = ZerocheckProver::new(
+					domain,
+					ZerocheckClaim {
+						poly: constraint.clone(),
+					},
+					make_composite_witness(&oracles, constraint, &witness)?,
+					&zc_challenges,
+					binding_order,
+					switchover_fn.clone(),
+				)?;
+				Ok(prover)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let ZerocheckBatchProveOutput {
+			evalcheck_claims: zerocheck_evalcheck_claims,
+			proof,
+		} = zerocheck::batch_prove(provers, binding_order, &mut challenger)?;
+
+		evalcheck_claims.extend(zerocheck_evalcheck_claims);
+		Some(proof)
+	};
+
+	// Prove the sumcheck claims
+	let sumcheck_claims = constraint_system.sumcheck_claims();
+	let sumcheck_proof = if sumcheck_claims.is_empty() {
+		None
+	} else {
+		let domains = sumcheck_claims
+			.iter()
+			.map(|claim| domain_factory.create(claim.poly.max_individual_degree() + 1))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let provers = sumcheck_claims
+			.iter()
+			.zip(&domains)
+			.map(|(claim, domain)| {
+				let prover = SumcheckProver::new(
+					domain,
+					claim.clone(),
+					make_composite_witness(&oracles, &claim.poly, &witness)?,
+					switchover_fn.clone(),
+				)?;
+				Ok(prover)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let SumcheckBatchProveOutput {
+			evalcheck_claims: sumcheck_evalcheck_claims,
+			proof,
+		} = sumcheck::batch_prove(provers, binding_order, &mut challenger)?;
+
+		evalcheck_claims.extend(sumcheck_evalcheck_claims);
+		Some(proof)
+	};
+
+	// Reduce all evaluation claims to one claim per committed batch
+	let mut witness_index = witness.witness_index();
+	let GreedyEvalcheckProveOutput {
+		same_query_claims,
+		proof: evalcheck_proof,
+	} = greedy_evalcheck::prove::<_, _, DomainField, _>(
+		&mut oracles,
+		&mut witness_index,
+		evalcheck_claims,
+		switchover_fn,
+		&mut challenger,
+		domain_factory,
+	)?;
+
+	// Prove the commitment openings
+	let opening_proofs = commit_schemes
+		.iter()
+		.zip(batch_oracle_ids.iter().zip(committed.iter()))
+		.enumerate()
+		.map(|(batch_id, (scheme, (oracle_ids, committed)))| {
+			let (_, same_query_claim) = same_query_claims
+				.iter()
+				.find(|(id, _)| *id == batch_id)
+				.ok_or(Error::MissingBatchEvalClaim { batch_id })?;
+			scheme.prove_evaluation(
+				&mut challenger,
+				committed,
+				&witness,
+				oracle_ids,
+				&same_query_claim.eval_point,
+			)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	Ok(Proof {
+		commitments,
+		channel_proof,
+		permcheck_proofs,
+		grand_product_proof,
+		logup_proofs,
+		zerocheck_proof,
+		sumcheck_proof,
+		evalcheck_proof,
+		opening_proofs,
+	})
+}
+
+/// Commit to the given batches and observe the commitments.
+fn commit_batches<U, F, FW, PCS, CH>(
+	commit_schemes: &[PCS],
+	batch_ids: &[BatchId],
+	batch_oracle_ids: &[Vec<OracleId>],
+	witness: &MultilinearExtensionIndex<U, FW>,
+	batch_commitments: &mut [Option<(PCS::Commitment, PCS::Committed)>],
+	challenger: &mut CH,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: TowerField,
+	PCS: BatchCommitScheme<U, F, FW>,
+	CH: CanObserve<PCS::Commitment>,
+{
+	for &batch_id in batch_ids {
+		let (commitment, committed) =
+			commit_schemes[batch_id].commit(witness, &batch_oracle_ids[batch_id])?;
+		challenger.observe(commitment.clone());
+		batch_commitments[batch_id] = Some((commitment, committed));
+	}
+	Ok(())
+}
+
+fn scalar_witness<'a, U, F, FW, PCS>(
+	constraint_system: &ConstraintSystem<U, F, FW, PCS>,
+	witness: &MultilinearExtensionIndex<'a, U, FW>,
+	id: OracleId,
+) -> Result<MultilinearWitness<'a, FW>, Error>
+where
+	U: TowerUnderlier + PackScalar<FW>,
+	F: TowerField,
+	FW: CanonicalTowerExtension,
+{
+	witness
+		.get_scalar_multilin_poly(id)
+		.map_err(|err| Error::from(err.with_oracle_names(constraint_system.oracles())))
+}
+
+fn make_composite_witness<'a, U, F, FW>(
+	oracles: &MultilinearOracleSet<F>,
+	poly: &CompositePolyOracle<F>,
+	witness: &MultilinearExtensionIndex<'a, U, FW>,
+) -> Result<CompositeWitness<'a, U, F, FW>, Error>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F>,
+{
+	let multilinears = poly
+		.inner_polys()
+		.iter()
+		.map(|oracle| {
+			witness
+				.get_multilin_poly(oracle.id())
+				.map_err(|err| err.with_oracle_names(oracles))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	let witness = MultilinearComposite::new(
+		poly.n_vars(),
+		IsomorphicComposition::new(poly.composition()),
+		multilinears,
+	)?;
+	Ok(witness)
+}
@@ -0,0 +1,503 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	check_constraints, prove, prove_with_options, verify, BatchCommitScheme, ConstraintSystem,
+	ConstraintSystemBuilder, Error, ProveOptions, TowerBatchCommitScheme,
+};
+use crate::{
+	challenger::HashChallenger,
+	merkle_tree::MerkleTreeVCS,
+	oracle::{CompositePolyOracle, MultilinearOracleSet, OracleId},
+	poly_commit::{tensor_pcs::calculate_n_test_queries_reed_solomon, BasicTensorPCS},
+	polynomial::{
+		composition::BivariateProduct, CompositionPoly, Error as PolynomialError,
+		IsomorphicEvaluationDomainFactory,
+	},
+	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
+		channel::{Flush, FlushDirection, FlushMultiplicity},
+		logup::LogUpClaim,
+		permcheck::CopyConstraints,
+		sumcheck::SumcheckClaim,
+	},
+	reed_solomon::reed_solomon::ReedSolomonCode,
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	arch::OptimalUnderlier128b,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::WithUnderlier,
+	BinaryField128b, BinaryField8b, ExtensionField, Field, PackedField, TowerField,
+};
+use binius_hash::{GroestlDigest, GroestlDigestCompression, GroestlHasher, HasherDigest};
+use either::Either;
+use rand::{rngs::StdRng, SeedableRng};
+use std::iter::{self, repeat_with};
+
+type U = OptimalUnderlier128b;
+type F = BinaryField128b;
+type P8b = PackedType<U, BinaryField8b>;
+type Challenger = HashChallenger<BinaryField8b, GroestlHasher<BinaryField8b>>;
+type TestPCS<FS> = BasicTensorPCS<
+	U,
+	FS,
+	BinaryField8b,
+	F,
+	ReedSolomonCode<P8b>,
+	HasherDigest<PackedType<U, FS>, GroestlHasher<PackedType<U, FS>>>,
+	MerkleTreeVCS<
+		GroestlDigest,
+		GroestlDigest,
+		GroestlHasher<GroestlDigest>,
+		GroestlDigestCompression,
+	>,
+>;
+type TestScheme<FS> = TowerBatchCommitScheme<FS, TestPCS<FS>>;
+
+const LOG_ROWS: usize = 4;
+const LOG_DIM: usize = 5;
+const N_VARS: usize = LOG_ROWS + LOG_DIM;
+
+/// The composition $a \cdot b - c$.
+#[derive(Debug)]
+struct ProductComposition;
+
+impl<P: PackedField> CompositionPoly<P> for ProductComposition {
+	fn n_vars(&self) -> usize {
+		3
+	}
+
+	fn degree(&self) -> usize {
+		2
+	}
+
+	fn evaluate_scalar(&self, query: &[P::Scalar]) -> Result<P::Scalar, PolynomialError> {
+		self.evaluate(query)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, PolynomialError> {
+		if query.len() != 3 {
+			return Err(PolynomialError::IncorrectQuerySize { expected: 3 });
+		}
+		Ok(query[0] * query[1] - query[2])
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+}
+
+struct TestSystem {
+	oracles: MultilinearOracleSet<F>,
+	batch_id: usize,
+	a: OracleId,
+	b: OracleId,
+	c: OracleId,
+}
+
+impl TestSystem {
+	fn new() -> Self {
+		let mut oracles = MultilinearOracleSet::new();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField8b::TOWER_LEVEL);
+		let [a, b, c] = batch_scope.add_multiple();
+		let batch_id = batch_scope.build();
+		Self {
+			oracles,
+			batch_id,
+			a,
+			b,
+			c,
+		}
+	}
+}
+
+fn make_scheme<FS>() -> TestScheme<FS>
+where
+	FS: TowerField + ExtensionField<BinaryField8b>,
+	F: ExtensionField<FS>,
+	U: PackScalar<FS>,
+{
+	let rs_code = ReedSolomonCode::new(LOG_DIM, 1).unwrap();
+	let n_test_queries =
+		calculate_n_test_queries_reed_solomon::<_, F, _>(100, LOG_ROWS, &rs_code).unwrap();
+	let pcs =
+		TestPCS::<FS>::new_using_groestl_merkle_tree(LOG_ROWS, rs_code, n_test_queries).unwrap();
+	TowerBatchCommitScheme::new(pcs)
+}
+
+fn make_constraint_system(
+	system: &TestSystem,
+	sum: Option<F>,
+	binding_order: BatchBindingOrder,
+) -> ConstraintSystem<U, F, F, TestScheme<BinaryField8b>> {
+	let oracles = &system.oracles;
+	let mut builder = ConstraintSystemBuilder::new(oracles.clone());
+	builder.set_binding_order(binding_order);
+	builder.assert_zero_named(
+		"product",
+		CompositePolyOracle::new(
+			N_VARS,
+			vec![
+				oracles.oracle(system.a),
+				oracles.oracle(system.b),
+				oracles.oracle(system.c),
+			],
+			ProductComposition,
+		)
+		.unwrap(),
+	);
+	if let Some(sum) = sum {
+		builder.assert_sum(SumcheckClaim {
+			poly: CompositePolyOracle::new(
+				N_VARS,
+				vec![oracles.oracle(system.a), oracles.oracle(system.b)],
+				BivariateProduct,
+			)
+			.unwrap(),
+			sum,
+		});
+	}
+	builder
+		.set_commit_scheme(system.batch_id, make_scheme())
+		.unwrap();
+	builder.build().unwrap()
+}
+
+fn make_witness(
+	system: &TestSystem,
+	a: &[P8b],
+	b: &[P8b],
+	c: &[P8b],
+) -> MultilinearExtensionIndex<'static, U, F> {
+	MultilinearExtensionIndex::new()
+		.update_owned::<BinaryField8b, _>(iter::zip(
+			[system.a, system.b, system.c],
+			[to_underliers(a), to_underliers(b), to_underliers(c)],
+		))
+		.unwrap()
+}
+
+fn prove_and_verify<PCS>(
+	constraint_system: &ConstraintSystem<U, F, F, PCS>,
+	witness: MultilinearExtensionIndex<U, F>,
+	lookup_mappings: &[Vec<Vec<usize>>],
+) -> Result<(), Error>
+where
+	PCS: BatchCommitScheme<U, F, F, Commitment = GroestlDigest>,
+{
+	let challenger = Challenger::new();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<F>::default();
+	let proof = prove::<_, _, _, F, _, _>(
+		constraint_system,
+		witness,
+		lookup_mappings,
+		challenger.clone(),
+		domain_factory,
+		|_| 1,
+	)?;
+	verify(constraint_system, proof, challenger)
+}
+
+fn random_packed(rng: &mut StdRng) -> Vec<P8b> {
+	repeat_with(|| P8b::random(&mut *rng))
+		.take((1 << N_VARS) / P8b::WIDTH)
+		.collect()
+}
+
+fn to_underliers(values: &[P8b]) -> Vec<U> {
+	values.iter().map(|&value| value.to_underlier()).collect()
+}
+
+/// A product constraint together with a flush, copy constraint and lookup on its columns.
+struct RelationSystem {
+	oracles: MultilinearOracleSet<F>,
+	trace_batch_id: usize,
+	multiplicities_batch_id: usize,
+	/// The columns a, b, c = a * b, d = c in reverse row order and the byte table
+	columns: [OracleId; 5],
+	multiplicities: OracleId,
+}
+
+impl RelationSystem {
+	fn new() -> Self {
+		let mut oracles = MultilinearOracleSet::new();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField8b::TOWER_LEVEL);
+		let columns = batch_scope.add_multiple();
+		let trace_batch_id = batch_scope.build();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, F::TOWER_LEVEL);
+		let multiplicities = batch_scope.add_one();
+		let multiplicities_batch_id = batch_scope.build();
+		Self {
+			oracles,
+			trace_batch_id,
+			multiplicities_batch_id,
+			columns,
+			multiplicities,
+		}
+	}
+
+	fn builder(
+		&self,
+		copy_constraints: CopyConstraints,
+	) -> ConstraintSystemBuilder<U, F, F, Either<TestScheme<BinaryField8b>, TestScheme<F>>> {
+		let oracles = &self.oracles;
+		let [a, b, c, d, table] = self.columns.map(|id| oracles.oracle(id));
+
+		let mut builder = ConstraintSystemBuilder::new(oracles.clone());
+		builder
+			.assert_zero(
+				CompositePolyOracle::new(
+					N_VARS,
+					vec![a.clone(), b.clone(), c.clone()],
+					ProductComposition,
+				)
+				.unwrap(),
+			)
+			.flush(
+				Flush::new(0, FlushDirection::Push, vec![c], FlushMultiplicity::Constant(1))
+					.unwrap(),
+			)
+			.flush(
+				Flush::new(0, FlushDirection::Pull, vec![d], FlushMultiplicity::Constant(1))
+					.unwrap(),
+			)
+			.assert_lookup(
+				LogUpClaim::new(
+					table,
+					oracles.oracle(self.multiplicities),
+					vec![a.clone(), b.clone()],
+				)
+				.unwrap(),
+			);
+		builder
+			.assert_copy_constraints(vec![a, b], copy_constraints.into_sigma())
+			.unwrap();
+		builder
+	}
+
+	fn constraint_system(
+		&self,
+		copy_constraints: CopyConstraints,
+	) -> ConstraintSystem<U, F, F, Either<TestScheme<BinaryField8b>, TestScheme<F>>> {
+		let mut builder = self.builder(copy_constraints);
+		builder
+			.set_commit_scheme(self.trace_batch_id, Either::Left(make_scheme()))
+			.unwrap()
+			.set_commit_scheme(self.multiplicities_batch_id, Either::Right(make_scheme()))
+			.unwrap();
+		builder.build().unwrap()
+	}
+
+	/// The witness for the trace batch and the table indices of the looked up columns.
+	fn witness(
+		&self,
+		a: &[BinaryField8b],
+		b: &[BinaryField8b],
+	) -> (MultilinearExtensionIndex<'static, U, F>, Vec<Vec<Vec<usize>>>) {
+		let c = iter::zip(a, b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+		let d = c.iter().rev().copied().collect::<Vec<_>>();
+		let table = (0..1 << N_VARS)
+			.map(|i| BinaryField8b::new(i as u8))
+			.collect::<Vec<_>>();
+
+		let witness = MultilinearExtensionIndex::new()
+			.update_owned::<BinaryField8b, _>(iter::zip(
+				self.columns,
+				[a, b, &c[..], &d[..], &table[..]].map(pack_scalars),
+			))
+			.unwrap();
+		let mapping = |values: &[BinaryField8b]| {
+			values
+				.iter()
+				.map(|value| value.val() as usize)
+				.collect::<Vec<_>>()
+		};
+		(witness, vec![vec![mapping(a), mapping(b)]])
+	}
+}
+
+fn random_scalars(rng: &mut StdRng) -> Vec<BinaryField8b> {
+	repeat_with(|| <BinaryField8b as Field>::random(&mut *rng))
+		.take(1 << N_VARS)
+		.collect()
+}
+
+fn pack_scalars(values: &[BinaryField8b]) -> Vec<U> {
+	values
+		.chunks(P8b::WIDTH)
+		.map(|chunk| P8b::from_scalars(chunk.iter().copied()).to_underlier())
+		.collect()
+}
+
+#[test]
+fn test_prove_verify_zerocheck_and_sumcheck() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = TestSystem::new();
+
+	let a = random_packed(&mut rng);
+	let b = random_packed(&mut rng);
+	let c = iter::zip(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	let sum = c.iter().flat_map(|c| c.iter()).sum::<BinaryField8b>();
+
+	let constraint_system =
+		make_constraint_system(&system, Some(sum.into()), BatchBindingOrder::BackLoaded);
+	let witness = make_witness(&system, &a, &b, &c);
+	prove_and_verify(&constraint_system, witness, &[]).unwrap();
+}
+
+#[test]
+fn test_prove_verify_front_loaded() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = TestSystem::new();
+
+	let a = random_packed(&mut rng);
+	let b = random_packed(&mut rng);
+	let c = iter::zip(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	let sum = c.iter().flat_map(|c| c.iter()).sum::<BinaryField8b>();
+
+	let constraint_system =
+		make_constraint_system(&system, Some(sum.into()), BatchBindingOrder::FrontLoaded);
+	assert_eq!(constraint_system.binding_order(), BatchBindingOrder::FrontLoaded);
+	let witness = make_witness(&system, &a, &b, &c);
+	prove_and_verify(&constraint_system, witness, &[]).unwrap();
+}
+
+#[test]
+fn test_prove_verify_fails_on_unsatisfied_constraint() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = TestSystem::new();
+
+	let a = random_packed(&mut rng);
+	let b = random_packed(&mut rng);
+	let mut c = iter::zip(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	c[1].set(3, c[1].get(3) + BinaryField8b::ONE);
+
+	let constraint_system = make_constraint_system(&system, None, BatchBindingOrder::BackLoaded);
+	let witness = make_witness(&system, &a, &b, &c);
+	assert!(prove_and_verify(&constraint_system, witness, &[]).is_err());
+}
+
+#[test]
+fn test_check_constraints_reports_failing_rows() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = TestSystem::new();
+
+	let a = random_packed(&mut rng);
+	let b = random_packed(&mut rng);
+	let mut c = iter::zip(&a, &b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+	c[1].set(3, c[1].get(3) + BinaryField8b::ONE);
+	c[2].set(0, c[2].get(0) + BinaryField8b::ONE);
+
+	let constraint_system = make_constraint_system(&system, None, BatchBindingOrder::BackLoaded);
+	let witness = make_witness(&system, &a, &b, &c);
+
+	let unsatisfied = check_constraints(&constraint_system, &witness, 1).unwrap();
+	assert_eq!(unsatisfied.len(), 1);
+	let constraint = &unsatisfied[0];
+	assert_eq!(constraint.name.as_deref(), Some("product"));
+	assert_eq!(constraint.inner_oracle_ids, vec![system.a, system.b, system.c]);
+	assert_eq!(constraint.n_failing_rows, 2);
+	assert_eq!(constraint.failing_rows.len(), 1);
+
+	let row = &constraint.failing_rows[0];
+	assert_eq!(row.index, P8b::WIDTH + 3);
+	assert_eq!(row.inner_values, vec![a[1].get(3).into(), b[1].get(3).into(), c[1].get(3).into()]);
+	assert_eq!(row.value, F::ONE);
+
+	let result = prove_with_options::<_, _, _, F, _, _>(
+		&constraint_system,
+		witness,
+		&[],
+		Challenger::new(),
+		IsomorphicEvaluationDomainFactory::<F>::default(),
+		|_| 1,
+		ProveOptions {
+			debug_max_failing_rows: Some(1),
+		},
+	);
+	assert!(matches!(result, Err(Error::UnsatisfiedConstraints { .. })));
+}
+
+#[test]
+fn test_build_fails_without_commit_scheme() {
+	let system = TestSystem::new();
+	let oracles = &system.oracles;
+
+	let mut builder =
+		ConstraintSystemBuilder::<U, F, F, TestScheme<BinaryField8b>>::new(oracles.clone());
+	builder.assert_zero(
+		CompositePolyOracle::new(
+			N_VARS,
+			vec![
+				oracles.oracle(system.a),
+				oracles.oracle(system.b),
+				oracles.oracle(system.c),
+			],
+			ProductComposition,
+		)
+		.unwrap(),
+	);
+	assert!(matches!(
+		builder.build(),
+		Err(Error::MissingCommitScheme { batch_id }) if batch_id == system.batch_id
+	));
+}
+
+#[test]
+fn test_prove_verify_relations() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = RelationSystem::new();
+
+	let a = random_scalars(&mut rng);
+	let mut b = random_scalars(&mut rng);
+	b[5] = a[0];
+
+	let mut copy_constraints = CopyConstraints::new(2, N_VARS);
+	copy_constraints.connect((0, 0), (1, 5)).unwrap();
+
+	let constraint_system = system.constraint_system(copy_constraints);
+	let (witness, lookup_mappings) = system.witness(&a, &b);
+	prove_and_verify(&constraint_system, witness, &lookup_mappings).unwrap();
+}
+
+#[test]
+fn test_prove_verify_fails_on_broken_copy_constraint() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let system = RelationSystem::new();
+
+	let a = random_scalars(&mut rng);
+	let mut b = random_scalars(&mut rng);
+	b[5] = a[0] + BinaryField8b::ONE;
+
+	let mut copy_constraints = CopyConstraints::new(2, N_VARS);
+	copy_constraints.connect((0, 0), (1, 5)).unwrap();
+
+	let constraint_system = system.constraint_system(copy_constraints);
+	let (witness, lookup_mappings) = system.witness(&a, &b);
+	assert!(prove_and_verify(&constraint_system, witness, &lookup_mappings).is_err());
+}
+
+#[test]
+fn test_build_fails_on_multiplicities_in_trace_batch() {
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let mut batch_scope = oracles.build_committed_batch(N_VARS, F::TOWER_LEVEL);
+	let [column, table, multiplicities] = batch_scope.add_multiple();
+	let batch_id = batch_scope.build();
+
+	let mut builder = ConstraintSystemBuilder::<U, F, F, TestScheme<F>>::new(oracles.clone());
+	builder
+		.assert_lookup(
+			LogUpClaim::new(
+				oracles.oracle(table),
+				oracles.oracle(multiplicities),
+				vec![oracles.oracle(column)],
+			)
+			.unwrap(),
+		)
+		.set_commit_scheme(batch_id, make_scheme())
+		.unwrap();
+	assert!(matches!(
+		builder.build(),
+		Err(Error::MixedMultiplicitiesBatch { batch_id: id }) if id == batch_id
+	));
+}
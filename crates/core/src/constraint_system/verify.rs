@@ -0,0 +1,215 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	common::{sample_lookup_betas, ConstraintSystem, Proof},
+	error::Error,
+	pcs::BatchCommitScheme,
+};
+use crate::{
+	challenger::{CanObserve, CanSample, CanSampleBits},
+	protocols::{
+		channel, gkr_gpa, greedy_evalcheck, logup, permcheck, sumcheck,
+		zerocheck::{self, ZerocheckClaim},
+	},
+};
+use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, Field, TowerField};
+use tracing::instrument;
+
+/// Verify a proof for the constraint system.
+///
+/// The challenger must be in the same state as the one passed to [`super::prove()`].
+#[instrument(skip_all, name = "constraint_system::verify")]
+pub fn verify<U, F, FW, PCS, CH>(
+	constraint_system: &ConstraintSystem<U, F, FW, PCS>,
+	proof: Proof<F, PCS::Commitment, PCS::Proof>,
+	mut challenger: CH,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<FW>,
+	F: TowerField,
+	FW: Field,
+	PCS: BatchCommitScheme<U, F, FW>,
+	CH: CanObserve<F> + CanObserve<PCS::Commitment> + CanSample<F> + CanSampleBits<usize>,
+{
+	let Proof {
+		commitments,
+		channel_proof,
+		permcheck_proofs,
+		grand_product_proof,
+		logup_proofs,
+		zerocheck_proof,
+		sumcheck_proof,
+		evalcheck_proof,
+		opening_proofs,
+	} = proof;
+
+	let mut oracles = constraint_system.oracles().clone();
+	let commit_schemes = constraint_system.commit_schemes();
+	let copy_constraints = constraint_system.copy_constraints();
+	let lookups = constraint_system.lookups();
+
+	if commitments.len() != commit_schemes.len() {
+		return Err(Error::IncorrectNumberOfCommitments {
+			expected: commit_schemes.len(),
+			actual: commitments.len(),
+		});
+	}
+	if opening_proofs.len() != commit_schemes.len() {
+		return Err(Error::IncorrectNumberOfOpeningProofs {
+			expected: commit_schemes.len(),
+			actual: opening_proofs.len(),
+		});
+	}
+	if permcheck_proofs.len() != copy_constraints.len() {
+		return Err(Error::IncorrectNumberOfPermcheckProofs {
+			expected: copy_constraints.len(),
+			actual: permcheck_proofs.len(),
+		});
+	}
+	if logup_proofs.len() != lookups.len() {
+		return Err(Error::IncorrectNumberOfLookupProofs {
+			expected: lookups.len(),
+			actual: logup_proofs.len(),
+		});
+	}
+
+	// Observe the batch commitments, with the lookup multiplicities committed after the lookup
+	// weighting challenges are sampled
+	let is_multiplicity_batch = |batch_id: &usize| {
+		constraint_system
+			.multiplicity_batch_ids()
+			.contains(batch_id)
+	};
+	for (_, commitment) in commitments
+		.iter()
+		.enumerate()
+		.filter(|(batch_id, _)| !is_multiplicity_batch(batch_id))
+	{
+		challenger.observe(commitment.clone());
+	}
+	let lookup_betas = sample_lookup_betas(lookups, &mut challenger);
+	for (_, commitment) in commitments
+		.iter()
+		.enumerate()
+		.filter(|(batch_id, _)| is_multiplicity_batch(batch_id))
+	{
+		challenger.observe(commitment.clone());
+	}
+
+	// Sample the relation challenges once all batches are committed
+	let gamma = challenger.sample();
+	let alpha = challenger.sample();
+
+	let mut evalcheck_claims = Vec::new();
+
+	// Verify the reductions of the flushes and copy constraints to grand product claims
+	let mut grand_product_claims = Vec::new();
+	match (constraint_system.channel_claim(), channel_proof) {
+		(None, None) => {}
+		(None, Some(_)) => return Err(Error::ExtraChannelProof),
+		(Some(_), None) => return Err(Error::MissingChannelProof),
+		(Some(channel_claim), Some(channel_proof)) => {
+			grand_product_claims.extend(channel::verify(
+				&mut oracles,
+				channel_claim,
+				gamma,
+				alpha,
+				&channel_proof,
+			)?);
+			challenger.observe_slice(&channel_proof.products);
+		}
+	}
+
+	for (claim, permcheck_proof) in copy_constraints.iter().zip(&permcheck_proofs) {
+		grand_product_claims.extend(permcheck::verify(
+			&mut oracles,
+			claim,
+			gamma,
+			alpha,
+			permcheck_proof,
+		)?);
+		challenger.observe_slice(&permcheck_proof.identity_products);
+		challenger.observe_slice(&permcheck_proof.sigma_products);
+	}
+
+	match (grand_product_claims.is_empty(), grand_product_proof) {
+		(true, None) => {}
+		(true, Some(_)) => return Err(Error::ExtraGrandProductProof),
+		(false, None) => return Err(Error::MissingGrandProductProof),
+		(false, Some(grand_product_proof)) => {
+			evalcheck_claims.extend(gkr_gpa::batch_verify(
+				grand_product_claims,
+				grand_product_proof,
+				&mut challenger,
+			)?);
+		}
+	}
+
+	// Verify the lookups
+	for ((lookup, betas), logup_proof) in lookups.iter().zip(&lookup_betas).zip(logup_proofs) {
+		evalcheck_claims.extend(logup::verify(lookup, betas, gamma, logup_proof, &mut challenger)?);
+	}
+
+	// Verify the zerocheck constraints
+	let zerocheck_constraints = constraint_system.zerocheck_constraints();
+	match (zerocheck_constraints.is_empty(), zerocheck_proof) {
+		(true, None) => {}
+		(true, Some(_)) => return Err(Error::ExtraZerocheckProof),
+		(false, None) => return Err(Error::MissingZerocheckProof),
+		(false, Some(zerocheck_proof)) => {
+			let claims = zerocheck_constraints
+				.iter()
+				.map(|constraint| ZerocheckClaim {
+					poly: constraint.clone(),
+				});
+			evalcheck_claims.extend(zerocheck::batch_verify(
+				claims,
+				zerocheck_proof,
+				constraint_system.binding_order(),
+				&mut challenger,
+			)?);
+		}
+	}
+
+	// Verify the sumcheck claims
+	let sumcheck_claims = constraint_system.sumcheck_claims();
+	match (sumcheck_claims.is_empty(), sumcheck_proof) {
+		(true, None) => {}
+		(true, Some(_)) => return Err(Error::ExtraSumcheckProof),
+		(false, None) => return Err(Error::MissingSumcheckProof),
+		(false, Some(sumcheck_proof)) => {
+			evalcheck_claims.extend(sumcheck::batch_verify(
+				sumcheck_claims.iter().cloned(),
+				sumcheck_proof,
+				constraint_system.binding_order(),
+				&mut challenger,
+			)?);
+		}
+	}
+
+	// Reduce all evaluation claims to one claim per committed batch
+	let same_query_claims =
+		greedy_evalcheck::verify(&mut oracles, evalcheck_claims, evalcheck_proof, &mut challenger)?;
+
+	// Verify the commitment openings
+	for (batch_id, ((scheme, commitment), opening_proof)) in commit_schemes
+		.iter()
+		.zip(commitments.iter())
+		.zip(opening_proofs)
+		.enumerate()
+	{
+		let (_, same_query_claim) = same_query_claims
+			.iter()
+			.find(|(id, _)| *id == batch_id)
+			.ok_or(Error::MissingBatchEvalClaim { batch_id })?;
+		scheme.verify_evaluation(
+			&mut challenger,
+			commitment,
+			&same_query_claim.eval_point,
+			opening_proof,
+			&same_query_claim.evals,
+		)?;
+	}
+
+	Ok(())
+}
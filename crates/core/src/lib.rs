@@ -7,6 +7,7 @@
 #![allow(clippy::suspicious_op_assign_impl)]
 
 pub mod challenger;
+pub mod constraint_system;
 pub mod linalg;
 pub mod linear_code;
 pub mod merkle_tree;
@@ -4,7 +4,7 @@ use crate::{
 	oracle::{Error, MultilinearPolyOracle},
 	polynomial::CompositionPoly,
 };
-use binius_field::Field;
+use binius_field::{Field, TowerField};
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -44,16 +44,6 @@ impl<F: Field> CompositePolyOracle<F> {
 		self.composition.n_vars()
 	}
 
-	pub fn binary_tower_level(&self) -> usize {
-		self.composition.binary_tower_level().max(
-			self.inner
-				.iter()
-				.map(MultilinearPolyOracle::binary_tower_level)
-				.max()
-				.unwrap_or(0),
-		)
-	}
-
 	pub fn n_vars(&self) -> usize {
 		self.n_vars
 	}
@@ -67,11 +57,23 @@ impl<F: Field> CompositePolyOracle<F> {
 	}
 }
 
+impl<F: TowerField> CompositePolyOracle<F> {
+	pub fn binary_tower_level(&self) -> usize {
+		self.composition.binary_tower_level().max(
+			self.inner
+				.iter()
+				.map(MultilinearPolyOracle::binary_tower_level)
+				.max()
+				.unwrap_or(0),
+		)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
-		oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet},
+		oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet, ShiftVariant},
 		polynomial::Error as PolynomialError,
 	};
 	use binius_field::{BinaryField128b, BinaryField2b, BinaryField32b, BinaryField8b, TowerField};
@@ -177,4 +179,65 @@ mod tests {
 		.unwrap();
 		assert_eq!(composite.binary_tower_level(), BinaryField32b::TOWER_LEVEL);
 	}
+
+	#[test]
+	fn test_linear_combination_tower_level() {
+		type F = BinaryField128b;
+
+		let n_vars = 5;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 2,
+			tower_level: BinaryField2b::TOWER_LEVEL,
+		});
+		let [poly_0, poly_1] =
+			[0, 1].map(|index| oracles.committed_oracle_id(CommittedId { batch_id, index }));
+
+		let coeff_8b = F::from(BinaryField8b::new(0x2D));
+		let coeff_32b = F::from(BinaryField32b::new(0x10000));
+		let cases = [
+			(F::ZERO, [F::ONE, F::ONE], BinaryField2b::TOWER_LEVEL),
+			(F::ZERO, [F::ONE, coeff_8b], BinaryField8b::TOWER_LEVEL),
+			(coeff_32b, [F::ONE, coeff_8b], BinaryField32b::TOWER_LEVEL),
+		];
+		for (offset, [coeff_0, coeff_1], tower_level) in cases {
+			let id = oracles
+				.add_linear_combination_with_offset(
+					n_vars,
+					offset,
+					[(poly_0, coeff_0), (poly_1, coeff_1)],
+				)
+				.unwrap();
+			assert_eq!(oracles.tower_level(id), tower_level);
+			assert_eq!(oracles.oracle(id).binary_tower_level(), tower_level);
+		}
+	}
+
+	#[test]
+	fn test_shifted_with_fill_tower_level() {
+		type F = BinaryField128b;
+
+		let n_vars = 5;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 1,
+			tower_level: BinaryField8b::TOWER_LEVEL,
+		});
+		let poly = oracles.committed_oracle_id(CommittedId { batch_id, index: 0 });
+
+		for (fill, tower_level) in [
+			(F::ZERO, BinaryField8b::TOWER_LEVEL),
+			(F::ONE, F::TOWER_LEVEL),
+		] {
+			let id = oracles
+				.add_shifted_with_fill(poly, 1, n_vars, ShiftVariant::LogicalLeft, fill)
+				.unwrap();
+			assert_eq!(oracles.tower_level(id), tower_level);
+			assert_eq!(oracles.oracle(id).binary_tower_level(), tower_level);
+		}
+	}
 }
@@ -20,8 +20,30 @@ pub enum Error {
 		"n_vars ({n_vars}) must be at least as big as the requested log_degree ({log_degree})"
 	)]
 	NotEnoughVarsForPacking { n_vars: usize, log_degree: usize },
+	#[error("cannot zero-pad a {inner_n_vars}-variate oracle to {n_vars} variables at block {block_index}")]
+	InvalidZeroPadding {
+		n_vars: usize,
+		inner_n_vars: usize,
+		block_index: usize,
+	},
+	#[error("{permutation:?} is not a permutation of the {n_vars} variables of the inner oracle")]
+	InvalidVariablePermutation {
+		permutation: Vec<usize>,
+		n_vars: usize,
+	},
+	#[error(
+		"cannot embed an oracle of tower level {inner_tower_level} into tower level {tower_level}"
+	)]
+	InvalidEmbedding {
+		tower_level: usize,
+		inner_tower_level: usize,
+	},
+	#[error("circular shifts vacate no positions and cannot have a fill value")]
+	CircularShiftWithFill,
 	#[error("no oracle exists in this MultilinearOracleSet with id {0}")]
 	InvalidOracleId(OracleId),
+	#[error("an oracle named \"{name}\" already exists")]
+	DuplicateOracleName { name: String },
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
 }
@@ -0,0 +1,226 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::oracle::{MultilinearOracleSet, OracleId};
+use binius_field::TowerField;
+use std::fmt::{self, Display, Write};
+
+/// An oracle described as a node of the oracle graph.
+pub(super) struct OracleNode {
+	pub kind: &'static str,
+	pub n_vars: usize,
+	pub tower_level: usize,
+	/// The inner oracles, which are the sources of the incoming edges.
+	pub inputs: Vec<OracleNodeInput>,
+	/// Parameters of the oracle, besides its inputs.
+	pub params: Vec<(&'static str, NodeParam)>,
+}
+
+pub(super) struct OracleNodeInput {
+	pub id: OracleId,
+	/// Coefficient of the input, for linear combinations.
+	pub coeff: Option<String>,
+}
+
+pub(super) enum NodeParam {
+	Usize(usize),
+	Str(String),
+}
+
+impl Display for NodeParam {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Usize(value) => write!(f, "{value}"),
+			Self::Str(value) => write!(f, "{value}"),
+		}
+	}
+}
+
+impl<F: TowerField> MultilinearOracleSet<F> {
+	/// Export the oracle graph in the Graphviz DOT format.
+	///
+	/// Each oracle is a node labeled with its ID, name, kind, number of variables, tower level,
+	/// and parameters. Edges point from inner oracles to the oracles derived from them, and are
+	/// labeled with the coefficients of linear combinations.
+	pub fn to_dot(&self) -> String {
+		let mut dot = String::from("digraph oracles {\n\tnode [shape=box];\n");
+		for id in 0..self.size() {
+			let node = self.node(id);
+
+			let mut label = self.display_oracle(id).to_string();
+			if let Some(oracle_label) = self.label(id).filter(|label| !label.tags.is_empty()) {
+				write!(label, " [{}]", oracle_label.tags.join(", ")).expect("writes to String");
+			}
+			write!(
+				label,
+				"\n{}\nn_vars={}, tower_level={}",
+				node.kind, node.n_vars, node.tower_level
+			)
+			.expect("writes to String");
+			for (key, value) in node.params.iter() {
+				write!(label, "\n{key}={value}").expect("writes to String");
+			}
+			writeln!(dot, "\tn{id} [label=\"{}\"];", escape_dot(&label)).expect("writes to String");
+
+			for input in node.inputs.iter() {
+				write!(dot, "\tn{} -> n{id}", input.id).expect("writes to String");
+				if let Some(coeff) = &input.coeff {
+					write!(dot, " [label=\"{}\"]", escape_dot(coeff)).expect("writes to String");
+				}
+				dot.push_str(";\n");
+			}
+		}
+		dot.push_str("}\n");
+		dot
+	}
+
+	/// Export the oracle graph as JSON.
+	///
+	/// The output is an object with an `oracles` array, holding one object per oracle in ID order
+	/// with the fields `id`, `name`, `tags`, `kind`, `n_vars`, `tower_level`, `params`, and
+	/// `inputs`. Field elements are formatted with their `Debug` representation.
+	pub fn to_json(&self) -> String {
+		let mut json = String::from("{\"oracles\":[");
+		for id in 0..self.size() {
+			let node = self.node(id);
+			let label = self.label(id);
+
+			if id > 0 {
+				json.push(',');
+			}
+			write!(json, "{{\"id\":{id},\"name\":").expect("writes to String");
+			match label.and_then(|label| label.name.as_deref()) {
+				Some(name) => write_json_str(&mut json, name),
+				None => json.push_str("null"),
+			}
+			json.push_str(",\"tags\":[");
+			for (i, tag) in label.iter().flat_map(|label| label.tags.iter()).enumerate() {
+				if i > 0 {
+					json.push(',');
+				}
+				write_json_str(&mut json, tag);
+			}
+			write!(
+				json,
+				"],\"kind\":\"{}\",\"n_vars\":{},\"tower_level\":{},\"params\":{{",
+				node.kind, node.n_vars, node.tower_level
+			)
+			.expect("writes to String");
+			for (i, (key, value)) in node.params.iter().enumerate() {
+				if i > 0 {
+					json.push(',');
+				}
+				write!(json, "\"{key}\":").expect("writes to String");
+				match value {
+					NodeParam::Usize(value) => write!(json, "{value}").expect("writes to String"),
+					NodeParam::Str(value) => write_json_str(&mut json, value),
+				}
+			}
+			json.push_str("},\"inputs\":[");
+			for (i, input) in node.inputs.iter().enumerate() {
+				if i > 0 {
+					json.push(',');
+				}
+				write!(json, "{{\"id\":{}", input.id).expect("writes to String");
+				if let Some(coeff) = &input.coeff {
+					json.push_str(",\"coeff\":");
+					write_json_str(&mut json, coeff);
+				}
+				json.push('}');
+			}
+			json.push_str("]}");
+		}
+		json.push_str("]}");
+		json
+	}
+}
+
+fn escape_dot(s: &str) -> String {
+	let mut escaped = String::with_capacity(s.len());
+	for c in s.chars() {
+		match c {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+fn write_json_str(out: &mut String, s: &str) {
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => {
+				write!(out, "\\u{:04x}", c as u32).expect("writes to String");
+			}
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::oracle::{MultilinearOracleSet, OracleLabel, ShiftVariant};
+	use binius_field::{BinaryField128b, BinaryField8b, Field, TowerField};
+
+	type F = BinaryField128b;
+
+	fn make_oracles() -> MultilinearOracleSet<F> {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(6, BinaryField8b::TOWER_LEVEL);
+		let [a, b] = batch_scope
+			.add_multiple_labeled([OracleLabel::new("a").with_tag("in\"puts"), "b".into()])
+			.unwrap();
+		batch_scope.build();
+		oracles
+			.labeled("a_shifted")
+			.add_shifted(a, 3, 4, ShiftVariant::LogicalRight)
+			.unwrap();
+		oracles.add_packed(b, 2).unwrap();
+		oracles
+			.add_linear_combination(6, [(a, F::ONE), (b, F::ONE)])
+			.unwrap();
+		oracles
+	}
+
+	#[test]
+	fn test_to_dot() {
+		let dot = make_oracles().to_dot();
+		assert!(dot.starts_with("digraph oracles {"));
+		assert!(dot.contains(
+			"\tn2 [label=\"2 (\\\"a_shifted\\\")\\nshifted\\nn_vars=6, tower_level=3\\noffset=3\\n\
+			 block_bits=4\\nvariant=LogicalRight\"];"
+		));
+		assert!(dot.contains("\tn0 -> n2;\n"));
+		assert!(dot.contains("\tn1 -> n3;\n"));
+		assert!(dot.contains("\tn0 -> n4 [label="));
+		assert!(dot.contains("\tn1 -> n4 [label="));
+		assert!(dot.ends_with("}\n"));
+	}
+
+	#[test]
+	fn test_to_json() {
+		let json = make_oracles().to_json();
+		assert!(
+			json.starts_with("{\"oracles\":[{\"id\":0,\"name\":\"a\",\"tags\":[\"in\\\"puts\"]")
+		);
+		assert!(json.contains(
+			"{\"id\":2,\"name\":\"a_shifted\",\"tags\":[],\"kind\":\"shifted\",\"n_vars\":6,\
+			 \"tower_level\":3,\"params\":{\"offset\":3,\"block_bits\":4,\
+			 \"variant\":\"LogicalRight\"},\"inputs\":[{\"id\":0}]}"
+		));
+		assert!(json.contains(
+			"{\"id\":3,\"name\":null,\"tags\":[],\"kind\":\"packed\",\"n_vars\":4,\
+			 \"tower_level\":5,\"params\":{\"log_degree\":2},\"inputs\":[{\"id\":1}]}"
+		));
+		assert!(json.contains("\"kind\":\"linear_combination\""));
+		assert!(json.ends_with("]}"));
+	}
+}
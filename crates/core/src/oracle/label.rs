@@ -0,0 +1,258 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::{Error, MultilinearOracleSet, OracleId, ProjectionVariant, ShiftVariant},
+	polynomial::MultivariatePoly,
+};
+use binius_field::TowerField;
+use std::fmt::{self, Display};
+
+/// Human-readable metadata attached to a multilinear oracle.
+///
+/// Names are unique within a [`MultilinearOracleSet`] and can be used to look up oracle IDs.
+/// Tags are free-form and can be shared by many oracles, for example to group all columns of one
+/// gadget.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OracleLabel {
+	pub name: Option<String>,
+	pub tags: Vec<String>,
+}
+
+impl OracleLabel {
+	pub fn new(name: impl ToString) -> Self {
+		Self {
+			name: Some(name.to_string()),
+			tags: Vec::new(),
+		}
+	}
+
+	pub fn with_tag(mut self, tag: impl ToString) -> Self {
+		self.tags.push(tag.to_string());
+		self
+	}
+}
+
+impl From<&str> for OracleLabel {
+	fn from(name: &str) -> Self {
+		Self::new(name)
+	}
+}
+
+impl From<String> for OracleLabel {
+	fn from(name: String) -> Self {
+		Self::new(name)
+	}
+}
+
+/// Formats an oracle ID together with its name, if it has one.
+#[derive(Debug, Clone, Copy)]
+pub struct OracleDisplay<'a> {
+	pub id: OracleId,
+	pub name: Option<&'a str>,
+}
+
+impl<'a> Display for OracleDisplay<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.name {
+			Some(name) => write!(f, "{} (\"{}\")", self.id, name),
+			None => write!(f, "{}", self.id),
+		}
+	}
+}
+
+/// Adds one oracle with a label to a [`MultilinearOracleSet`].
+///
+/// Constructed with [`MultilinearOracleSet::labeled`]. The methods mirror the `add_*` methods of
+/// the oracle set. The label is checked before the oracle is added, so a duplicate name leaves
+/// the oracle set unchanged.
+pub struct LabeledOracleAdder<'a, F: TowerField> {
+	oracles: &'a mut MultilinearOracleSet<F>,
+	label: OracleLabel,
+}
+
+impl<'a, F: TowerField> LabeledOracleAdder<'a, F> {
+	pub(super) fn new(oracles: &'a mut MultilinearOracleSet<F>, label: OracleLabel) -> Self {
+		Self { oracles, label }
+	}
+
+	fn add_with(
+		self,
+		add: impl FnOnce(&mut MultilinearOracleSet<F>) -> Result<OracleId, Error>,
+	) -> Result<OracleId, Error> {
+		self.oracles.check_label(&self.label)?;
+		let id = add(self.oracles)?;
+		self.oracles.set_label(id, self.label)?;
+		Ok(id)
+	}
+
+	pub fn add_transparent(
+		self,
+		poly: impl MultivariatePoly<F> + 'static,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_transparent(poly))
+	}
+
+	pub fn add_repeating(self, id: OracleId, log_count: usize) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_repeating(id, log_count))
+	}
+
+	pub fn add_interleaved(self, id0: OracleId, id1: OracleId) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_interleaved(id0, id1))
+	}
+
+	pub fn add_merged(self, id0: OracleId, id1: OracleId) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_merged(id0, id1))
+	}
+
+	pub fn add_shifted(
+		self,
+		id: OracleId,
+		offset: usize,
+		block_bits: usize,
+		variant: ShiftVariant,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_shifted(id, offset, block_bits, variant))
+	}
+
+	pub fn add_shifted_with_fill(
+		self,
+		id: OracleId,
+		offset: usize,
+		block_bits: usize,
+		variant: ShiftVariant,
+		fill: F,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| {
+			oracles.add_shifted_with_fill(id, offset, block_bits, variant, fill)
+		})
+	}
+
+	pub fn add_shifted_2d(
+		self,
+		id: OracleId,
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		variant: ShiftVariant,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| {
+			oracles.add_shifted_2d(id, col_bits, row_bits, col_offset, row_offset, variant)
+		})
+	}
+
+	pub fn add_packed(self, id: OracleId, log_degree: usize) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_packed(id, log_degree))
+	}
+
+	pub fn add_zero_padded(
+		self,
+		id: OracleId,
+		n_vars: usize,
+		block_index: usize,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_zero_padded(id, n_vars, block_index))
+	}
+
+	pub fn add_permuted_vars(
+		self,
+		id: OracleId,
+		permutation: Vec<usize>,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_permuted_vars(id, permutation))
+	}
+
+	pub fn add_embedded(self, id: OracleId, tower_level: usize) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_embedded(id, tower_level))
+	}
+
+	pub fn add_projected(
+		self,
+		id: OracleId,
+		values: Vec<F>,
+		variant: ProjectionVariant,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_projected(id, values, variant))
+	}
+
+	pub fn add_linear_combination(
+		self,
+		n_vars: usize,
+		inner: impl IntoIterator<Item = (OracleId, F)>,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_linear_combination(n_vars, inner))
+	}
+
+	pub fn add_linear_combination_with_offset(
+		self,
+		n_vars: usize,
+		offset: F,
+		inner: impl IntoIterator<Item = (OracleId, F)>,
+	) -> Result<OracleId, Error> {
+		self.add_with(|oracles| oracles.add_linear_combination_with_offset(n_vars, offset, inner))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use binius_field::{BinaryField128b, BinaryField1b};
+
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_labels_and_lookup() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(4, BinaryField1b::TOWER_LEVEL);
+		let a = batch_scope
+			.add_one_labeled(OracleLabel::new("a").with_tag("inputs"))
+			.unwrap();
+		let [b, c] = batch_scope
+			.add_multiple_labeled([OracleLabel::new("b").with_tag("inputs"), "c".into()])
+			.unwrap();
+		let unnamed = batch_scope.add_one();
+		batch_scope.build();
+
+		let shifted = oracles
+			.labeled("a_shifted")
+			.add_shifted(a, 1, 2, ShiftVariant::LogicalLeft)
+			.unwrap();
+
+		assert_eq!(oracles.oracle_id_by_name("a"), Some(a));
+		assert_eq!(oracles.oracle_id_by_name("c"), Some(c));
+		assert_eq!(oracles.oracle_id_by_name("a_shifted"), Some(shifted));
+		assert_eq!(oracles.oracle_id_by_name("d"), None);
+		assert_eq!(oracles.name(b), Some("b"));
+		assert_eq!(oracles.name(unnamed), None);
+		assert_eq!(oracles.oracle_ids_with_tag("inputs").collect::<Vec<_>>(), vec![a, b]);
+		assert_eq!(oracles.display_oracle(c).to_string(), format!("{c} (\"c\")"));
+		assert_eq!(oracles.display_oracle(unnamed).to_string(), unnamed.to_string());
+		assert!(oracles.to_string().contains("shifted 0 (\"a\")"));
+	}
+
+	#[test]
+	fn test_duplicate_names_are_rejected() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(4, BinaryField1b::TOWER_LEVEL);
+		let a = batch_scope.add_one_labeled("a").unwrap();
+		assert!(matches!(
+			batch_scope.add_multiple_labeled(["b", "b"]),
+			Err(Error::DuplicateOracleName { .. })
+		));
+		batch_scope.build();
+		let n_oracles = oracles.size();
+
+		assert!(matches!(
+			oracles.labeled("a").add_repeating(a, 1),
+			Err(Error::DuplicateOracleName { .. })
+		));
+		assert_eq!(oracles.size(), n_oracles);
+
+		let repeating = oracles.add_repeating(a, 1).unwrap();
+		assert!(oracles.set_label(repeating, "a").is_err());
+		oracles.set_label(a, "renamed").unwrap();
+		oracles.set_label(repeating, "a").unwrap();
+		assert_eq!(oracles.oracle_id_by_name("a"), Some(repeating));
+		assert_eq!(oracles.oracle_id_by_name("renamed"), Some(a));
+	}
+}
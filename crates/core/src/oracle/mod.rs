@@ -9,9 +9,12 @@
 mod committed;
 mod composite;
 mod error;
+mod export;
+mod label;
 mod multilinear;
 
 pub use committed::*;
 pub use composite::*;
 pub use error::Error;
+pub use label::*;
 pub use multilinear::*;
@@ -2,13 +2,25 @@
 
 use crate::{
 	oracle::{
+		export::{NodeParam, OracleNode, OracleNodeInput},
 		BatchId, CommittedBatch, CommittedBatchSpec, CommittedId, CompositePolyOracle, Error,
+		LabeledOracleAdder, OracleDisplay, OracleLabel,
+	},
+	polynomial::{
+		transparent::step_down::StepDown, Error as PolynomialError, IdentityCompositionPoly,
+		MultivariatePoly,
 	},
-	polynomial::{Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly},
 };
 use binius_field::{Field, TowerField};
 use getset::{CopyGetters, Getters};
-use std::{array, fmt::Debug, mem::ManuallyDrop, sync::Arc};
+use std::{
+	array,
+	collections::{HashMap, HashSet},
+	fmt::{self, Debug, Display},
+	iter,
+	mem::ManuallyDrop,
+	sync::Arc,
+};
 
 /// Identifier for a multilinear oracle in a [`MultilinearOracleSet`].
 pub type OracleId = usize;
@@ -40,11 +52,33 @@ enum MultilinearOracleMeta<F: TowerField> {
 		offset: usize,
 		block_bits: usize,
 		variant: ShiftVariant,
+		fill: F,
+	},
+	Shifted2D {
+		inner_id: OracleId,
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		variant: ShiftVariant,
 	},
 	Packed {
 		inner_id: OracleId,
 		log_degree: usize,
 	},
+	ZeroPadded {
+		inner_id: OracleId,
+		n_vars: usize,
+		block_index: usize,
+	},
+	PermutedVars {
+		inner_id: OracleId,
+		permutation: Vec<usize>,
+	},
+	Embedded {
+		inner_id: OracleId,
+		tower_level: usize,
+	},
 	Projected {
 		inner_id: OracleId,
 		values: Vec<F>,
@@ -65,10 +99,15 @@ enum MultilinearOracleMeta<F: TowerField> {
 ///
 /// The oracle set also tracks the committed polynomial in batches where each batch is committed
 /// together with a polynomial commitment scheme.
+///
+/// Oracles can optionally be given an [`OracleLabel`] with a unique name and a set of tags. Names
+/// appear in error messages, tracing spans, and in the [`Display`] dump of the oracle set.
 #[derive(Debug, Clone)]
 pub struct MultilinearOracleSet<F: TowerField> {
 	batches: Vec<CommittedBatchMeta>,
 	oracles: Vec<MultilinearOracleMeta<F>>,
+	labels: HashMap<OracleId, OracleLabel>,
+	names: HashMap<String, OracleId>,
 }
 
 impl<F: TowerField> MultilinearOracleSet<F> {
@@ -77,6 +116,8 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		Self {
 			batches: Vec::new(),
 			oracles: Vec::new(),
+			labels: HashMap::new(),
+			names: HashMap::new(),
 		}
 	}
 
@@ -86,6 +127,220 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		id
 	}
 
+	/// Returns the number of oracles in the set.
+	///
+	/// Oracle IDs are assigned sequentially, so the valid IDs are `0..self.size()`.
+	pub fn size(&self) -> usize {
+		self.oracles.len()
+	}
+
+	/// Returns an adder that gives the next added oracle the label.
+	///
+	/// ## Example
+	///
+	/// ```ignore
+	/// let carry = oracles
+	/// 	.labeled(OracleLabel::new("carry").with_tag("u32add"))
+	/// 	.add_shifted(cout, 1, 5, ShiftVariant::LogicalLeft)?;
+	/// ```
+	pub fn labeled(&mut self, label: impl Into<OracleLabel>) -> LabeledOracleAdder<F> {
+		LabeledOracleAdder::new(self, label.into())
+	}
+
+	/// Attach a label to an existing oracle, replacing any previous label.
+	///
+	/// ## Throws
+	///
+	/// * `Error::InvalidOracleId` if the oracle does not exist
+	/// * `Error::DuplicateOracleName` if another oracle already has the label's name
+	pub fn set_label(&mut self, id: OracleId, label: impl Into<OracleLabel>) -> Result<(), Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+		self.insert_label(id, label.into())
+	}
+
+	/// Check that the label's name is not taken by any oracle.
+	pub(super) fn check_label(&self, label: &OracleLabel) -> Result<(), Error> {
+		match &label.name {
+			Some(name) if self.names.contains_key(name) => {
+				Err(Error::DuplicateOracleName { name: name.clone() })
+			}
+			_ => Ok(()),
+		}
+	}
+
+	fn insert_label(&mut self, id: OracleId, label: OracleLabel) -> Result<(), Error> {
+		if let Some(name) = &label.name {
+			if self.names.get(name).is_some_and(|&other_id| other_id != id) {
+				return Err(Error::DuplicateOracleName { name: name.clone() });
+			}
+		}
+		if let Some(old_name) = self.labels.remove(&id).and_then(|label| label.name) {
+			self.names.remove(&old_name);
+		}
+		if let Some(name) = &label.name {
+			self.names.insert(name.clone(), id);
+		}
+		self.labels.insert(id, label);
+		Ok(())
+	}
+
+	pub fn label(&self, id: OracleId) -> Option<&OracleLabel> {
+		self.labels.get(&id)
+	}
+
+	pub fn name(&self, id: OracleId) -> Option<&str> {
+		self.label(id)?.name.as_deref()
+	}
+
+	/// Look up an oracle by the name in its label.
+	pub fn oracle_id_by_name(&self, name: &str) -> Option<OracleId> {
+		self.names.get(name).copied()
+	}
+
+	/// Returns the IDs of all oracles with the given tag, in increasing order.
+	pub fn oracle_ids_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = OracleId> + 'a {
+		(0..self.oracles.len()).filter(move |id| {
+			self.label(*id)
+				.is_some_and(|label| label.tags.iter().any(|t| t == tag))
+		})
+	}
+
+	/// Returns a value that formats the oracle ID together with its name, if it has one.
+	pub fn display_oracle(&self, id: OracleId) -> OracleDisplay {
+		OracleDisplay {
+			id,
+			name: self.name(id),
+		}
+	}
+
+	/// Describe an oracle as a node of the oracle graph, for the dump and export formats.
+	pub(super) fn node(&self, id: OracleId) -> OracleNode {
+		use MultilinearOracleMeta::*;
+
+		let input = |id: OracleId| OracleNodeInput { id, coeff: None };
+		let (kind, inputs, params) = match &self.oracles[id] {
+			Transparent(poly) => {
+				("transparent", vec![], vec![("poly", NodeParam::Str(format!("{poly:?}")))])
+			}
+			Committed(CommittedId { batch_id, index }) => (
+				"committed",
+				vec![],
+				vec![
+					("batch_id", NodeParam::Usize(*batch_id)),
+					("index", NodeParam::Usize(*index)),
+				],
+			),
+			Repeating {
+				inner_id,
+				log_count,
+			} => (
+				"repeating",
+				vec![input(*inner_id)],
+				vec![("log_count", NodeParam::Usize(*log_count))],
+			),
+			Interleaved(inner_id_0, inner_id_1) => {
+				("interleaved", vec![input(*inner_id_0), input(*inner_id_1)], vec![])
+			}
+			Merged(inner_id_0, inner_id_1) => {
+				("merged", vec![input(*inner_id_0), input(*inner_id_1)], vec![])
+			}
+			Shifted {
+				inner_id,
+				offset,
+				block_bits,
+				variant,
+				fill,
+			} => {
+				let mut params = vec![
+					("offset", NodeParam::Usize(*offset)),
+					("block_bits", NodeParam::Usize(*block_bits)),
+					("variant", NodeParam::Str(format!("{variant:?}"))),
+				];
+				if *fill != F::ZERO {
+					params.push(("fill", NodeParam::Str(format!("{fill:?}"))));
+				}
+				("shifted", vec![input(*inner_id)], params)
+			}
+			Shifted2D {
+				inner_id,
+				col_bits,
+				row_bits,
+				col_offset,
+				row_offset,
+				variant,
+			} => (
+				"shifted_2d",
+				vec![input(*inner_id)],
+				vec![
+					("col_bits", NodeParam::Usize(*col_bits)),
+					("row_bits", NodeParam::Usize(*row_bits)),
+					("col_offset", NodeParam::Usize(*col_offset)),
+					("row_offset", NodeParam::Usize(*row_offset)),
+					("variant", NodeParam::Str(format!("{variant:?}"))),
+				],
+			),
+			Packed {
+				inner_id,
+				log_degree,
+			} => (
+				"packed",
+				vec![input(*inner_id)],
+				vec![("log_degree", NodeParam::Usize(*log_degree))],
+			),
+			ZeroPadded {
+				inner_id,
+				block_index,
+				..
+			} => (
+				"zero_padded",
+				vec![input(*inner_id)],
+				vec![("block_index", NodeParam::Usize(*block_index))],
+			),
+			PermutedVars {
+				inner_id,
+				permutation,
+			} => (
+				"permuted_vars",
+				vec![input(*inner_id)],
+				vec![("permutation", NodeParam::Str(format!("{permutation:?}")))],
+			),
+			Embedded { inner_id, .. } => ("embedded", vec![input(*inner_id)], vec![]),
+			Projected {
+				inner_id,
+				values,
+				variant,
+			} => (
+				"projected",
+				vec![input(*inner_id)],
+				vec![
+					("values", NodeParam::Str(format!("{values:?}"))),
+					("variant", NodeParam::Str(format!("{variant:?}"))),
+				],
+			),
+			LinearCombination { offset, inner, .. } => (
+				"linear_combination",
+				inner
+					.iter()
+					.map(|(inner_id, coeff)| OracleNodeInput {
+						id: *inner_id,
+						coeff: Some(format!("{coeff:?}")),
+					})
+					.collect(),
+				vec![("offset", NodeParam::Str(format!("{offset:?}")))],
+			),
+		};
+
+		OracleNode {
+			kind,
+			n_vars: self.n_vars(id),
+			tower_level: self.tower_level(id),
+			inputs,
+			params,
+		}
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		poly: impl MultivariatePoly<F> + 'static,
@@ -182,6 +437,27 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		offset: usize,
 		block_bits: usize,
 		variant: ShiftVariant,
+	) -> Result<OracleId, Error> {
+		self.add_shifted_with_fill(id, offset, block_bits, variant, F::ZERO)
+	}
+
+	/// Shift an oracle within blocks of `2^block_bits` values, filling the vacated positions with
+	/// `fill`.
+	///
+	/// This is only meaningful for the logical shift variants, as circular shifts vacate no
+	/// positions. With `block_bits` equal to the number of variables of the inner oracle, the
+	/// shift moves values across the whole column instead of within each block.
+	///
+	/// ## Throws
+	///
+	/// * `Error::CircularShiftWithFill` if `fill` is nonzero and `variant` is circular
+	pub fn add_shifted_with_fill(
+		&mut self,
+		id: OracleId,
+		offset: usize,
+		block_bits: usize,
+		variant: ShiftVariant,
+		fill: F,
 	) -> Result<OracleId, Error> {
 		if id >= self.oracles.len() {
 			return Err(Error::InvalidOracleId(id));
@@ -203,11 +479,49 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 			.into());
 		}
 
+		if variant.is_circular() && fill != F::ZERO {
+			return Err(Error::CircularShiftWithFill);
+		}
+
 		let id = self.add(MultilinearOracleMeta::Shifted {
 			inner_id: id,
 			offset,
 			block_bits,
 			variant,
+			fill,
+		});
+		Ok(id)
+	}
+
+	/// Shift an oracle whose values are interpreted as a stack of matrices along both axes.
+	///
+	/// The low `col_bits` variables index the column and the next `row_bits` variables index the
+	/// row, so each block of `2^(col_bits + row_bits)` values is a row-major matrix with
+	/// `2^row_bits` rows and `2^col_bits` columns. Every matrix is shifted by `row_offset` rows
+	/// and `col_offset` columns, where `variant` determines the direction and whether the shift
+	/// wraps around in each dimension. One of the offsets may be zero.
+	pub fn add_shifted_2d(
+		&mut self,
+		id: OracleId,
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		variant: ShiftVariant,
+	) -> Result<OracleId, Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+
+		let inner = self.oracle(id);
+		let shifted = Shifted2D::new(inner, col_bits, row_bits, col_offset, row_offset, variant)?;
+		let id = self.add(MultilinearOracleMeta::Shifted2D {
+			inner_id: id,
+			col_bits: shifted.col_bits,
+			row_bits: shifted.row_bits,
+			col_offset: shifted.col_offset,
+			row_offset: shifted.row_offset,
+			variant: shifted.shift_variant,
 		});
 		Ok(id)
 	}
@@ -233,6 +547,98 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		Ok(id)
 	}
 
+	/// Embed an oracle into a larger hypercube with `n_vars` variables, padding it with zeros.
+	///
+	/// The inner oracle occupies the low variables of the result, and the remaining high variables
+	/// select one of `2^(n_vars - inner_n_vars)` blocks. The values of the inner oracle are placed
+	/// in the block with index `block_index`, and all other blocks are zero.
+	pub fn add_zero_padded(
+		&mut self,
+		id: OracleId,
+		n_vars: usize,
+		block_index: usize,
+	) -> Result<OracleId, Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+
+		let inner_n_vars = self.n_vars(id);
+		if n_vars < inner_n_vars || block_index >= 1 << (n_vars - inner_n_vars) {
+			return Err(Error::InvalidZeroPadding {
+				n_vars,
+				inner_n_vars,
+				block_index,
+			});
+		}
+
+		let id = self.add(MultilinearOracleMeta::ZeroPadded {
+			inner_id: id,
+			n_vars,
+			block_index,
+		});
+		Ok(id)
+	}
+
+	/// Reorder the variables of an oracle.
+	///
+	/// Variable `i` of the result is variable `permutation[i]` of the inner oracle, so the
+	/// hypercube vertex with bits `b_i` of the result is the vertex with bits `b_i` moved to
+	/// positions `permutation[i]` of the inner oracle. For example, reversing the variables
+	/// reverses the bits of the hypercube indices, and rotating them transposes a row-major trace
+	/// into a column-major one.
+	pub fn add_permuted_vars(
+		&mut self,
+		id: OracleId,
+		permutation: Vec<usize>,
+	) -> Result<OracleId, Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+
+		let inner_n_vars = self.n_vars(id);
+		if !is_permutation(&permutation, inner_n_vars) {
+			return Err(Error::InvalidVariablePermutation {
+				permutation,
+				n_vars: inner_n_vars,
+			});
+		}
+
+		let id = self.add(MultilinearOracleMeta::PermutedVars {
+			inner_id: id,
+			permutation,
+		});
+		Ok(id)
+	}
+
+	/// Embed the values of an oracle into the binary tower field of level `tower_level`.
+	///
+	/// The result has the same values as the inner oracle, but is typed at a higher tower level.
+	/// Unlike [`Self::add_packed`], each value is embedded into its own extension field element.
+	/// This is useful to bring the inputs of a linear combination to a common tower level, so
+	/// that its witness can be stored and committed at a single level.
+	pub fn add_embedded(&mut self, id: OracleId, tower_level: usize) -> Result<OracleId, Error> {
+		if id >= self.oracles.len() {
+			return Err(Error::InvalidOracleId(id));
+		}
+		if tower_level > F::TOWER_LEVEL {
+			return Err(Error::TowerLevelTooHigh { tower_level });
+		}
+
+		let inner_tower_level = self.tower_level(id);
+		if tower_level < inner_tower_level {
+			return Err(Error::InvalidEmbedding {
+				tower_level,
+				inner_tower_level,
+			});
+		}
+
+		let id = self.add(MultilinearOracleMeta::Embedded {
+			inner_id: id,
+			tower_level,
+		});
+		Ok(id)
+	}
+
 	pub fn add_projected(
 		&mut self,
 		id: OracleId,
@@ -376,10 +782,31 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 				offset,
 				block_bits,
 				variant,
+				fill,
 			} => MultilinearPolyOracle::Shifted(
 				id,
 				Shifted::new(self.oracle(*inner_id), *offset, *block_bits, *variant)
-					.expect("shift parameters validated by add_shifted"),
+					.expect("shift parameters validated by add_shifted")
+					.with_fill(*fill),
+			),
+			MultilinearOracleMeta::Shifted2D {
+				inner_id,
+				col_bits,
+				row_bits,
+				col_offset,
+				row_offset,
+				variant,
+			} => MultilinearPolyOracle::Shifted2D(
+				id,
+				Shifted2D::new(
+					self.oracle(*inner_id),
+					*col_bits,
+					*row_bits,
+					*col_offset,
+					*row_offset,
+					*variant,
+				)
+				.expect("shift parameters validated by add_shifted_2d"),
 			),
 			MultilinearOracleMeta::Packed {
 				inner_id,
@@ -391,6 +818,38 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 					log_degree: *log_degree,
 				},
 			),
+			MultilinearOracleMeta::ZeroPadded {
+				inner_id,
+				n_vars,
+				block_index,
+			} => MultilinearPolyOracle::ZeroPadded(
+				id,
+				ZeroPadded {
+					inner: Box::new(self.oracle(*inner_id)),
+					n_vars: *n_vars,
+					block_index: *block_index,
+				},
+			),
+			MultilinearOracleMeta::PermutedVars {
+				inner_id,
+				permutation,
+			} => MultilinearPolyOracle::PermutedVars(
+				id,
+				PermutedVars {
+					inner: Box::new(self.oracle(*inner_id)),
+					permutation: permutation.clone(),
+				},
+			),
+			MultilinearOracleMeta::Embedded {
+				inner_id,
+				tower_level,
+			} => MultilinearPolyOracle::Embedded(
+				id,
+				Embedded {
+					inner: Box::new(self.oracle(*inner_id)),
+					tower_level: *tower_level,
+				},
+			),
 			MultilinearOracleMeta::Projected {
 				inner_id,
 				values,
@@ -430,10 +889,14 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 			Interleaved(inner_id_0, _) => self.n_vars(*inner_id_0) + 1,
 			Merged(inner_id_0, _) => self.n_vars(*inner_id_0) + 1,
 			Shifted { inner_id, .. } => self.n_vars(*inner_id),
+			Shifted2D { inner_id, .. } => self.n_vars(*inner_id),
 			Packed {
 				inner_id,
 				log_degree,
 			} => self.n_vars(*inner_id) - log_degree,
+			ZeroPadded { n_vars, .. } => *n_vars,
+			PermutedVars { permutation, .. } => permutation.len(),
+			Embedded { inner_id, .. } => self.n_vars(*inner_id),
 			Projected {
 				inner_id, values, ..
 			} => self.n_vars(*inner_id) - values.len(),
@@ -454,19 +917,54 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 			Merged(inner_id_0, inner_id_1) => self
 				.tower_level(*inner_id_0)
 				.max(self.tower_level(*inner_id_1)),
-			Shifted { inner_id, .. } => self.tower_level(*inner_id),
+			// A nonzero fill value may raise the tower level
+			Shifted { inner_id, fill, .. } if *fill == F::ZERO => self.tower_level(*inner_id),
+			Shifted { .. } => F::TOWER_LEVEL,
+			Shifted2D { inner_id, .. } => self.tower_level(*inner_id),
 			Packed {
 				inner_id,
 				log_degree,
 			} => self.tower_level(*inner_id) + log_degree,
+			ZeroPadded { inner_id, .. } => self.tower_level(*inner_id),
+			PermutedVars { inner_id, .. } => self.tower_level(*inner_id),
+			Embedded { tower_level, .. } => *tower_level,
 			Projected { .. } => F::TOWER_LEVEL,
-			// TODO: We can derive this more tightly by inspecting the coefficients and inner
-			// polynomials.
-			LinearCombination { .. } => F::TOWER_LEVEL,
+			LinearCombination { offset, inner, .. } => inner
+				.iter()
+				.map(|(inner_id, coeff)| self.tower_level(*inner_id).max(coeff.min_tower_level()))
+				.fold(offset.min_tower_level(), usize::max),
 		}
 	}
 }
 
+/// Dumps the oracle graph, one oracle per line.
+impl<F: TowerField> Display for MultilinearOracleSet<F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for id in 0..self.oracles.len() {
+			let node = self.node(id);
+			write!(f, "{}", self.display_oracle(id))?;
+			if let Some(label) = self.label(id).filter(|label| !label.tags.is_empty()) {
+				write!(f, " [{}]", label.tags.join(", "))?;
+			}
+			write!(f, ": {}", node.kind)?;
+			for (i, input) in node.inputs.iter().enumerate() {
+				let separator = if i == 0 { " " } else { ", " };
+				write!(f, "{separator}")?;
+				if let Some(coeff) = &input.coeff {
+					write!(f, "{coeff} * ")?;
+				}
+				write!(f, "{}", self.display_oracle(input.id))?;
+			}
+			write!(f, ", n_vars={}, tower_level={}", node.n_vars, node.tower_level)?;
+			for (key, value) in node.params.iter() {
+				write!(f, ", {key}={value}")?;
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
+
 /// A multilinear polynomial oracle in the polynomial IOP model.
 ///
 /// In the multilinear polynomial IOP model, a prover sends multilinear polynomials to an oracle,
@@ -505,7 +1003,11 @@ pub enum MultilinearPolyOracle<F: Field> {
 	Merged(OracleId, Box<MultilinearPolyOracle<F>>, Box<MultilinearPolyOracle<F>>),
 	Projected(OracleId, Projected<F>),
 	Shifted(OracleId, Shifted<F>),
+	Shifted2D(OracleId, Shifted2D<F>),
 	Packed(OracleId, Packed<F>),
+	ZeroPadded(OracleId, ZeroPadded<F>),
+	PermutedVars(OracleId, PermutedVars<F>),
+	Embedded(OracleId, Embedded<F>),
 	LinearCombination(OracleId, LinearCombination<F>),
 }
 
@@ -583,13 +1085,29 @@ impl<F: Field> Projected<F> {
 	}
 }
 
+/// The direction of a shift, and whether values wrap around at the block boundaries.
+///
+/// With `x` the index within a block of size `2^b` and `o` the shift offset, the shifted value at
+/// `x` is the inner value at:
+///
+/// * `CircularLeft`: `(x + o) mod 2^b`
+/// * `CircularRight`: `(x - o) mod 2^b`
+/// * `LogicalLeft`: `x + o`, or the fill value if `x + o >= 2^b`
+/// * `LogicalRight`: `x - o`, or the fill value if `x < o`
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ShiftVariant {
 	CircularLeft,
+	CircularRight,
 	LogicalLeft,
 	LogicalRight,
 }
 
+impl ShiftVariant {
+	pub fn is_circular(&self) -> bool {
+		matches!(self, Self::CircularLeft | Self::CircularRight)
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub struct Shifted<F: Field> {
 	inner: Box<MultilinearPolyOracle<F>>,
@@ -599,6 +1117,9 @@ pub struct Shifted<F: Field> {
 	block_size: usize,
 	#[get_copy = "pub"]
 	shift_variant: ShiftVariant,
+	/// The value of the positions vacated by a logical shift.
+	#[get_copy = "pub"]
+	fill: F,
 }
 
 impl<F: Field> Shifted<F> {
@@ -628,12 +1149,110 @@ impl<F: Field> Shifted<F> {
 			shift_offset,
 			block_size,
 			shift_variant,
+			fill: F::ZERO,
 		})
 	}
 
+	fn with_fill(self, fill: F) -> Self {
+		Self { fill, ..self }
+	}
+
 	pub fn inner(&self) -> &MultilinearPolyOracle<F> {
 		&self.inner
 	}
+
+	/// Evaluates the contribution of the fill value to the shifted oracle at a point.
+	///
+	/// This is the fill value times the multilinear extension of the indicator of the vacated
+	/// positions. The shifted oracle evaluates to the shift with zero fill plus this term.
+	pub fn fill_eval(&self, eval_point: &[F]) -> Result<F, PolynomialError> {
+		if self.fill == F::ZERO {
+			return Ok(F::ZERO);
+		}
+
+		let block_point = &eval_point[..self.block_size];
+		let vacated = match self.shift_variant {
+			ShiftVariant::CircularLeft | ShiftVariant::CircularRight => F::ZERO,
+			// The last `shift_offset` positions of each block are vacated
+			ShiftVariant::LogicalLeft => {
+				let index = (1 << self.block_size) - self.shift_offset;
+				F::ONE - StepDown::new(self.block_size, index)?.evaluate(block_point)?
+			}
+			// The first `shift_offset` positions of each block are vacated
+			ShiftVariant::LogicalRight => {
+				StepDown::new(self.block_size, self.shift_offset)?.evaluate(block_point)?
+			}
+		};
+		Ok(self.fill * vacated)
+	}
+}
+
+/// An oracle shifted along both axes of a matrix interpretation of the hypercube.
+///
+/// See [`MultilinearOracleSet::add_shifted_2d`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Shifted2D<F: Field> {
+	#[get = "pub"]
+	inner: Box<MultilinearPolyOracle<F>>,
+	#[get_copy = "pub"]
+	col_bits: usize,
+	#[get_copy = "pub"]
+	row_bits: usize,
+	#[get_copy = "pub"]
+	col_offset: usize,
+	#[get_copy = "pub"]
+	row_offset: usize,
+	#[get_copy = "pub"]
+	shift_variant: ShiftVariant,
+}
+
+impl<F: Field> Shifted2D<F> {
+	fn new(
+		inner: MultilinearPolyOracle<F>,
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		shift_variant: ShiftVariant,
+	) -> Result<Self, Error> {
+		if col_bits + row_bits > inner.n_vars() {
+			return Err(PolynomialError::InvalidBlockSize {
+				n_vars: inner.n_vars(),
+			}
+			.into());
+		}
+
+		for (bits, offset) in [(col_bits, col_offset), (row_bits, row_offset)] {
+			if offset >= 1 << bits {
+				return Err(PolynomialError::InvalidShiftOffset {
+					max_shift_offset: (1 << bits) - 1,
+					shift_offset: offset,
+				}
+				.into());
+			}
+		}
+		if col_offset == 0 && row_offset == 0 {
+			return Err(PolynomialError::InvalidShiftOffset {
+				max_shift_offset: (1 << col_bits) - 1,
+				shift_offset: 0,
+			}
+			.into());
+		}
+
+		Ok(Self {
+			inner: inner.into(),
+			col_bits,
+			row_bits,
+			col_offset,
+			row_offset,
+			shift_variant,
+		})
+	}
+
+	/// The number of low variables of a matrix, which is the block size of the shift.
+	pub fn block_size(&self) -> usize {
+		self.col_bits + self.row_bits
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
@@ -650,6 +1269,62 @@ pub struct Packed<F: Field> {
 	log_degree: usize,
 }
 
+/// An oracle embedded into a larger hypercube with zero padding.
+///
+/// See [`MultilinearOracleSet::add_zero_padded`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct ZeroPadded<F: Field> {
+	#[get = "pub"]
+	inner: Box<MultilinearPolyOracle<F>>,
+	#[get_copy = "pub"]
+	n_vars: usize,
+	/// Index of the block, selected by the high variables, that holds the inner oracle.
+	#[get_copy = "pub"]
+	block_index: usize,
+}
+
+/// An oracle with reordered variables.
+///
+/// See [`MultilinearOracleSet::add_permuted_vars`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct PermutedVars<F: Field> {
+	#[get = "pub"]
+	inner: Box<MultilinearPolyOracle<F>>,
+	/// Variable `i` of this oracle is variable `permutation[i]` of the inner oracle.
+	#[get = "pub"]
+	permutation: Vec<usize>,
+}
+
+impl<F: Field> PermutedVars<F> {
+	/// Maps an evaluation point of this oracle to the equivalent point of the inner oracle.
+	pub fn inner_eval_point(&self, eval_point: &[F]) -> Vec<F> {
+		let mut inner_eval_point = vec![F::ZERO; eval_point.len()];
+		for (&inner_var, &coord) in self.permutation.iter().zip(eval_point) {
+			inner_eval_point[inner_var] = coord;
+		}
+		inner_eval_point
+	}
+}
+
+/// An oracle with the values of its inner oracle, embedded into a higher tower level.
+///
+/// See [`MultilinearOracleSet::add_embedded`].
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Embedded<F: Field> {
+	#[get = "pub"]
+	inner: Box<MultilinearPolyOracle<F>>,
+	#[get_copy = "pub"]
+	tower_level: usize,
+}
+
+fn is_permutation(permutation: &[usize], n: usize) -> bool {
+	let mut seen = vec![false; n];
+	permutation.len() == n
+		&& permutation
+			.iter()
+			.all(|&i| i < n && !std::mem::replace(&mut seen[i], true))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub struct LinearCombination<F: Field> {
 	#[get_copy = "pub"]
@@ -706,7 +1381,11 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Merged(id, ..) => *id,
 			Projected(id, _) => *id,
 			Shifted(id, _) => *id,
+			Shifted2D(id, _) => *id,
 			Packed(id, _) => *id,
+			ZeroPadded(id, _) => *id,
+			PermutedVars(id, _) => *id,
+			Embedded(id, _) => *id,
 			LinearCombination(id, _) => *id,
 		}
 	}
@@ -723,11 +1402,23 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Merged(_, poly0, ..) => 1 + poly0.n_vars(),
 			Projected(_, projected) => projected.n_vars(),
 			Shifted(_, shifted) => shifted.inner().n_vars(),
+			Shifted2D(_, shifted) => shifted.inner().n_vars(),
 			Packed(_, packed) => packed.inner().n_vars() - packed.log_degree(),
+			ZeroPadded(_, padded) => padded.n_vars(),
+			PermutedVars(_, permuted) => permuted.permutation().len(),
+			Embedded(_, embedded) => embedded.inner().n_vars(),
 			LinearCombination(_, lin_com) => lin_com.n_vars,
 		}
 	}
 
+	pub fn into_composite(self) -> CompositePolyOracle<F> {
+		let composite =
+			CompositePolyOracle::new(self.n_vars(), vec![self], IdentityCompositionPoly);
+		composite.expect("Can always apply the identity composition to one variable")
+	}
+}
+
+impl<F: TowerField> MultilinearPolyOracle<F> {
 	/// Maximum tower level of the oracle's values over the boolean hypercube.
 	pub fn binary_tower_level(&self) -> usize {
 		use MultilinearPolyOracle::*;
@@ -741,22 +1432,22 @@ impl<F: Field> MultilinearPolyOracle<F> {
 			Merged(_, poly0, poly1) => poly0.binary_tower_level().max(poly1.binary_tower_level()),
 			// TODO: This is wrong, should be F::TOWER_LEVEL
 			Projected(_, projected) => projected.inner().binary_tower_level(),
-			Shifted(_, shifted) => shifted.inner().binary_tower_level(),
+			Shifted(_, shifted) if shifted.fill() == F::ZERO => {
+				shifted.inner().binary_tower_level()
+			}
+			Shifted(..) => F::TOWER_LEVEL,
+			Shifted2D(_, shifted) => shifted.inner().binary_tower_level(),
 			Packed(_, packed) => packed.log_degree + packed.inner().binary_tower_level(),
+			ZeroPadded(_, padded) => padded.inner().binary_tower_level(),
+			PermutedVars(_, permuted) => permuted.inner().binary_tower_level(),
+			Embedded(_, embedded) => embedded.tower_level(),
 			LinearCombination(_, lin_com) => lin_com
 				.inner
 				.iter()
-				.map(|(poly, _)| poly.binary_tower_level())
-				.max()
-				.unwrap_or(0),
+				.map(|(poly, coeff)| poly.binary_tower_level().max(coeff.min_tower_level()))
+				.fold(lin_com.offset.min_tower_level(), usize::max),
 		}
 	}
-
-	pub fn into_composite(self) -> CompositePolyOracle<F> {
-		let composite =
-			CompositePolyOracle::new(self.n_vars(), vec![self], IdentityCompositionPoly);
-		composite.expect("Can always apply the identity composition to one variable")
-	}
 }
 
 pub struct CommittedBatchBuildScope<'a, F: TowerField> {
@@ -780,6 +1471,40 @@ impl<'a, F: TowerField> CommittedBatchBuildScope<'a, F> {
 		oracle_ids
 	}
 
+	/// Like [`Self::add_one`], and give the oracle a label.
+	pub fn add_one_labeled(&mut self, label: impl Into<OracleLabel>) -> Result<OracleId, Error> {
+		let label = label.into();
+		self.oracles.check_label(&label)?;
+		let oracle_id = self.add_one();
+		self.oracles.insert_label(oracle_id, label)?;
+		Ok(oracle_id)
+	}
+
+	/// Like [`Self::add_multiple`], and give the oracles labels.
+	///
+	/// The labels are checked before any oracle is added, so on error the batch is unchanged.
+	pub fn add_multiple_labeled<const N: usize>(
+		&mut self,
+		labels: [impl Into<OracleLabel>; N],
+	) -> Result<[OracleId; N], Error> {
+		let labels = labels.map(Into::into);
+		let mut names = HashSet::new();
+		for label in labels.iter() {
+			self.oracles.check_label(label)?;
+			if let Some(name) = &label.name {
+				if !names.insert(name) {
+					return Err(Error::DuplicateOracleName { name: name.clone() });
+				}
+			}
+		}
+
+		let oracle_ids = self.add_multiple::<N>();
+		for (oracle_id, label) in iter::zip(oracle_ids, labels) {
+			self.oracles.insert_label(oracle_id, label)?;
+		}
+		Ok(oracle_ids)
+	}
+
 	pub fn build(self) -> BatchId {
 		let batch_id = self.oracles.add_committed_batch(CommittedBatchSpec {
 			n_vars: self.n_vars,
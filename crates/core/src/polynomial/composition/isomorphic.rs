@@ -0,0 +1,98 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::polynomial::{CompositionPoly, Error};
+use binius_field::{Field, PackedField};
+use std::sync::Arc;
+
+/// A composition polynomial over `F` evaluated on packed elements of an isomorphic field.
+///
+/// Composite oracles store their compositions as `CompositionPoly<F>` trait objects, whereas the
+/// prover witness is often packed over a different but isomorphic field, like
+/// `BinaryField128bPolyval` for `BinaryField128b`. This adapter evaluates the packed query lane by
+/// lane, mapping the scalars into `F` and the results back. It is noticeably slower than a
+/// composition implemented natively over the packed field, so it is intended for generic drivers
+/// that only have access to the type-erased composition.
+#[derive(Clone, Debug)]
+pub struct IsomorphicComposition<F: Field> {
+	inner: Arc<dyn CompositionPoly<F>>,
+}
+
+impl<F: Field> IsomorphicComposition<F> {
+	pub fn new(inner: Arc<dyn CompositionPoly<F>>) -> Self {
+		Self { inner }
+	}
+}
+
+impl<F, P> CompositionPoly<P> for IsomorphicComposition<F>
+where
+	F: Field + From<P::Scalar>,
+	P: PackedField<Scalar: From<F>>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.inner.degree()
+	}
+
+	fn evaluate_scalar(&self, query: &[P::Scalar]) -> Result<P::Scalar, Error> {
+		let query = query.iter().map(|&x| F::from(x)).collect::<Vec<_>>();
+		Ok(self.inner.evaluate_scalar(&query)?.into())
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
+		if query.len() != self.inner.n_vars() {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.inner.n_vars(),
+			});
+		}
+
+		let mut lane_query = vec![F::ZERO; query.len()];
+		let mut result = P::zero();
+		for i in 0..P::WIDTH {
+			for (lane_value, packed) in lane_query.iter_mut().zip(query) {
+				*lane_value = F::from(packed.get(i));
+			}
+			result.set(i, self.inner.evaluate_scalar(&lane_query)?.into());
+		}
+		Ok(result)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.inner.binary_tower_level()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::polynomial::composition::BivariateProduct;
+	use binius_field::{
+		arch::packed_polyval_256::PackedBinaryPolyval2x128b, BinaryField128b,
+		BinaryField128bPolyval,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	#[test]
+	fn test_isomorphic_composition_matches_native() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let composition = IsomorphicComposition::<BinaryField128b>::new(Arc::new(BivariateProduct));
+
+		let a = PackedBinaryPolyval2x128b::random(&mut rng);
+		let b = PackedBinaryPolyval2x128b::random(&mut rng);
+
+		let result =
+			CompositionPoly::<PackedBinaryPolyval2x128b>::evaluate(&composition, &[a, b]).unwrap();
+		assert_eq!(result, a * b);
+
+		let scalar_result = CompositionPoly::<BinaryField128bPolyval>::evaluate_scalar(
+			&composition,
+			&[a.get(0), b.get(0)],
+		)
+		.unwrap();
+		assert_eq!(scalar_result, a.get(0) * b.get(0));
+
+		assert!(CompositionPoly::<PackedBinaryPolyval2x128b>::evaluate(&composition, &[a]).is_err());
+	}
+}
@@ -2,8 +2,10 @@
 
 pub mod bivariate_product;
 pub mod index;
+pub mod isomorphic;
 pub mod mix;
 
 pub use bivariate_product::*;
 pub use index::*;
+pub use isomorphic::*;
 pub use mix::*;
@@ -1,5 +1,6 @@
 // Copyright 2023 Ulvetanna Inc.
 
+use super::univariate_poly::UnivariateBasis;
 use binius_field::Error as FieldError;
 use std::ops::Range;
 
@@ -28,6 +29,12 @@ pub enum Error {
 		max_shift_offset: usize,
 		shift_offset: usize,
 	},
+	#[error("univariate polynomials must be in the same basis")]
+	UnivariateBasisMismatch,
+	#[error("operation is not supported for univariate polynomials in {basis:?} basis")]
+	UnsupportedUnivariateBasis { basis: UnivariateBasis },
+	#[error("division by the zero polynomial")]
+	DivisionByZeroPolynomial,
 	#[error("duplicate point in domain")]
 	DuplicateDomainPoint,
 	#[error("argument length must be a power of two")]
@@ -36,6 +43,8 @@ pub enum Error {
 	TooManyVariables,
 	#[error("indexed point on hypercube is out of range: index={index}")]
 	HypercubeIndexOutOfRange { index: usize },
+	#[error("indexed point on hypercube occurs more than once: index={index}")]
+	DuplicateHypercubeIndex { index: usize },
 	#[error("MultilinearQuery is full, cannot update further. Has {max_query_vars} variables")]
 	MultilinearQueryFull { max_query_vars: usize },
 	#[error("mixed polynomial was not provided")]
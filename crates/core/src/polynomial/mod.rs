@@ -6,8 +6,10 @@ pub mod multilinear;
 pub mod multilinear_extension;
 pub mod multilinear_query;
 pub mod multivariate;
+pub mod structured;
 pub mod transparent;
 pub mod univariate;
+pub mod univariate_poly;
 pub mod util;
 
 pub use error::*;
@@ -15,4 +17,6 @@ pub use multilinear::*;
 pub use multilinear_extension::*;
 pub use multilinear_query::*;
 pub use multivariate::*;
+pub use structured::*;
 pub use univariate::*;
+pub use univariate_poly::*;
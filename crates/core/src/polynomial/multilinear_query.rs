@@ -42,6 +42,11 @@ impl<P: PackedField> MultilinearQuery<P> {
 		self.n_vars
 	}
 
+	/// The number of scalars allocated for the expansion, which fits `max_query_vars` variables.
+	pub fn capacity(&self) -> usize {
+		self.expanded_query.capacity() * P::WIDTH
+	}
+
 	/// Returns the tensor product expansion of the query
 	///
 	/// If the number of query variables is less than the packing width, return a single packed element.
@@ -0,0 +1,685 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Multilinear polynomials with structured hypercube evaluations.
+//!
+//! Witness columns that are constant, periodic, or mostly zero do not need to be stored as dense
+//! vectors of evaluations over the hypercube. The types in this module store only the data that
+//! determines the polynomial and implement [`MultilinearPoly`] directly on that representation,
+//! so that they can be passed to the sumcheck provers as they are. Partial evaluations return
+//! dense multilinear extensions, but their cost scales with the size of the output rather than
+//! with the size of the hypercube.
+
+use super::{
+	error::Error, multilinear::MultilinearPoly, multilinear_extension::MultilinearExtension,
+	multilinear_query::MultilinearQuery, MultilinearExtensionSpecialized,
+};
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	ExtensionField, Field, PackedField,
+};
+use binius_utils::array_2d::Array2D;
+use p3_util::log2_strict_usize;
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// A multilinear polynomial that takes the same value at every vertex of the hypercube.
+///
+/// The multilinear extension of a constant function is constant, so every evaluation and partial
+/// evaluation of this polynomial is the stored value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantMultilinear<F: Field> {
+	n_vars: usize,
+	value: F,
+}
+
+impl<F: Field> ConstantMultilinear<F> {
+	pub fn new(n_vars: usize, value: F) -> Self {
+		Self { n_vars, value }
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn value(&self) -> F {
+		self.value
+	}
+}
+
+impl<F, PE> MultilinearPoly<PE> for ConstantMultilinear<F>
+where
+	F: Field,
+	PE: PackedField,
+	PE::Scalar: ExtensionField<F>,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn extension_degree(&self) -> usize {
+		<PE::Scalar as ExtensionField<F>>::DEGREE
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(self.value.into())
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: PE::Scalar,
+	) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(scalar * self.value)
+	}
+
+	fn evaluate(&self, query: &MultilinearQuery<PE>) -> Result<PE::Scalar, Error> {
+		check_full_query(self.n_vars, query)?;
+		Ok(self.value.into())
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		dense_from_fn(self.n_vars - query.n_vars(), |_| self.value.into())
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		dense_from_fn(self.n_vars - query.n_vars(), |_| self.value.into())
+	}
+
+	fn evaluate_subcube(
+		&self,
+		indices: Range<usize>,
+		query: &MultilinearQuery<PE>,
+		evals_0: &mut Array2D<PE::Scalar>,
+		evals_1: &mut Array2D<PE::Scalar>,
+		col_index: usize,
+	) -> Result<(), Error> {
+		check_subcube_args(self.n_vars, &indices, query, evals_0, evals_1, col_index)?;
+		// The tensor expansion of a query sums to one
+		for i in 0..indices.len() {
+			evals_0[(i, col_index)] = self.value.into();
+			evals_1[(i, col_index)] = self.value.into();
+		}
+		Ok(())
+	}
+
+	fn subcube_evals(&self, vars: usize, index: usize, dst: &mut [PE]) -> Result<(), Error> {
+		check_subcube_evals_args(self.n_vars, vars, index, dst)?;
+		dst.fill(PE::broadcast(self.value.into()));
+		Ok(())
+	}
+}
+
+/// A multilinear polynomial whose hypercube evaluations repeat a period of $2^k$ values.
+///
+/// The polynomial has $n \ge k$ variables and takes the value `period[i % 2^k]` at the vertex
+/// with index $i$, so it depends only on its $k$ low-indexed variables. Only the period is
+/// stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodicMultilinear<F: Field> {
+	n_vars: usize,
+	log_period: usize,
+	period: Vec<F>,
+}
+
+impl<F: Field> PeriodicMultilinear<F> {
+	pub fn new(n_vars: usize, period: Vec<F>) -> Result<Self, Error> {
+		if !period.len().is_power_of_two() {
+			return Err(Error::PowerOfTwoLengthRequired);
+		}
+		let log_period = log2_strict_usize(period.len());
+		if log_period > n_vars {
+			return Err(Error::ArgumentRangeError {
+				arg: "period.len()".into(),
+				range: 1..(1 << n_vars) + 1,
+			});
+		}
+		Ok(Self {
+			n_vars,
+			log_period,
+			period,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	pub fn period(&self) -> &[F] {
+		&self.period
+	}
+
+	fn value(&self, index: usize) -> F {
+		self.period[index % self.period.len()]
+	}
+
+	/// Evaluates the low-indexed variables at the query.
+	///
+	/// The result is the period of the partially evaluated polynomial, which has $2^{k - q}$
+	/// values, or a single value if $q \ge k$.
+	fn fold_low<PE>(&self, query: &MultilinearQuery<PE>) -> Vec<PE::Scalar>
+	where
+		PE: PackedField,
+		PE::Scalar: ExtensionField<F>,
+	{
+		let query_vars = query.n_vars();
+		(0..1 << self.log_period.saturating_sub(query_vars))
+			.into_par_iter()
+			.map(|i| {
+				(0..1 << query_vars)
+					.map(|j| query_coeff(query, j) * self.value((i << query_vars) | j))
+					.sum()
+			})
+			.collect()
+	}
+}
+
+impl<F, PE> MultilinearPoly<PE> for PeriodicMultilinear<F>
+where
+	F: Field,
+	PE: PackedField,
+	PE::Scalar: ExtensionField<F>,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn extension_degree(&self) -> usize {
+		<PE::Scalar as ExtensionField<F>>::DEGREE
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(self.value(index).into())
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: PE::Scalar,
+	) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(scalar * self.value(index))
+	}
+
+	fn evaluate(&self, query: &MultilinearQuery<PE>) -> Result<PE::Scalar, Error> {
+		check_full_query(self.n_vars, query)?;
+		Ok(self.fold_low(query)[0])
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		let folded = self.fold_low(query);
+		dense_from_fn(self.n_vars - query.n_vars(), |i| folded[i % folded.len()])
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		let new_n_vars = self.n_vars - query.n_vars();
+
+		// Only the low k - (n - q) query variables select values within the period, so the
+		// query expansion is summed over the remaining ones
+		let log_folded = self.log_period.saturating_sub(new_n_vars);
+		let mut folded = vec![PE::Scalar::ZERO; 1 << log_folded];
+		for j in 0..1 << query.n_vars() {
+			folded[j % folded.len()] += query_coeff(query, j);
+		}
+		dense_from_fn(new_n_vars, |i| {
+			folded
+				.iter()
+				.enumerate()
+				.map(|(j, &coeff)| coeff * self.value((j << new_n_vars) | i))
+				.sum()
+		})
+	}
+
+	fn evaluate_subcube(
+		&self,
+		indices: Range<usize>,
+		query: &MultilinearQuery<PE>,
+		evals_0: &mut Array2D<PE::Scalar>,
+		evals_1: &mut Array2D<PE::Scalar>,
+		col_index: usize,
+	) -> Result<(), Error> {
+		check_subcube_args(self.n_vars, &indices, query, evals_0, evals_1, col_index)?;
+		let query_vars = query.n_vars();
+		let subcube_eval = |subcube_index: usize| {
+			(0..1 << query_vars)
+				.map(|j| query_coeff(query, j) * self.value((subcube_index << query_vars) | j))
+				.sum::<PE::Scalar>()
+		};
+
+		if query_vars >= self.log_period {
+			// Every subcube covers whole periods, so all subcube evaluations are equal
+			let eval = subcube_eval(0);
+			for i in 0..indices.len() {
+				evals_0[(i, col_index)] = eval;
+				evals_1[(i, col_index)] = eval;
+			}
+		} else {
+			for (i, k) in indices.enumerate() {
+				evals_0[(i, col_index)] = subcube_eval(2 * k);
+				evals_1[(i, col_index)] = subcube_eval(2 * k + 1);
+			}
+		}
+		Ok(())
+	}
+
+	fn subcube_evals(&self, vars: usize, index: usize, dst: &mut [PE]) -> Result<(), Error> {
+		check_subcube_evals_args(self.n_vars, vars, index, dst)?;
+		for i in 0..1 << vars {
+			set_packed_slice(dst, i, self.value((index << vars) | i).into());
+		}
+		Ok(())
+	}
+}
+
+/// A multilinear polynomial that is zero at all but a few vertices of the hypercube.
+///
+/// Only the nonzero evaluations are stored, as pairs of a hypercube index and a value sorted by
+/// index. Evaluations take time proportional to the number of stored values rather than to the
+/// size of the hypercube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMultilinear<F: Field> {
+	n_vars: usize,
+	entries: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparseMultilinear<F> {
+	/// Constructs a sparse multilinear from its nonzero hypercube evaluations, in any order.
+	///
+	/// ## Throws
+	///
+	/// * `HypercubeIndexOutOfRange` if an index is not a vertex of the `n_vars`-variate hypercube
+	/// * `DuplicateHypercubeIndex` if an index occurs more than once
+	pub fn new(
+		n_vars: usize,
+		entries: impl IntoIterator<Item = (usize, F)>,
+	) -> Result<Self, Error> {
+		let mut entries = entries.into_iter().collect::<Vec<_>>();
+		entries.sort_unstable_by_key(|&(index, _)| index);
+		if let Some(&(index, _)) = entries.last() {
+			check_hypercube_index(n_vars, index)?;
+		}
+		if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+			return Err(Error::DuplicateHypercubeIndex { index: pair[0].0 });
+		}
+		Ok(Self { n_vars, entries })
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// The stored evaluations, sorted by hypercube index.
+	pub fn entries(&self) -> &[(usize, F)] {
+		&self.entries
+	}
+
+	/// The stored evaluations with indices in the given range.
+	fn entries_in(&self, range: Range<usize>) -> &[(usize, F)] {
+		let start = self
+			.entries
+			.partition_point(|&(index, _)| index < range.start);
+		let end = self
+			.entries
+			.partition_point(|&(index, _)| index < range.end);
+		&self.entries[start..end]
+	}
+
+	fn value(&self, index: usize) -> F {
+		self.entries_in(index..index + 1)
+			.first()
+			.map_or(F::ZERO, |&(_, value)| value)
+	}
+}
+
+impl<F, PE> MultilinearPoly<PE> for SparseMultilinear<F>
+where
+	F: Field,
+	PE: PackedField,
+	PE::Scalar: ExtensionField<F>,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn extension_degree(&self) -> usize {
+		<PE::Scalar as ExtensionField<F>>::DEGREE
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(self.value(index).into())
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: PE::Scalar,
+	) -> Result<PE::Scalar, Error> {
+		check_hypercube_index(self.n_vars, index)?;
+		Ok(scalar * self.value(index))
+	}
+
+	fn evaluate(&self, query: &MultilinearQuery<PE>) -> Result<PE::Scalar, Error> {
+		check_full_query(self.n_vars, query)?;
+		Ok(self
+			.entries
+			.iter()
+			.map(|&(index, value)| query_coeff(query, index) * value)
+			.sum())
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		let query_vars = query.n_vars();
+		let mut evals = vec![PE::Scalar::ZERO; 1 << (self.n_vars - query_vars)];
+		for &(index, value) in &self.entries {
+			let coeff = query_coeff(query, index % (1 << query_vars));
+			evals[index >> query_vars] += coeff * value;
+		}
+		dense_from_fn(self.n_vars - query_vars, |i| evals[i])
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: &MultilinearQuery<PE>,
+	) -> Result<MultilinearExtensionSpecialized<PE, PE>, Error> {
+		check_partial_query(self.n_vars, query)?;
+		let new_n_vars = self.n_vars - query.n_vars();
+		let mut evals = vec![PE::Scalar::ZERO; 1 << new_n_vars];
+		for &(index, value) in &self.entries {
+			let coeff = query_coeff(query, index >> new_n_vars);
+			evals[index % (1 << new_n_vars)] += coeff * value;
+		}
+		dense_from_fn(new_n_vars, |i| evals[i])
+	}
+
+	fn evaluate_subcube(
+		&self,
+		indices: Range<usize>,
+		query: &MultilinearQuery<PE>,
+		evals_0: &mut Array2D<PE::Scalar>,
+		evals_1: &mut Array2D<PE::Scalar>,
+		col_index: usize,
+	) -> Result<(), Error> {
+		check_subcube_args(self.n_vars, &indices, query, evals_0, evals_1, col_index)?;
+		let query_vars = query.n_vars();
+		let subcube_eval = |subcube_index: usize| {
+			let start = subcube_index << query_vars;
+			self.entries_in(start..start + (1 << query_vars))
+				.iter()
+				.map(|&(index, value)| query_coeff(query, index - start) * value)
+				.sum::<PE::Scalar>()
+		};
+
+		for (i, k) in indices.enumerate() {
+			evals_0[(i, col_index)] = subcube_eval(2 * k);
+			evals_1[(i, col_index)] = subcube_eval(2 * k + 1);
+		}
+		Ok(())
+	}
+
+	fn subcube_evals(&self, vars: usize, index: usize, dst: &mut [PE]) -> Result<(), Error> {
+		check_subcube_evals_args(self.n_vars, vars, index, dst)?;
+		dst.fill(PE::zero());
+		let start = index << vars;
+		for &(index, value) in self.entries_in(start..start + (1 << vars)) {
+			set_packed_slice(dst, index - start, value.into());
+		}
+		Ok(())
+	}
+}
+
+/// The coefficient of the vertex with the given index in the tensor expansion of the query.
+fn query_coeff<P: PackedField>(query: &MultilinearQuery<P>, index: usize) -> P::Scalar {
+	get_packed_slice(query.expansion(), index)
+}
+
+/// Packs the values of a function on the `n_vars`-variate hypercube into a multilinear extension.
+fn dense_from_fn<P: PackedField>(
+	n_vars: usize,
+	f: impl Fn(usize) -> P::Scalar + Sync,
+) -> Result<MultilinearExtensionSpecialized<P, P>, Error> {
+	if n_vars < P::LOG_WIDTH {
+		return Err(Error::PackedFieldNotFilled {
+			length: 1 << n_vars,
+			packed_width: P::WIDTH,
+		});
+	}
+	let values = (0..1 << (n_vars - P::LOG_WIDTH))
+		.into_par_iter()
+		.map(|i| P::from_fn(|j| f((i << P::LOG_WIDTH) | j)))
+		.collect();
+	Ok(MultilinearExtension::from_values(values)?.specialize())
+}
+
+fn check_hypercube_index(n_vars: usize, index: usize) -> Result<(), Error> {
+	if index >= 1 << n_vars {
+		return Err(Error::HypercubeIndexOutOfRange { index });
+	}
+	Ok(())
+}
+
+fn check_full_query<P: PackedField>(
+	n_vars: usize,
+	query: &MultilinearQuery<P>,
+) -> Result<(), Error> {
+	if query.n_vars() != n_vars {
+		return Err(Error::IncorrectQuerySize { expected: n_vars });
+	}
+	Ok(())
+}
+
+fn check_partial_query<P: PackedField>(
+	n_vars: usize,
+	query: &MultilinearQuery<P>,
+) -> Result<(), Error> {
+	if query.n_vars() > n_vars {
+		return Err(Error::IncorrectQuerySize { expected: n_vars });
+	}
+	Ok(())
+}
+
+fn check_subcube_args<P: PackedField>(
+	n_vars: usize,
+	indices: &Range<usize>,
+	query: &MultilinearQuery<P>,
+	evals_0: &Array2D<P::Scalar>,
+	evals_1: &Array2D<P::Scalar>,
+	col_index: usize,
+) -> Result<(), Error> {
+	if query.n_vars() >= n_vars {
+		return Err(Error::ArgumentRangeError {
+			arg: "query.n_vars()".into(),
+			range: 0..n_vars,
+		});
+	}
+	let max_index = 1 << (n_vars - query.n_vars() - 1);
+	if indices.end > max_index {
+		return Err(Error::ArgumentRangeError {
+			arg: "indices.end".into(),
+			range: 0..max_index + 1,
+		});
+	}
+	if indices.len() > evals_0.rows().min(evals_1.rows()) {
+		return Err(Error::ArgumentRangeError {
+			arg: "evals.rows()".into(),
+			range: indices.len()..usize::MAX,
+		});
+	}
+	if col_index >= evals_0.cols().min(evals_1.cols()) {
+		return Err(Error::ArgumentRangeError {
+			arg: "col_index".into(),
+			range: 0..evals_0.cols().min(evals_1.cols()),
+		});
+	}
+	Ok(())
+}
+
+fn check_subcube_evals_args<P: PackedField>(
+	n_vars: usize,
+	vars: usize,
+	index: usize,
+	dst: &[P],
+) -> Result<(), Error> {
+	if vars > n_vars {
+		return Err(Error::ArgumentRangeError {
+			arg: "vars".into(),
+			range: 0..n_vars + 1,
+		});
+	}
+	if dst.len() * P::WIDTH != 1 << vars {
+		return Err(Error::ArgumentRangeError {
+			arg: "dst.len()".into(),
+			range: (1 << vars) / P::WIDTH..(1 << vars) / P::WIDTH + 1,
+		});
+	}
+	if index >= 1 << (n_vars - vars) {
+		return Err(Error::ArgumentRangeError {
+			arg: "index".into(),
+			range: 0..(1 << (n_vars - vars)),
+		});
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField128b, BinaryField8b, PackedBinaryField4x32b};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::{iter::repeat_with, sync::Arc};
+
+	type F = BinaryField8b;
+	type FE = BinaryField128b;
+
+	const N_VARS: usize = 7;
+
+	/// Checks all evaluation methods of a structured multilinear against its dense equivalent.
+	fn check_against_dense<PE>(poly: Arc<dyn MultilinearPoly<PE> + Send + Sync>)
+	where
+		PE: PackedField,
+		PE::Scalar: ExtensionField<F>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = poly.n_vars();
+		let values = (0..1 << n_vars)
+			.map(|i| poly.evaluate_on_hypercube(i).unwrap())
+			.collect::<Vec<_>>();
+		let dense = MultilinearExtension::from_values(
+			values
+				.chunks(PE::WIDTH)
+				.map(|chunk| PE::from_fn(|j| chunk[j]))
+				.collect(),
+		)
+		.unwrap()
+		.specialize::<PE>();
+
+		let point = repeat_with(|| <PE::Scalar as Field>::random(&mut rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<PE>::with_full_query(&point).unwrap();
+		assert_eq!(poly.evaluate(&query).unwrap(), dense.evaluate(&query).unwrap());
+
+		for query_vars in [0, 1, 3] {
+			let query = MultilinearQuery::<PE>::with_full_query(&point[..query_vars]).unwrap();
+			assert_eq!(
+				poly.evaluate_partial_low(&query).unwrap(),
+				dense.evaluate_partial_low(&query).unwrap()
+			);
+			assert_eq!(
+				poly.evaluate_partial_high(&query).unwrap(),
+				dense.evaluate_partial_high(&query).unwrap()
+			);
+
+			let n_indices = 1 << (n_vars - query_vars - 1);
+			let mut expected = [Array2D::new(n_indices, 2), Array2D::new(n_indices, 2)];
+			let mut actual = [Array2D::new(n_indices, 2), Array2D::new(n_indices, 2)];
+			let [expected_0, expected_1] = &mut expected;
+			dense
+				.evaluate_subcube(0..n_indices, &query, expected_0, expected_1, 1)
+				.unwrap();
+			let [actual_0, actual_1] = &mut actual;
+			poly.evaluate_subcube(0..n_indices, &query, actual_0, actual_1, 1)
+				.unwrap();
+			for i in 0..n_indices {
+				assert_eq!(actual[0][(i, 1)], expected[0][(i, 1)]);
+				assert_eq!(actual[1][(i, 1)], expected[1][(i, 1)]);
+			}
+		}
+
+		let vars = 3;
+		for index in 0..1 << (n_vars - vars) {
+			let mut expected = vec![PE::zero(); (1 << vars) / PE::WIDTH];
+			let mut actual = vec![PE::zero(); (1 << vars) / PE::WIDTH];
+			dense.subcube_evals(vars, index, &mut expected).unwrap();
+			poly.subcube_evals(vars, index, &mut actual).unwrap();
+			assert_eq!(actual, expected);
+		}
+	}
+
+	#[test]
+	fn test_constant_multilinear_consistent_with_dense() {
+		let value = F::new(0x2a);
+		check_against_dense::<FE>(Arc::new(ConstantMultilinear::new(N_VARS, value)));
+		check_against_dense::<PackedBinaryField4x32b>(Arc::new(ConstantMultilinear::new(
+			N_VARS, value,
+		)));
+	}
+
+	#[test]
+	fn test_periodic_multilinear_consistent_with_dense() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for log_period in [0, 2, 5, N_VARS] {
+			let period = repeat_with(|| F::random(&mut rng))
+				.take(1 << log_period)
+				.collect::<Vec<_>>();
+			let poly = PeriodicMultilinear::new(N_VARS, period).unwrap();
+			check_against_dense::<FE>(Arc::new(poly.clone()));
+			check_against_dense::<PackedBinaryField4x32b>(Arc::new(poly));
+		}
+	}
+
+	#[test]
+	fn test_sparse_multilinear_consistent_with_dense() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let entries = [0, 5, 6, 33, 64, 127].map(|index| (index, F::random(&mut rng)));
+		let poly = SparseMultilinear::new(N_VARS, entries.into_iter().rev()).unwrap();
+		assert_eq!(poly.entries(), entries);
+		check_against_dense::<FE>(Arc::new(poly.clone()));
+		check_against_dense::<PackedBinaryField4x32b>(Arc::new(poly));
+	}
+
+	#[test]
+	fn test_sparse_multilinear_invalid_entries() {
+		assert_matches!(
+			SparseMultilinear::new(3, [(8, F::ONE)]),
+			Err(Error::HypercubeIndexOutOfRange { index: 8 })
+		);
+		assert_matches!(
+			SparseMultilinear::new(3, [(2, F::ONE), (1, F::ONE), (2, F::ONE)]),
+			Err(Error::DuplicateHypercubeIndex { index: 2 })
+		);
+	}
+}
@@ -0,0 +1,139 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::polynomial::{Error, MultilinearExtension, MultivariatePoly};
+use binius_field::{PackedField, TowerField};
+use p3_util::log2_ceil_usize;
+
+/// Encode an integer as the binary field element whose $\mathbb{F}_2$-basis coordinates are the
+/// bits of the integer.
+///
+/// Distinct integers map to distinct field elements, as long as they fit into the field.
+pub fn encode_index<F: TowerField>(index: usize) -> Result<F, Error> {
+	let n_bits = (usize::BITS - index.leading_zeros()) as usize;
+	if n_bits > F::N_BITS {
+		return Err(Error::ArgumentRangeError {
+			arg: "index bits".into(),
+			range: 0..F::N_BITS + 1,
+		});
+	}
+
+	(0..n_bits)
+		.filter(|&bit| (index >> bit) & 1 == 1)
+		.map(|bit| Ok(F::basis(0, bit)?))
+		.sum()
+}
+
+/// Represents a multilinear whose hypercube evaluation at index $i$ is the field element
+/// [encoding](encode_index) the integer $2^{n\\_vars} \cdot block + i$.
+///
+/// Identity polynomials of consecutive blocks assign a distinct field element to every cell of a
+/// set of columns, as used by permutation arguments. As the block occupies the bits above the
+/// index, the polynomial is affine: $id(x) = e(2^{n\\_vars} \cdot block) + \sum_j x_j e(2^j)$,
+/// where $e$ is the encoding.
+#[derive(Debug, Clone, Copy)]
+pub struct Identity {
+	n_vars: usize,
+	block: usize,
+}
+
+impl Identity {
+	pub fn new(n_vars: usize, block: usize) -> Self {
+		Self { n_vars, block }
+	}
+
+	pub fn multilinear_extension<P: PackedField<Scalar: TowerField>>(
+		&self,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if self.n_vars < P::LOG_WIDTH {
+			return Err(Error::PackedFieldNotFilled {
+				length: 1 << self.n_vars,
+				packed_width: 1 << P::LOG_WIDTH,
+			});
+		}
+
+		let offset = self.block << self.n_vars;
+		let values = (0..1 << (self.n_vars - P::LOG_WIDTH))
+			.map(|i| {
+				let mut packed_value = P::default();
+				for j in 0..P::WIDTH {
+					packed_value.set(j, encode_index(offset + i * P::WIDTH + j)?);
+				}
+				Ok(packed_value)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		MultilinearExtension::from_values(values)
+	}
+
+	/// The number of bits of the largest encoded integer.
+	fn n_bits(&self) -> usize {
+		self.n_vars + (usize::BITS - self.block.leading_zeros()) as usize
+	}
+}
+
+impl<F: TowerField> MultivariatePoly<F> for Identity {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		1
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		if query.len() != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let offset = encode_index::<F>(self.block << self.n_vars)?;
+		query
+			.iter()
+			.enumerate()
+			.try_fold(offset, |acc, (bit, &q)| Ok(acc + q * F::basis(0, bit)?))
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		log2_ceil_usize(self.n_bits())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{encode_index, Identity};
+	use crate::polynomial::{MultilinearQuery, MultivariatePoly};
+	use binius_field::{BinaryField128b, BinaryField32b, Field};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::iter::repeat_with;
+
+	#[test]
+	fn test_encode_index() {
+		assert_eq!(encode_index::<BinaryField32b>(0).unwrap(), BinaryField32b::ZERO);
+		assert_eq!(encode_index::<BinaryField32b>(0x1234).unwrap(), BinaryField32b::new(0x1234));
+		assert!(encode_index::<BinaryField32b>(1 << 32).is_err());
+	}
+
+	#[test]
+	fn test_identity_hypercube_evals() {
+		let identity = Identity::new(4, 3);
+		let mle = identity.multilinear_extension::<BinaryField32b>().unwrap();
+		for (i, &eval) in mle.evals().iter().enumerate() {
+			assert_eq!(eval, BinaryField32b::new((3 << 4) + i as u32));
+		}
+		assert_eq!(MultivariatePoly::<BinaryField32b>::binary_tower_level(&identity), 3);
+	}
+
+	#[test]
+	fn test_identity_evaluate_matches_multilinear_extension() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let identity = Identity::new(5, 6);
+		let mle = identity.multilinear_extension::<BinaryField128b>().unwrap();
+
+		let point = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(5)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::with_full_query(&point).unwrap();
+		assert_eq!(identity.evaluate(&point).unwrap(), mle.evaluate(&query).unwrap());
+	}
+}
@@ -3,8 +3,10 @@
 pub mod constant;
 pub mod disjoint_product;
 pub mod eq_ind;
+pub mod identity;
 pub mod multilinear_extension;
 pub mod select_row;
 pub mod shift_ind;
+pub mod shift_ind_2d;
 pub mod step_down;
 pub mod tower_basis;
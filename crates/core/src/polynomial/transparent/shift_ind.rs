@@ -15,6 +15,10 @@ use binius_field::{util::eq, Field, PackedFieldIndexable, TowerField};
 ///     * $f(x, y) = 1$ if $\{y\} - \{o\} \equiv \{x\} (\text{mod } 2^b)$
 ///     * $f(x, y) = 0$ otw
 ///
+/// Else if ShiftVariant is CircularRight:
+///     * $f(x, y) = 1$ if $\{y\} + \{o\} \equiv \{x\} (\text{mod } 2^b)$
+///     * $f(x, y) = 0$ otw
+///
 /// Else if ShiftVariant is LogicalLeft:
 ///    * $f(x, y) = 1$ if $\{y\} - \{o\} \equiv \{x\}$
 ///    * $f(x, y) = 0$ otw
@@ -39,7 +43,8 @@ use binius_field::{util::eq, Field, PackedFieldIndexable, TowerField};
 /// (wrap around if ShiftVariant is Circular + direction of steps depending on ShiftVariant's direction)
 ///
 /// # Note
-/// CircularLeft corresponds to the shift indicator in Section 4.3.
+/// CircularLeft corresponds to the shift indicator in Section 4.3. CircularRight is the
+/// CircularLeft indicator with offset $2^b - o$.
 /// LogicalLeft corresponds to the shift prime indicator in Section 4.3.
 /// LogicalRight corresponds to the shift double prime indicator in Section 4.3.
 ///
@@ -107,12 +112,15 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		})
 	}
 
-	fn multilinear_extension_circular<P>(&self) -> Result<MultilinearExtension<P>, Error>
+	fn multilinear_extension_circular<P>(
+		&self,
+		left_shift_offset: usize,
+	) -> Result<MultilinearExtension<P>, Error>
 	where
 		P: PackedFieldIndexable<Scalar = F>,
 	{
 		let (ps, pps) =
-			partial_evaluate_hypercube_impl::<P>(self.block_size, self.shift_offset, &self.r)?;
+			partial_evaluate_hypercube_impl::<P>(self.block_size, left_shift_offset, &self.r)?;
 		let values = ps
 			.iter()
 			.zip(pps)
@@ -147,7 +155,10 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		P: PackedFieldIndexable<Scalar = F>,
 	{
 		match self.shift_variant {
-			ShiftVariant::CircularLeft => self.multilinear_extension_circular(),
+			ShiftVariant::CircularLeft => self.multilinear_extension_circular(self.shift_offset),
+			ShiftVariant::CircularRight => self.multilinear_extension_circular(
+				get_left_shift_offset(self.block_size, self.shift_offset),
+			),
 			ShiftVariant::LogicalLeft => self.multilinear_extension_logical_left(),
 			ShiftVariant::LogicalRight => self.multilinear_extension_logical_right(),
 		}
@@ -164,14 +175,16 @@ impl<F: Field> ShiftIndPartialEval<F> {
 		let left_shift_offset = match self.shift_variant {
 			ShiftVariant::CircularLeft => self.shift_offset,
 			ShiftVariant::LogicalLeft => self.shift_offset,
-			ShiftVariant::LogicalRight => get_left_shift_offset(self.block_size, self.shift_offset),
+			ShiftVariant::CircularRight | ShiftVariant::LogicalRight => {
+				get_left_shift_offset(self.block_size, self.shift_offset)
+			}
 		};
 
 		let (p_res, pp_res) =
 			evaluate_shift_ind_help(self.block_size, left_shift_offset, x, &self.r)?;
 
 		match self.shift_variant {
-			ShiftVariant::CircularLeft => Ok(p_res + pp_res),
+			ShiftVariant::CircularLeft | ShiftVariant::CircularRight => Ok(p_res + pp_res),
 			ShiftVariant::LogicalLeft => Ok(p_res),
 			ShiftVariant::LogicalRight => Ok(pp_res),
 		}
@@ -380,6 +393,36 @@ mod tests {
 		assert_eq!(eval_mle, eval_mvp);
 	}
 
+	fn test_circular_right_shift_consistency_help<
+		F: TowerField,
+		P: PackedFieldIndexable<Scalar = F>,
+	>(
+		block_size: usize,
+		left_shift_offset: usize,
+	) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let r = repeat_with(|| F::random(&mut rng))
+			.take(block_size)
+			.collect::<Vec<_>>();
+		let eval_point = &repeat_with(|| F::random(&mut rng))
+			.take(block_size)
+			.collect::<Vec<_>>();
+
+		// Get Multivariate Poly version
+		let shift_variant = ShiftVariant::CircularRight;
+		let shift_r_mvp =
+			ShiftIndPartialEval::new(block_size, left_shift_offset, shift_variant, r).unwrap();
+		let eval_mvp = shift_r_mvp.evaluate(eval_point).unwrap();
+
+		// Get MultilinearExtension version
+		let shift_r_mle = shift_r_mvp.multilinear_extension::<P>().unwrap();
+		let multilin_query = MultilinearQuery::<P>::with_full_query(eval_point).unwrap();
+		let eval_mle = shift_r_mle.evaluate(&multilin_query).unwrap();
+
+		// Assert equality
+		assert_eq!(eval_mle, eval_mvp);
+	}
+
 	fn test_logical_left_shift_consistency_help<
 		F: TowerField,
 		P: PackedFieldIndexable<Scalar = F>,
@@ -452,6 +495,18 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_circular_right_shift_consistency_schwartz_zippel() {
+		for block_size in 2..=10 {
+			for left_shift_offset in [1, 2, 3, (1 << block_size) - 1, (1 << block_size) / 2] {
+				test_circular_right_shift_consistency_help::<_, PackedBinaryField4x32b>(
+					block_size,
+					left_shift_offset,
+				);
+			}
+		}
+	}
+
 	#[test]
 	fn test_logical_left_shift_consistency_schwartz_zippel() {
 		for block_size in 2..=10 {
@@ -497,6 +552,26 @@ mod tests {
 			});
 		});
 	}
+	fn test_circular_right_shift_functionality_help<F: TowerField>(
+		block_size: usize,
+		left_shift_offset: usize,
+	) {
+		let shift_variant = ShiftVariant::CircularRight;
+		(0..(1 << block_size)).for_each(|i| {
+			let r = decompose_index_to_hypercube_point::<F>(block_size, i);
+			let shift_r_mvp =
+				ShiftIndPartialEval::new(block_size, left_shift_offset, shift_variant, r).unwrap();
+			(0..(1 << block_size)).for_each(|j| {
+				let x = decompose_index_to_hypercube_point::<F>(block_size, j);
+				let eval_mvp = shift_r_mvp.evaluate(&x).unwrap();
+				if (i + left_shift_offset) % (1 << block_size) == j {
+					assert_eq!(eval_mvp, F::ONE);
+				} else {
+					assert_eq!(eval_mvp, F::ZERO);
+				}
+			});
+		});
+	}
 	fn test_logical_left_shift_functionality_help<F: TowerField>(
 		block_size: usize,
 		right_shift_offset: usize,
@@ -557,6 +632,23 @@ mod tests {
 		}
 	}
 	#[test]
+	fn test_circular_right_shift_functionality() {
+		for block_size in 3..5 {
+			for left_shift_offset in [
+				1,
+				3,
+				(1 << block_size) - 1,
+				(1 << block_size) - 2,
+				(1 << (block_size - 1)),
+			] {
+				test_circular_right_shift_functionality_help::<BinaryField32b>(
+					block_size,
+					left_shift_offset,
+				);
+			}
+		}
+	}
+	#[test]
 	fn test_logical_left_shift_functionality() {
 		for block_size in 3..5 {
 			for right_shift_offset in [
@@ -0,0 +1,262 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::ShiftVariant,
+	polynomial::{
+		transparent::{eq_ind::EqIndPartialEval, shift_ind::ShiftIndPartialEval},
+		Error, MultilinearExtension, MultivariatePoly,
+	},
+};
+use binius_field::{PackedFieldIndexable, TowerField};
+
+/// Represents MLE of the two-dimensional shift indicator on $2(c + r)$ variables partially
+/// evaluated at $Y = r$.
+///
+/// The $c + r$ variables of $X$ and $Y$ are interpreted as a column index on the low $c$
+/// variables and a row index on the high $r$ variables. The indicator is the product of the
+/// one-dimensional shift indicators [`ShiftIndPartialEval`] of the columns and of the rows, with
+/// the same shift variant. A dimension with a zero offset is not shifted, and its factor is the
+/// equality indicator instead.
+///
+/// Since the factors depend on disjoint variables, evaluating the indicator costs the same as
+/// evaluating the two one-dimensional indicators, and its multilinear extension is the tensor
+/// product of theirs.
+#[derive(Debug, Clone)]
+pub struct Shift2DIndPartialEval<F: TowerField> {
+	/// Number of column variables $c$
+	col_bits: usize,
+	/// Number of row variables $r$
+	row_bits: usize,
+	/// Column shift offset $o_c \in \{0, \ldots, 2^c - 1\}$
+	col_offset: usize,
+	/// Row shift offset $o_r \in \{0, \ldots, 2^r - 1\}$
+	row_offset: usize,
+	/// Shift variant of both dimensions
+	shift_variant: ShiftVariant,
+	/// Partial evaluation point, typically the lowest $c + r$ coords of a larger challenge point
+	r: Vec<F>,
+}
+
+impl<F: TowerField> Shift2DIndPartialEval<F> {
+	pub fn new(
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		shift_variant: ShiftVariant,
+		r: Vec<F>,
+	) -> Result<Self, Error> {
+		if r.len() != col_bits + row_bits {
+			return Err(Error::IncorrectQuerySize {
+				expected: col_bits + row_bits,
+			});
+		}
+		for (bits, offset) in [(col_bits, col_offset), (row_bits, row_offset)] {
+			if offset >= 1 << bits {
+				return Err(Error::InvalidShiftOffset {
+					max_shift_offset: (1 << bits) - 1,
+					shift_offset: offset,
+				});
+			}
+		}
+
+		Ok(Self {
+			col_bits,
+			row_bits,
+			col_offset,
+			row_offset,
+			shift_variant,
+			r,
+		})
+	}
+
+	/// The column and row factors of the indicator.
+	fn factors(&self) -> Result<[Factor<F>; 2], Error> {
+		let (r_col, r_row) = self.r.split_at(self.col_bits);
+		Ok([
+			Factor::new(self.col_bits, self.col_offset, self.shift_variant, r_col)?,
+			Factor::new(self.row_bits, self.row_offset, self.shift_variant, r_row)?,
+		])
+	}
+
+	/// Evaluates this partially evaluated shift indicator MLE $f(X, r)$ over the entire
+	/// $(c + r)$-variate hypercube.
+	pub fn multilinear_extension<P>(&self) -> Result<MultilinearExtension<P>, Error>
+	where
+		P: PackedFieldIndexable<Scalar = F>,
+	{
+		let n_vars = self.col_bits + self.row_bits;
+		if n_vars < P::LOG_WIDTH {
+			return Err(Error::PackedFieldNotFilled {
+				length: 1 << n_vars,
+				packed_width: 1 << P::LOG_WIDTH,
+			});
+		}
+
+		let [col, row] = self.factors()?;
+		let (col, row) = (col.multilinear_extension()?, row.multilinear_extension()?);
+
+		let mut values = vec![P::zero(); 1 << (n_vars - P::LOG_WIDTH)];
+		for (row_values, &row_value) in P::unpack_scalars_mut(&mut values)
+			.chunks_exact_mut(col.evals().len())
+			.zip(row.evals())
+		{
+			for (value, &col_value) in row_values.iter_mut().zip(col.evals()) {
+				*value = col_value * row_value;
+			}
+		}
+		MultilinearExtension::from_values(values)
+	}
+}
+
+impl<F: TowerField> MultivariatePoly<F> for Shift2DIndPartialEval<F> {
+	fn n_vars(&self) -> usize {
+		self.col_bits + self.row_bits
+	}
+
+	fn degree(&self) -> usize {
+		self.col_bits + self.row_bits
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let n_vars = MultivariatePoly::<F>::n_vars(self);
+		if query.len() != n_vars {
+			return Err(Error::IncorrectQuerySize { expected: n_vars });
+		}
+
+		let [col, row] = self.factors()?;
+		let (query_col, query_row) = query.split_at(self.col_bits);
+		Ok(col.evaluate(query_col)? * row.evaluate(query_row)?)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		F::TOWER_LEVEL
+	}
+}
+
+/// The indicator of one dimension, which is the identity if the dimension is not shifted.
+enum Factor<F: TowerField> {
+	Eq(EqIndPartialEval<F>),
+	Shift(ShiftIndPartialEval<F>),
+}
+
+impl<F: TowerField> Factor<F> {
+	fn new(
+		bits: usize,
+		offset: usize,
+		shift_variant: ShiftVariant,
+		r: &[F],
+	) -> Result<Self, Error> {
+		if offset == 0 {
+			Ok(Self::Eq(EqIndPartialEval::new(bits, r.to_vec())?))
+		} else {
+			Ok(Self::Shift(ShiftIndPartialEval::new(bits, offset, shift_variant, r.to_vec())?))
+		}
+	}
+
+	fn multilinear_extension(&self) -> Result<MultilinearExtension<F>, Error> {
+		match self {
+			Self::Eq(eq_ind) => eq_ind.multilinear_extension(),
+			Self::Shift(shift_ind) => shift_ind.multilinear_extension(),
+		}
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		match self {
+			Self::Eq(eq_ind) => MultivariatePoly::<F>::evaluate(eq_ind, query),
+			Self::Shift(shift_ind) => shift_ind.evaluate(query),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		polynomial::multilinear_query::MultilinearQuery,
+		protocols::test_utils::decompose_index_to_hypercube_point,
+	};
+	use binius_field::{BinaryField32b, Field, PackedBinaryField4x32b};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::iter::repeat_with;
+
+	const VARIANTS: [ShiftVariant; 4] = [
+		ShiftVariant::CircularLeft,
+		ShiftVariant::CircularRight,
+		ShiftVariant::LogicalLeft,
+		ShiftVariant::LogicalRight,
+	];
+
+	/// The index that a one-dimensional shift reads at index `x`, if any.
+	fn shifted_index(x: usize, bits: usize, offset: usize, variant: ShiftVariant) -> Option<usize> {
+		let size = 1 << bits;
+		match variant {
+			ShiftVariant::CircularLeft => Some((x + offset) % size),
+			ShiftVariant::CircularRight => Some((x + size - offset) % size),
+			ShiftVariant::LogicalLeft => (x + offset < size).then_some(x + offset),
+			ShiftVariant::LogicalRight => x.checked_sub(offset),
+		}
+	}
+
+	#[test]
+	fn test_shift_2d_consistency_schwartz_zippel() {
+		type F = BinaryField32b;
+		type P = PackedBinaryField4x32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let (col_bits, row_bits) = (3, 2);
+		for variant in VARIANTS {
+			for (col_offset, row_offset) in [(1, 0), (0, 3), (5, 1)] {
+				let r = repeat_with(|| F::random(&mut rng))
+					.take(col_bits + row_bits)
+					.collect::<Vec<_>>();
+				let eval_point = repeat_with(|| F::random(&mut rng))
+					.take(col_bits + row_bits)
+					.collect::<Vec<_>>();
+
+				let shift_r_mvp = Shift2DIndPartialEval::new(
+					col_bits, row_bits, col_offset, row_offset, variant, r,
+				)
+				.unwrap();
+				let eval_mvp = shift_r_mvp.evaluate(&eval_point).unwrap();
+
+				let shift_r_mle = shift_r_mvp.multilinear_extension::<P>().unwrap();
+				let multilin_query = MultilinearQuery::<P>::with_full_query(&eval_point).unwrap();
+				let eval_mle = shift_r_mle.evaluate(&multilin_query).unwrap();
+
+				assert_eq!(eval_mle, eval_mvp);
+			}
+		}
+	}
+
+	#[test]
+	fn test_shift_2d_functionality() {
+		type F = BinaryField32b;
+
+		let (col_bits, row_bits) = (2, 2);
+		let n_vars = col_bits + row_bits;
+		for variant in VARIANTS {
+			for (col_offset, row_offset) in [(1, 0), (0, 3), (3, 1)] {
+				for i in 0..1 << n_vars {
+					let r = decompose_index_to_hypercube_point::<F>(n_vars, i);
+					let shift_r_mvp = Shift2DIndPartialEval::new(
+						col_bits, row_bits, col_offset, row_offset, variant, r,
+					)
+					.unwrap();
+					for j in 0..1 << n_vars {
+						let x = decompose_index_to_hypercube_point::<F>(n_vars, j);
+						let (col, row) = (j % (1 << col_bits), j >> col_bits);
+						let source = shifted_index(col, col_bits, col_offset, variant)
+							.zip(shifted_index(row, row_bits, row_offset, variant));
+						let expected = if source == Some((i % (1 << col_bits), i >> col_bits)) {
+							F::ONE
+						} else {
+							F::ZERO
+						};
+						assert_eq!(shift_r_mvp.evaluate(&x).unwrap(), expected);
+					}
+				}
+			}
+		}
+	}
+}
@@ -135,6 +135,29 @@ impl<F: Field> EvaluationDomain<F> {
 
 		Ok(result)
 	}
+
+	/// Evaluates all Lagrange basis polynomials of the domain at a point.
+	///
+	/// The $i$-th returned value is $L_i(x)$, the unique polynomial of degree less than the domain
+	/// size that is one at the $i$-th domain point and zero at all others. Extrapolating a vector
+	/// of values is equivalent to the inner product of the values with the returned vector.
+	pub fn lagrange_evals<FE: ExtensionField<F>>(&self, x: FE) -> Vec<FE> {
+		let n = self.size();
+
+		// suffix_prods[i] is the product of (x - x_j) for j >= i
+		let mut suffix_prods = vec![FE::ONE; n + 1];
+		for i in (0..n).rev() {
+			suffix_prods[i] = suffix_prods[i + 1] * (x - self.points[i]);
+		}
+
+		let mut prefix_prod = FE::ONE;
+		let mut result = Vec::with_capacity(n);
+		for i in 0..n {
+			result.push(prefix_prod * suffix_prods[i + 1] * self.weights[i]);
+			prefix_prod *= x - self.points[i];
+		}
+		result
+	}
 }
 
 #[inline]
@@ -322,4 +345,35 @@ mod tests {
 		let interpolated = domain.interpolate(&values).unwrap();
 		assert_eq!(interpolated, coeffs);
 	}
+
+	#[test]
+	fn test_lagrange_evals() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let domain = EvaluationDomain::<BinaryField8b>::new(7).unwrap();
+
+		let values = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(domain.size())
+			.collect::<Vec<_>>();
+
+		let x = <BinaryField128b as Field>::random(&mut rng);
+		let lagrange_evals = domain.lagrange_evals(x);
+		let inner_product = values
+			.iter()
+			.zip(lagrange_evals.iter())
+			.map(|(&value, &lagrange_eval)| value * lagrange_eval)
+			.sum::<BinaryField128b>();
+		assert_eq!(inner_product, domain.extrapolate(&values, x).unwrap());
+
+		for (i, &point) in domain.points().iter().enumerate() {
+			let lagrange_evals = domain.lagrange_evals(point);
+			for (j, &eval) in lagrange_evals.iter().enumerate() {
+				let expected = if i == j {
+					BinaryField8b::ONE
+				} else {
+					BinaryField8b::ZERO
+				};
+				assert_eq!(eval, expected);
+			}
+		}
+	}
 }
@@ -0,0 +1,713 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Dense univariate polynomials over binary fields.
+//!
+//! Polynomials are represented by their coefficients either in the standard monomial basis
+//! $1, X, X^2, \ldots$ or in the novel polynomial basis of [LCH14], in which the additive NTT from
+//! [binius_ntt] computes evaluations over linear subspaces. The novel basis polynomial $X_j$ is
+//! defined as $\prod_{i} \hat{W}_i(X)^{j_i}$, where $j_i$ are the bits of $j$ and $\hat{W}_i$ is
+//! the normalized subspace vanishing polynomial for the span of the first $i$ canonical basis
+//! elements of the field. Since $X_j$ has degree $j$, the length of the coefficient vector
+//! determines the degree in both bases.
+//!
+//! [LCH14]: <https://arxiv.org/abs/1404.3458>
+
+use super::{error::Error, univariate::evaluate_univariate};
+use binius_field::{BinaryField, Field};
+use binius_ntt::{AdditiveNTT, AdditiveNTTWithOTFCompute, BasisConversion, NovelPolyBasis};
+use p3_util::log2_ceil_usize;
+use rayon::prelude::*;
+
+/// Operand length below which multiplication falls back to the schoolbook algorithm.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Operand length from which monomial basis multiplication uses the additive NTT.
+const NTT_MUL_THRESHOLD: usize = 512;
+
+/// Number of points below which multipoint evaluation uses Horner's method for each point.
+const MULTIPOINT_EVAL_THRESHOLD: usize = 64;
+
+/// The basis in which the coefficients of a [`UnivariatePoly`] are expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnivariateBasis {
+	/// The monomial basis $1, X, X^2, \ldots$.
+	Monomial,
+	/// The [LCH14] novel polynomial basis with respect to the canonical basis of the field.
+	///
+	/// [LCH14]: <https://arxiv.org/abs/1404.3458>
+	Novel,
+}
+
+/// A dense univariate polynomial.
+///
+/// The coefficient vector never has trailing zeros, so the zero polynomial has no coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnivariatePoly<F: Field> {
+	coeffs: Vec<F>,
+	basis: UnivariateBasis,
+}
+
+impl<F: BinaryField> UnivariatePoly<F> {
+	pub fn new(mut coeffs: Vec<F>, basis: UnivariateBasis) -> Self {
+		trim(&mut coeffs);
+		Self { coeffs, basis }
+	}
+
+	pub fn zero(basis: UnivariateBasis) -> Self {
+		Self {
+			coeffs: Vec::new(),
+			basis,
+		}
+	}
+
+	/// The monic polynomial $\prod_i (X - x_i)$ vanishing on the given points, in monomial basis.
+	pub fn vanishing(points: &[F]) -> Self {
+		Self::new(vanishing_coeffs(points), UnivariateBasis::Monomial)
+	}
+
+	pub fn coeffs(&self) -> &[F] {
+		&self.coeffs
+	}
+
+	pub fn into_coeffs(self) -> Vec<F> {
+		self.coeffs
+	}
+
+	pub fn basis(&self) -> UnivariateBasis {
+		self.basis
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.coeffs.is_empty()
+	}
+
+	/// The degree of the polynomial, or `None` for the zero polynomial.
+	pub fn degree(&self) -> Option<usize> {
+		self.coeffs.len().checked_sub(1)
+	}
+
+	pub fn add(&self, other: &Self) -> Result<Self, Error> {
+		self.check_same_basis(other)?;
+
+		let (mut sum, addend) = if self.coeffs.len() >= other.coeffs.len() {
+			(self.coeffs.clone(), &other.coeffs)
+		} else {
+			(other.coeffs.clone(), &self.coeffs)
+		};
+		add_at(&mut sum, addend, 0);
+		Ok(Self::new(sum, self.basis))
+	}
+
+	pub fn scale(&self, scalar: F) -> Self {
+		let coeffs = self.coeffs.iter().map(|&coeff| coeff * scalar).collect();
+		Self::new(coeffs, self.basis)
+	}
+
+	/// Converts the polynomial to the given basis.
+	///
+	/// The conversion takes $O(n \log^2 n)$ operations for a polynomial with $n$ coefficients.
+	///
+	/// ## Throws
+	///
+	/// * `Error::DomainSizeTooLarge` if the degree of the polynomial does not fit into the field
+	pub fn to_basis(&self, basis: UnivariateBasis) -> Result<Self, Error> {
+		if self.basis == basis || self.is_zero() {
+			return Ok(Self {
+				coeffs: self.coeffs.clone(),
+				basis,
+			});
+		}
+
+		let log_len = log2_ceil_usize(self.coeffs.len());
+		if log_len > max_log_domain_size::<F>() {
+			return Err(Error::DomainSizeTooLarge);
+		}
+
+		let novel_basis = NovelPolyBasis::<F>::new(log_len)
+			.expect("log_len is checked against the field size above");
+		let mut coeffs = zero_padded(&self.coeffs, 1 << log_len);
+		match basis {
+			UnivariateBasis::Monomial => novel_basis.novel_to_monomial(coeffs.as_mut_slice(), 0),
+			UnivariateBasis::Novel => novel_basis.monomial_to_novel(coeffs.as_mut_slice(), 0),
+		}
+		.expect("the data length matches the basis size");
+		Ok(Self::new(coeffs, basis))
+	}
+
+	/// Multiplies two polynomials with the algorithm best suited for their basis and sizes.
+	///
+	/// Polynomials in monomial basis are multiplied with Karatsuba's algorithm, which falls back
+	/// to the schoolbook algorithm for small operands, unless both operands are large enough for
+	/// the basis conversions and the additive NTT to be faster. Polynomials in novel basis are
+	/// multiplied with the additive NTT.
+	pub fn mul(&self, other: &Self) -> Result<Self, Error> {
+		match self.basis {
+			UnivariateBasis::Monomial
+				if self.coeffs.len().min(other.coeffs.len()) >= NTT_MUL_THRESHOLD =>
+			{
+				self.mul_ntt(other)
+			}
+			UnivariateBasis::Monomial => self.mul_karatsuba(other),
+			UnivariateBasis::Novel => self.mul_ntt(other),
+		}
+	}
+
+	/// Multiplies two polynomials in monomial basis with the quadratic schoolbook algorithm.
+	pub fn mul_schoolbook(&self, other: &Self) -> Result<Self, Error> {
+		self.check_basis(other, UnivariateBasis::Monomial)?;
+		Ok(Self::new(mul_schoolbook(&self.coeffs, &other.coeffs), UnivariateBasis::Monomial))
+	}
+
+	/// Multiplies two polynomials in monomial basis with Karatsuba's algorithm.
+	pub fn mul_karatsuba(&self, other: &Self) -> Result<Self, Error> {
+		self.check_basis(other, UnivariateBasis::Monomial)?;
+		Ok(Self::new(mul_karatsuba(&self.coeffs, &other.coeffs), UnivariateBasis::Monomial))
+	}
+
+	/// Multiplies two polynomials by pointwise multiplication of their evaluations over a
+	/// subspace, computed with the additive NTT.
+	///
+	/// Polynomials in monomial basis are converted to novel basis and back.
+	///
+	/// ## Throws
+	///
+	/// * `Error::DomainSizeTooLarge` if the degree of the product does not fit into the field
+	pub fn mul_ntt(&self, other: &Self) -> Result<Self, Error> {
+		self.check_same_basis(other)?;
+		if self.is_zero() || other.is_zero() {
+			return Ok(Self::zero(self.basis));
+		}
+
+		let log_size = log2_ceil_usize(self.coeffs.len() + other.coeffs.len() - 1);
+		if log_size > max_log_domain_size::<F>() {
+			return Err(Error::DomainSizeTooLarge);
+		}
+
+		// The NTT requires a domain of at least two points.
+		let ntt = AdditiveNTTWithOTFCompute::<F>::new(log_size.max(1))
+			.expect("log_size is checked against the field size above");
+		let mut lhs = zero_padded(&self.to_basis(UnivariateBasis::Novel)?.coeffs, 1 << log_size);
+		let mut rhs = zero_padded(&other.to_basis(UnivariateBasis::Novel)?.coeffs, 1 << log_size);
+		ntt.forward_transform(lhs.as_mut_slice(), 0, 0)
+			.expect("the data length matches the NTT domain size");
+		ntt.forward_transform(rhs.as_mut_slice(), 0, 0)
+			.expect("the data length matches the NTT domain size");
+
+		lhs.par_iter_mut()
+			.zip(rhs.par_iter())
+			.for_each(|(lhs_i, &rhs_i)| *lhs_i *= rhs_i);
+
+		ntt.inverse_transform(lhs.as_mut_slice(), 0, 0)
+			.expect("the data length matches the NTT domain size");
+		Self::new(lhs, UnivariateBasis::Novel).to_basis(self.basis)
+	}
+
+	/// Divides by `divisor`, returning the quotient and the remainder.
+	///
+	/// Polynomials in novel basis are converted to monomial basis and back.
+	///
+	/// ## Throws
+	///
+	/// * `Error::DivisionByZeroPolynomial` if `divisor` is zero
+	/// * `Error::UnivariateBasisMismatch` if the polynomials are in different bases
+	/// * `Error::DomainSizeTooLarge` if the degree of an operand does not fit into the field
+	pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+		self.check_same_basis(divisor)?;
+		let dividend = self.to_basis(UnivariateBasis::Monomial)?;
+		let divisor = divisor.to_basis(UnivariateBasis::Monomial)?;
+
+		let (quotient, remainder) = div_rem(&dividend.coeffs, &divisor.coeffs)?;
+		Ok((
+			Self::new(quotient, UnivariateBasis::Monomial).to_basis(self.basis)?,
+			Self::new(remainder, UnivariateBasis::Monomial).to_basis(self.basis)?,
+		))
+	}
+
+	/// Computes the monic greatest common divisor of two polynomials with Euclid's algorithm.
+	///
+	/// The GCD of two zero polynomials is zero. Polynomials in novel basis are converted to
+	/// monomial basis and back.
+	///
+	/// ## Throws
+	///
+	/// * `Error::UnivariateBasisMismatch` if the polynomials are in different bases
+	/// * `Error::DomainSizeTooLarge` if the degree of an operand does not fit into the field
+	pub fn gcd(&self, other: &Self) -> Result<Self, Error> {
+		self.check_same_basis(other)?;
+
+		let mut a = self.to_basis(UnivariateBasis::Monomial)?.coeffs;
+		let mut b = other.to_basis(UnivariateBasis::Monomial)?.coeffs;
+		while !b.is_empty() {
+			let (_, remainder) = div_rem(&a, &b)?;
+			a = b;
+			b = remainder;
+		}
+
+		if let Some(&lead) = a.last() {
+			let lead_inv = lead.invert().expect("leading coefficient is non-zero");
+			for coeff in a.iter_mut() {
+				*coeff *= lead_inv;
+			}
+		}
+		Self::new(a, UnivariateBasis::Monomial).to_basis(self.basis)
+	}
+
+	/// Evaluates the polynomial at a point.
+	pub fn evaluate(&self, x: F) -> F {
+		match self.basis {
+			UnivariateBasis::Monomial => evaluate_univariate(&self.coeffs, x),
+			UnivariateBasis::Novel => evaluate_novel(&self.coeffs, x),
+		}
+	}
+
+	/// Evaluates the polynomial at many points.
+	///
+	/// In monomial basis, large instances are evaluated with a remainder tree: the polynomial is
+	/// reduced modulo the vanishing polynomials of each half of the points recursively.
+	pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+		match self.basis {
+			UnivariateBasis::Monomial => evaluate_many_monomial(&self.coeffs, points),
+			UnivariateBasis::Novel => points
+				.par_iter()
+				.map(|&x| evaluate_novel(&self.coeffs, x))
+				.collect(),
+		}
+	}
+
+	/// Evaluates the polynomial over a coset of the additive NTT domain.
+	///
+	/// The evaluation points are the $2^{\text{log\_size}}$ points of the `coset`-th coset of the
+	/// subspace spanned by the first `log_size` canonical basis elements of the field, in the same
+	/// order as [`AdditiveNTT::forward_transform`] returns them. Polynomials in monomial basis are
+	/// converted to novel basis first.
+	pub fn evaluate_on_subspace(&self, log_size: usize, coset: u32) -> Result<Vec<F>, Error> {
+		let coset_bits = (u32::BITS - coset.leading_zeros()) as usize;
+		let max_log_size = max_log_domain_size::<F>().min(F::N_BITS.saturating_sub(coset_bits));
+		if log_size > max_log_size || self.coeffs.len() > 1 << log_size {
+			return Err(Error::ArgumentRangeError {
+				arg: "log_size".to_string(),
+				range: log2_ceil_usize(self.coeffs.len())..max_log_size + 1,
+			});
+		}
+
+		// The NTT requires a domain of at least two points.
+		let ntt = AdditiveNTTWithOTFCompute::<F>::new((log_size + coset_bits).max(1))
+			.expect("log_size is checked against the field size above");
+		let novel = self.to_basis(UnivariateBasis::Novel)?;
+		let mut evals = zero_padded(&novel.coeffs, 1 << log_size);
+		ntt.forward_transform(evals.as_mut_slice(), coset, 0)
+			.expect("the data length matches the NTT domain size");
+		Ok(evals)
+	}
+
+	fn check_same_basis(&self, other: &Self) -> Result<(), Error> {
+		if self.basis != other.basis {
+			return Err(Error::UnivariateBasisMismatch);
+		}
+		Ok(())
+	}
+
+	fn check_basis(&self, other: &Self, basis: UnivariateBasis) -> Result<(), Error> {
+		self.check_same_basis(other)?;
+		if self.basis != basis {
+			return Err(Error::UnsupportedUnivariateBasis { basis: self.basis });
+		}
+		Ok(())
+	}
+}
+
+/// Evaluates the normalized subspace polynomials $\hat{W}_0(x), \ldots, \hat{W}_{n-1}(x)$.
+///
+/// The unnormalized subspace polynomials satisfy the recurrence
+/// $W_{i+1}(X) = W_i(X)^2 + W_i(\beta_i) W_i(X)$, where $\beta_i$ are the canonical basis
+/// elements, and $\hat{W}_i = W_i / W_i(\beta_i)$.
+fn normalized_subspace_evals<F: BinaryField>(x: F, n: usize) -> Vec<F> {
+	// basis_evals[j] = W_i(β_j) for j >= i
+	let mut basis_evals = (0..n)
+		.map(|j| F::basis(j).expect("n is at most F::N_BITS"))
+		.collect::<Vec<_>>();
+	let mut x_eval = x;
+
+	let mut result = Vec::with_capacity(n);
+	for i in 0..n {
+		let norm_const = basis_evals[i];
+		result.push(
+			x_eval
+				* norm_const
+					.invert()
+					.expect("basis elements are linearly independent"),
+		);
+
+		x_eval = x_eval.square() + norm_const * x_eval;
+		for basis_eval in basis_evals[i + 1..].iter_mut() {
+			*basis_eval = basis_eval.square() + norm_const * *basis_eval;
+		}
+	}
+	result
+}
+
+/// The largest dimension of a subspace of `F` whose points can be indexed with a `usize`.
+fn max_log_domain_size<F: BinaryField>() -> usize {
+	F::N_BITS.min(usize::BITS as usize - 1)
+}
+
+fn evaluate_novel<F: BinaryField>(coeffs: &[F], x: F) -> F {
+	if coeffs.is_empty() {
+		return F::ZERO;
+	}
+
+	let log_len = log2_ceil_usize(coeffs.len());
+	let subspace_evals = normalized_subspace_evals(x, log_len);
+
+	// Fold the coefficients one basis bit at a time, like a multilinear evaluation.
+	let mut values = zero_padded(coeffs, 1 << log_len);
+	for (i, &w_i) in subspace_evals.iter().enumerate() {
+		for j in 0..1 << (log_len - i - 1) {
+			values[j] = values[2 * j] + values[2 * j + 1] * w_i;
+		}
+	}
+	values[0]
+}
+
+fn evaluate_many_monomial<F: Field>(coeffs: &[F], points: &[F]) -> Vec<F> {
+	if points.len() <= MULTIPOINT_EVAL_THRESHOLD || coeffs.len() <= MULTIPOINT_EVAL_THRESHOLD {
+		return points
+			.par_iter()
+			.map(|&x| evaluate_univariate(coeffs, x))
+			.collect();
+	}
+
+	let (left, right) = points.split_at(points.len() / 2);
+	let (_, left_remainder) =
+		div_rem(coeffs, &vanishing_coeffs(left)).expect("vanishing polynomial is monic");
+	let (_, right_remainder) =
+		div_rem(coeffs, &vanishing_coeffs(right)).expect("vanishing polynomial is monic");
+
+	let (mut evals, right_evals) = rayon::join(
+		|| evaluate_many_monomial(&left_remainder, left),
+		|| evaluate_many_monomial(&right_remainder, right),
+	);
+	evals.extend(right_evals);
+	evals
+}
+
+fn vanishing_coeffs<F: Field>(points: &[F]) -> Vec<F> {
+	match points {
+		[] => vec![F::ONE],
+		[point] => vec![-*point, F::ONE],
+		_ => {
+			let (left, right) = points.split_at(points.len() / 2);
+			mul_karatsuba(&vanishing_coeffs(left), &vanishing_coeffs(right))
+		}
+	}
+}
+
+fn div_rem<F: Field>(dividend: &[F], divisor: &[F]) -> Result<(Vec<F>, Vec<F>), Error> {
+	let divisor_len = divisor.len();
+	let lead_inv = divisor
+		.last()
+		.and_then(|lead| lead.invert())
+		.ok_or(Error::DivisionByZeroPolynomial)?;
+
+	if dividend.len() < divisor_len {
+		return Ok((Vec::new(), dividend.to_vec()));
+	}
+
+	let mut remainder = dividend.to_vec();
+	let mut quotient = vec![F::ZERO; dividend.len() - divisor_len + 1];
+	for i in (0..quotient.len()).rev() {
+		let coeff = remainder[i + divisor_len - 1] * lead_inv;
+		quotient[i] = coeff;
+		for (rem_j, &divisor_j) in remainder[i..i + divisor_len].iter_mut().zip(divisor) {
+			*rem_j -= coeff * divisor_j;
+		}
+	}
+
+	remainder.truncate(divisor_len - 1);
+	trim(&mut quotient);
+	trim(&mut remainder);
+	Ok((quotient, remainder))
+}
+
+fn mul_schoolbook<F: Field>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Vec::new();
+	}
+
+	let mut result = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+	for (i, &lhs_i) in lhs.iter().enumerate() {
+		for (result_ij, &rhs_j) in result[i..].iter_mut().zip(rhs) {
+			*result_ij += lhs_i * rhs_j;
+		}
+	}
+	result
+}
+
+fn mul_karatsuba<F: Field>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	if lhs.len().min(rhs.len()) <= KARATSUBA_THRESHOLD {
+		return mul_schoolbook(lhs, rhs);
+	}
+
+	let half = lhs.len().max(rhs.len()).div_ceil(2);
+	let mut result = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+
+	if lhs.len() <= half || rhs.len() <= half {
+		// Unbalanced operands: split only the longer one.
+		let (short, long) = if lhs.len() <= rhs.len() {
+			(lhs, rhs)
+		} else {
+			(rhs, lhs)
+		};
+		let (long_lo, long_hi) = long.split_at(half);
+		add_at(&mut result, &mul_karatsuba(short, long_lo), 0);
+		add_at(&mut result, &mul_karatsuba(short, long_hi), half);
+		return result;
+	}
+
+	let (lhs_lo, lhs_hi) = lhs.split_at(half);
+	let (rhs_lo, rhs_hi) = rhs.split_at(half);
+
+	let lo = mul_karatsuba(lhs_lo, rhs_lo);
+	let hi = mul_karatsuba(lhs_hi, rhs_hi);
+
+	let mut lhs_sum = lhs_lo.to_vec();
+	add_at(&mut lhs_sum, lhs_hi, 0);
+	let mut rhs_sum = rhs_lo.to_vec();
+	add_at(&mut rhs_sum, rhs_hi, 0);
+
+	// mid = (lhs_lo + lhs_hi) * (rhs_lo + rhs_hi) - lo - hi
+	let mut mid = mul_karatsuba(&lhs_sum, &rhs_sum);
+	for (mid_i, &lo_i) in mid.iter_mut().zip(&lo) {
+		*mid_i -= lo_i;
+	}
+	for (mid_i, &hi_i) in mid.iter_mut().zip(&hi) {
+		*mid_i -= hi_i;
+	}
+
+	// The trailing coefficients of `mid` beyond the product degree are zero, so truncating them
+	// in `add_at` is sound.
+	add_at(&mut result, &lo, 0);
+	add_at(&mut result, &mid, half);
+	add_at(&mut result, &hi, 2 * half);
+	result
+}
+
+/// Adds `addend` multiplied by $X^{\text{offset}}$ to `acc`, ignoring coefficients past the end.
+fn add_at<F: Field>(acc: &mut [F], addend: &[F], offset: usize) {
+	for (acc_i, &addend_i) in acc[offset..].iter_mut().zip(addend) {
+		*acc_i += addend_i;
+	}
+}
+
+fn zero_padded<F: Field>(coeffs: &[F], len: usize) -> Vec<F> {
+	let mut padded = Vec::with_capacity(len);
+	padded.extend_from_slice(coeffs);
+	padded.resize(len, F::ZERO);
+	padded
+}
+
+fn trim<F: Field>(coeffs: &mut Vec<F>) {
+	while coeffs.last().is_some_and(|coeff| *coeff == F::ZERO) {
+		coeffs.pop();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use assert_matches::assert_matches;
+	use binius_field::{
+		BinaryField128b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::iter::repeat_with;
+
+	fn random_poly<F: BinaryField>(
+		rng: &mut StdRng,
+		len: usize,
+		basis: UnivariateBasis,
+	) -> UnivariatePoly<F> {
+		let coeffs = repeat_with(|| <F as Field>::random(&mut *rng))
+			.take(len)
+			.collect();
+		UnivariatePoly::new(coeffs, basis)
+	}
+
+	#[test]
+	fn test_karatsuba_matches_schoolbook() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for (lhs_len, rhs_len) in [
+			(0, 10),
+			(10, 10),
+			(100, 100),
+			(150, 37),
+			(33, 260),
+			(257, 255),
+		] {
+			let lhs = random_poly::<BinaryField32b>(&mut rng, lhs_len, UnivariateBasis::Monomial);
+			let rhs = random_poly::<BinaryField32b>(&mut rng, rhs_len, UnivariateBasis::Monomial);
+			assert_eq!(lhs.mul_karatsuba(&rhs).unwrap(), lhs.mul_schoolbook(&rhs).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_ntt_mul_in_novel_basis() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let lhs = random_poly::<BinaryField32b>(&mut rng, 100, UnivariateBasis::Novel);
+		let rhs = random_poly::<BinaryField32b>(&mut rng, 61, UnivariateBasis::Novel);
+		let product = lhs.mul(&rhs).unwrap();
+		assert_eq!(product.degree(), Some(99 + 60));
+
+		for _ in 0..10 {
+			let x = <BinaryField32b as Field>::random(&mut rng);
+			assert_eq!(product.evaluate(x), lhs.evaluate(x) * rhs.evaluate(x));
+		}
+	}
+
+	#[test]
+	fn test_basis_conversion() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let novel = random_poly::<BinaryField32b>(&mut rng, 77, UnivariateBasis::Novel);
+		let monomial = novel.to_basis(UnivariateBasis::Monomial).unwrap();
+		assert_eq!(monomial.degree(), novel.degree());
+
+		for _ in 0..10 {
+			let x = <BinaryField32b as Field>::random(&mut rng);
+			assert_eq!(monomial.evaluate(x), novel.evaluate(x));
+		}
+		assert_eq!(monomial.to_basis(UnivariateBasis::Novel).unwrap(), novel);
+	}
+
+	#[test]
+	fn test_ntt_mul_in_monomial_basis() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let lhs = random_poly::<BinaryField32b>(&mut rng, 600, UnivariateBasis::Monomial);
+		let rhs = random_poly::<BinaryField32b>(&mut rng, 513, UnivariateBasis::Monomial);
+		assert_eq!(lhs.mul_ntt(&rhs).unwrap(), lhs.mul_karatsuba(&rhs).unwrap());
+	}
+
+	#[test]
+	fn test_novel_evaluation_matches_ntt() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_size = 5;
+		let poly = random_poly::<BinaryField8b>(&mut rng, 1 << log_size, UnivariateBasis::Novel);
+
+		for coset in 0..4 {
+			let evals = poly.evaluate_on_subspace(log_size, coset).unwrap();
+			for (i, &eval) in evals.iter().enumerate() {
+				let index = (coset as usize) << log_size | i;
+				let point = (0..8)
+					.filter(|b| (index >> b) & 1 == 1)
+					.map(|b| <BinaryField8b as ExtensionField<BinaryField1b>>::basis(b).unwrap())
+					.sum::<BinaryField8b>();
+				assert_eq!(poly.evaluate(point), eval);
+			}
+		}
+	}
+
+	#[test]
+	fn test_evaluate_on_subspace_rejects_unaddressable_domain() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly = random_poly::<BinaryField128b>(&mut rng, 4, UnivariateBasis::Novel);
+		for log_size in [64, 100, 129] {
+			assert_matches!(
+				poly.evaluate_on_subspace(log_size, 0),
+				Err(Error::ArgumentRangeError { .. })
+			);
+		}
+	}
+
+	#[test]
+	fn test_div_rem() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let dividend = random_poly::<BinaryField32b>(&mut rng, 50, UnivariateBasis::Monomial);
+		let divisor = random_poly::<BinaryField32b>(&mut rng, 13, UnivariateBasis::Monomial);
+
+		let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+		assert!(remainder.degree() < divisor.degree());
+		assert_eq!(quotient.mul(&divisor).unwrap().add(&remainder).unwrap(), dividend);
+
+		let zero = UnivariatePoly::zero(UnivariateBasis::Monomial);
+		assert_matches!(dividend.div_rem(&zero), Err(Error::DivisionByZeroPolynomial));
+	}
+
+	#[test]
+	fn test_div_rem_in_novel_basis() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let dividend = random_poly::<BinaryField32b>(&mut rng, 50, UnivariateBasis::Novel);
+		let divisor = random_poly::<BinaryField32b>(&mut rng, 13, UnivariateBasis::Novel);
+
+		let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+		assert_eq!(quotient.basis(), UnivariateBasis::Novel);
+		assert_eq!(remainder.basis(), UnivariateBasis::Novel);
+		assert!(remainder.degree() < divisor.degree());
+		assert_eq!(quotient.mul(&divisor).unwrap().add(&remainder).unwrap(), dividend);
+
+		let (monomial_quotient, monomial_remainder) = dividend
+			.to_basis(UnivariateBasis::Monomial)
+			.unwrap()
+			.div_rem(&divisor.to_basis(UnivariateBasis::Monomial).unwrap())
+			.unwrap();
+		assert_eq!(quotient.to_basis(UnivariateBasis::Monomial).unwrap(), monomial_quotient);
+		assert_eq!(remainder.to_basis(UnivariateBasis::Monomial).unwrap(), monomial_remainder);
+	}
+
+	#[test]
+	fn test_gcd() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let points = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(12)
+			.collect::<Vec<_>>();
+
+		let lhs = UnivariatePoly::vanishing(&points[..8]);
+		let rhs = UnivariatePoly::vanishing(&points[4..]);
+		assert_eq!(lhs.gcd(&rhs).unwrap(), UnivariatePoly::vanishing(&points[4..8]));
+	}
+
+	#[test]
+	fn test_gcd_in_novel_basis() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let points = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(12)
+			.collect::<Vec<_>>();
+
+		let to_novel =
+			|poly: UnivariatePoly<BinaryField32b>| poly.to_basis(UnivariateBasis::Novel).unwrap();
+		let lhs = to_novel(UnivariatePoly::vanishing(&points[..8]));
+		let rhs = to_novel(UnivariatePoly::vanishing(&points[4..]));
+		let gcd = lhs.gcd(&rhs).unwrap();
+		assert_eq!(gcd, to_novel(UnivariatePoly::vanishing(&points[4..8])));
+	}
+
+	#[test]
+	fn test_evaluate_many() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly = random_poly::<BinaryField32b>(&mut rng, 300, UnivariateBasis::Monomial);
+		let points = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(200)
+			.collect::<Vec<_>>();
+
+		let expected = points.iter().map(|&x| poly.evaluate(x)).collect::<Vec<_>>();
+		assert_eq!(poly.evaluate_many(&points), expected);
+
+		let vanishing = UnivariatePoly::vanishing(&points);
+		assert!(vanishing
+			.evaluate_many(&points)
+			.into_iter()
+			.all(|eval| eval == BinaryField32b::ZERO));
+	}
+
+	#[test]
+	fn test_basis_mismatch() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let monomial = random_poly::<BinaryField8b>(&mut rng, 4, UnivariateBasis::Monomial);
+		let novel = random_poly::<BinaryField8b>(&mut rng, 4, UnivariateBasis::Novel);
+		assert_matches!(monomial.add(&novel), Err(Error::UnivariateBasisMismatch));
+		assert_matches!(
+			novel.mul_karatsuba(&novel),
+			Err(Error::UnsupportedUnivariateBasis {
+				basis: UnivariateBasis::Novel
+			})
+		);
+	}
+}
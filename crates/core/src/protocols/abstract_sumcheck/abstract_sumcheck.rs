@@ -6,7 +6,7 @@ use crate::{
 	oracle::CompositePolyOracle, polynomial::EvaluationDomain, protocols::evalcheck::EvalcheckClaim,
 };
 
-use super::{Error, VerificationError};
+use super::{BatchBindingOrder, Error, VerificationError};
 
 #[derive(Debug, Clone)]
 pub struct AbstractSumcheckRound<F> {
@@ -15,7 +15,9 @@ pub struct AbstractSumcheckRound<F> {
 	/// For proof-size optimization, this vector is
 	/// trimmed as much as possible such that the verifier
 	/// can recover the missing coefficients. Which specific
-	/// coefficients are missing depends on context.
+	/// coefficients are missing depends on context, but their
+	/// positions must not depend on the degree of the round
+	/// polynomial, so that round messages can be mixed when batching.
 	pub coeffs: Vec<F>,
 }
 
@@ -86,12 +88,13 @@ pub trait AbstractSumcheckProver<F: Field> {
 
 	fn finalize(self, prev_rd_challenge: Option<F>) -> Result<ReducedClaim<F>, Self::Error>;
 
-	/// Returns whether two provers may be used together in a batch proof
+	/// Returns whether two provers may be used together in a batch proof with the given binding
+	/// order
 	///
 	/// REQUIRES:
 	/// * The relation between batch-consistent provers is an equivalence relation
 	/// * self.n_vars() >= other.n_vars()
-	fn batch_proving_consistent(&self, other: &Self) -> bool;
+	fn batch_proving_consistent(&self, other: &Self, binding_order: BatchBindingOrder) -> bool;
 
 	fn n_vars(&self) -> usize;
 }
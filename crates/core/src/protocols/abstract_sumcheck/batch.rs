@@ -48,11 +48,29 @@ impl<F: Field> From<BatchedAbstractSumcheckRoundClaim<F>> for AbstractSumcheckRo
 	}
 }
 
+/// The order in which the variables of claims over fewer variables are bound in a batch.
+///
+/// Both orders bind the claims in a batch over $n$ rounds, where $n$ is the largest number of
+/// variables, without scaling the claims over fewer variables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BatchBindingOrder {
+	/// Claims over $k < n$ variables join the batch in round $n - k$, so that they are bound over
+	/// the last $k$ round challenges. Batching coefficients are sampled when the claims join.
+	#[default]
+	BackLoaded,
+	/// All claims are in the batch from the first round, and claims over $k < n$ variables leave
+	/// it after round $k - 1$, so that they are bound over the first $k$ round challenges. The
+	/// prover sends the evaluation of a claim when it leaves, which the verifier subtracts from
+	/// the batched round claim.
+	FrontLoaded,
+}
+
 /// Prove a batched abstract sumcheck instance.
 ///
 /// See module documentation for details.
 pub fn batch_prove<F, ASP, CH>(
 	provers: impl IntoIterator<Item = ASP>,
+	binding_order: BatchBindingOrder,
 	mut challenger: CH,
 ) -> Result<AbstractSumcheckBatchProveOutput<F>, ASP::Error>
 where
@@ -60,7 +78,7 @@ where
 	ASP: AbstractSumcheckProver<F>,
 	CH: CanObserve<F> + CanSample<F>,
 {
-	let (original_indices, mut sorted_provers) = stable_sort(provers, |prover| prover.n_vars());
+	let (original_indices, sorted_provers) = stable_sort(provers, |prover| prover.n_vars());
 
 	if sorted_provers.is_empty() {
 		return Err(Error::EmptyBatch.into());
@@ -69,12 +87,46 @@ where
 	let first_prover = &sorted_provers[0];
 	let is_batch_elligible = sorted_provers
 		.iter()
-		.all(|prover| first_prover.batch_proving_consistent(prover));
+		.all(|prover| first_prover.batch_proving_consistent(prover, binding_order));
 	if !is_batch_elligible {
 		return Err(Error::InelligibleBatch.into());
 	}
 
-	let n_rounds = first_prover.n_vars();
+	let (round_proofs, sorted_reduced_claims) = match binding_order {
+		BatchBindingOrder::BackLoaded => prove_back_loaded(sorted_provers, &mut challenger)?,
+		BatchBindingOrder::FrontLoaded => prove_front_loaded(sorted_provers, &mut challenger)?,
+	};
+
+	let sorted_evals = sorted_reduced_claims
+		.iter()
+		.map(|claim| claim.eval)
+		.collect();
+
+	let sumcheck_batch_proof = AbstractSumcheckBatchProof {
+		rounds: round_proofs,
+		sorted_evals,
+	};
+
+	let reduced_claims = unsort(original_indices, sorted_reduced_claims);
+
+	Ok(AbstractSumcheckBatchProveOutput {
+		proof: sumcheck_batch_proof,
+		reduced_claims,
+	})
+}
+
+type RoundProofsAndReducedClaims<F> = (Vec<AbstractSumcheckRound<F>>, Vec<ReducedClaim<F>>);
+
+fn prove_back_loaded<F, ASP, CH>(
+	mut sorted_provers: Vec<ASP>,
+	mut challenger: CH,
+) -> Result<RoundProofsAndReducedClaims<F>, ASP::Error>
+where
+	F: Field,
+	ASP: AbstractSumcheckProver<F>,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let n_rounds = sorted_provers[0].n_vars();
 
 	let mut first_batch_coeff = Some(F::ONE);
 	let mut batch_coeffs = Vec::with_capacity(sorted_provers.len());
@@ -121,22 +173,59 @@ where
 		})
 		.collect::<Result<Vec<_>, _>>()?;
 
-	let sorted_evals = sorted_reduced_claims
-		.iter()
-		.map(|claim| claim.eval)
-		.collect();
+	Ok((round_proofs, sorted_reduced_claims))
+}
 
-	let sumcheck_batch_proof = AbstractSumcheckBatchProof {
-		rounds: round_proofs,
-		sorted_evals,
-	};
+fn prove_front_loaded<F, ASP, CH>(
+	mut sorted_provers: Vec<ASP>,
+	mut challenger: CH,
+) -> Result<RoundProofsAndReducedClaims<F>, ASP::Error>
+where
+	F: Field,
+	ASP: AbstractSumcheckProver<F>,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let n_rounds = sorted_provers[0].n_vars();
 
-	let reduced_claims = unsort(original_indices, sorted_reduced_claims);
+	let mut first_batch_coeff = Some(F::ONE);
+	let batch_coeffs = (0..sorted_provers.len())
+		.map(|_| make_batching_coeff(&mut first_batch_coeff, &mut challenger))
+		.collect::<Vec<_>>();
 
-	Ok(AbstractSumcheckBatchProveOutput {
-		proof: sumcheck_batch_proof,
-		reduced_claims,
-	})
+	let mut round_proofs = Vec::with_capacity(n_rounds);
+	let mut reversed_reduced_claims = Vec::with_capacity(sorted_provers.len());
+
+	let mut prev_rd_challenge = None;
+	for round_no in 0..=n_rounds {
+		// Provers are sorted in descending order by n_vars, hence the provers leaving the batch
+		// after round_no rounds are at the end
+		while sorted_provers
+			.last()
+			.is_some_and(|prover| prover.n_vars() == round_no)
+		{
+			let prover = sorted_provers.pop().expect("checked above");
+			let reduced_claim = prover.finalize(prev_rd_challenge)?;
+			challenger.observe(reduced_claim.eval);
+			reversed_reduced_claims.push(reduced_claim);
+		}
+
+		if round_no == n_rounds {
+			break;
+		}
+
+		let mut batch_round_proof = AbstractSumcheckRound { coeffs: Vec::new() };
+		for (prover, &coeff) in sorted_provers.iter_mut().zip(batch_coeffs.iter()) {
+			let proof = prover.execute_round(prev_rd_challenge)?;
+			mix_round_proofs(&mut batch_round_proof, &proof, coeff);
+		}
+
+		challenger.observe_slice(&batch_round_proof.coeffs);
+		round_proofs.push(batch_round_proof);
+		prev_rd_challenge = Some(challenger.sample());
+	}
+
+	reversed_reduced_claims.reverse();
+	Ok((round_proofs, reversed_reduced_claims))
 }
 
 /// Verify a batched abstract sumcheck instance.
@@ -145,6 +234,7 @@ where
 pub fn batch_verify<F, ASR, CH>(
 	claims: impl IntoIterator<Item = AbstractSumcheckClaim<F>>,
 	proof: AbstractSumcheckBatchProof<F>,
+	binding_order: BatchBindingOrder,
 	reductor: ASR,
 	mut challenger: CH,
 ) -> Result<Vec<ReducedClaim<F>>, ASR::Error>
@@ -164,6 +254,49 @@ where
 		return Err(Error::Verification(VerificationError::NumberOfRounds).into());
 	}
 
+	let eval_point = match binding_order {
+		BatchBindingOrder::BackLoaded => {
+			verify_back_loaded(&sorted_claims, &proof, reductor, &mut challenger)?
+		}
+		BatchBindingOrder::FrontLoaded => {
+			verify_front_loaded(&sorted_claims, &proof, reductor, &mut challenger)?
+		}
+	};
+
+	let sorted_reduced_claims =
+		proof
+			.sorted_evals
+			.iter()
+			.zip(sorted_claims)
+			.map(|(eval, claim)| {
+				let eval_point = match binding_order {
+					BatchBindingOrder::BackLoaded => &eval_point[n_rounds - claim.n_vars()..],
+					BatchBindingOrder::FrontLoaded => &eval_point[..claim.n_vars()],
+				};
+				ReducedClaim {
+					eval_point: eval_point.to_vec(),
+					eval: *eval,
+				}
+			});
+
+	let reduced_claims = unsort(original_indices, sorted_reduced_claims);
+
+	Ok(reduced_claims)
+}
+
+fn verify_back_loaded<F, ASR, CH>(
+	sorted_claims: &[AbstractSumcheckClaim<F>],
+	proof: &AbstractSumcheckBatchProof<F>,
+	reductor: ASR,
+	mut challenger: CH,
+) -> Result<Vec<F>, ASR::Error>
+where
+	F: Field,
+	CH: CanSample<F> + CanObserve<F>,
+	ASR: AbstractSumcheckReductor<F>,
+{
+	let n_rounds = proof.rounds.len();
+
 	let mut first_batch_coeff = Some(F::ONE);
 	let mut batch_coeffs = Vec::with_capacity(sorted_claims.len());
 	let mut rd_claim = BatchedAbstractSumcheckRoundClaim {
@@ -242,21 +375,76 @@ where
 		.map(|(eval, coeff)| *eval * coeff)
 		.sum::<F>();
 
-	assert_eq!(batched_eval, final_eval);
+	if batched_eval != final_eval {
+		return Err(Error::Verification(VerificationError::IncorrectBatchEvaluation).into());
+	}
 
-	let sorted_reduced_claims =
-		proof
-			.sorted_evals
+	Ok(eval_point)
+}
+
+fn verify_front_loaded<F, ASR, CH>(
+	sorted_claims: &[AbstractSumcheckClaim<F>],
+	proof: &AbstractSumcheckBatchProof<F>,
+	reductor: ASR,
+	mut challenger: CH,
+) -> Result<Vec<F>, ASR::Error>
+where
+	F: Field,
+	CH: CanSample<F> + CanObserve<F>,
+	ASR: AbstractSumcheckReductor<F>,
+{
+	let n_rounds = proof.rounds.len();
+
+	if proof.sorted_evals.len() != sorted_claims.len() {
+		return Err(Error::Verification(VerificationError::NumberOfFinalEvaluations).into());
+	}
+
+	let mut first_batch_coeff = Some(F::ONE);
+	let batch_coeffs = (0..sorted_claims.len())
+		.map(|_| make_batching_coeff(&mut first_batch_coeff, &mut challenger))
+		.collect::<Vec<_>>();
+
+	let mut rd_claim = BatchedAbstractSumcheckRoundClaim {
+		partial_point: Vec::with_capacity(n_rounds),
+		current_batched_round_sum: sorted_claims
 			.iter()
-			.zip(sorted_claims)
-			.map(|(eval, claim)| ReducedClaim {
-				eval_point: eval_point[n_rounds - claim.n_vars()..].to_vec(),
-				eval: *eval,
-			});
+			.zip(batch_coeffs.iter())
+			.map(|(claim, &coeff)| claim.sum * coeff)
+			.sum(),
+	};
 
-	let reduced_claims = unsort(original_indices, sorted_reduced_claims);
+	let mut n_active_claims = sorted_claims.len();
+	for round_no in 0..=n_rounds {
+		// Remove the evaluations of the claims leaving the batch after round_no rounds
+		while n_active_claims > 0 && sorted_claims[n_active_claims - 1].n_vars() == round_no {
+			n_active_claims -= 1;
 
-	Ok(reduced_claims)
+			let eval = proof.sorted_evals[n_active_claims];
+			challenger.observe(eval);
+			rd_claim.current_batched_round_sum -= eval * batch_coeffs[n_active_claims];
+		}
+
+		let Some(round_proof) = proof.rounds.get(round_no) else {
+			break;
+		};
+
+		challenger.observe_slice(round_proof.coeffs.as_slice());
+		rd_claim = reductor
+			.reduce_round_claim(
+				round_no,
+				rd_claim.into(),
+				challenger.sample(),
+				round_proof.clone(),
+			)?
+			.into();
+	}
+
+	// Every claim has left the batch, hence its evaluations must account for the whole sum
+	if rd_claim.current_batched_round_sum != F::ZERO {
+		return Err(Error::Verification(VerificationError::IncorrectBatchEvaluation).into());
+	}
+
+	Ok(rd_claim.partial_point)
 }
 
 fn mix_round_proofs<F: Field>(
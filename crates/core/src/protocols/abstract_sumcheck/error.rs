@@ -26,4 +26,6 @@ pub enum VerificationError {
 	NumberOfBatchCoeffs,
 	#[error("the number of final evaluations must match the number of instances")]
 	NumberOfFinalEvaluations,
+	#[error("the final evaluations do not match the batched round claim")]
+	IncorrectBatchEvaluation,
 }
@@ -117,6 +117,14 @@ pub trait AbstractSumcheckEvaluator<P: PackedField>: Sync {
 ///
 /// NB. Note that `switchover=0` does not make sense, as first round is never folded.//
 ///
+/// Pushing the switchover towards the middle round bounds the large field memory of this state to
+/// $O(\sqrt{2^{n\_vars}})$ scalars per multilinear, recomputing the early rounds from the small
+/// field multilinears at the cost of $O(n\_vars)$ passes over the witness. Only the plain sumcheck
+/// prover holds no other large field buffers, see
+/// [`sumcheck::streaming_switchover_fn`](crate::protocols::sumcheck::streaming_switchover_fn);
+/// zerocheck and GKR sumcheck provers additionally hold equality indicator tables of
+/// $2^{n\_vars - 1}$ scalars.
+///
 /// [Gruen24]: https://eprint.iacr.org/2024/108
 #[derive(Debug)]
 pub struct ProverState<PW, M: Send>
@@ -168,6 +176,23 @@ where
 		})
 	}
 
+	/// The number of large field scalars held by the state, ie. the allocated tensor product of
+	/// the pre-switchover challenges and the folded multilinears.
+	pub fn large_field_size(&self) -> usize {
+		let query_size = self.query.as_ref().map_or(0, |query| query.capacity());
+		let folded_size = self
+			.multilinears
+			.iter()
+			.map(|multilin| match multilin {
+				SumcheckMultilinear::Transparent { .. } => 0,
+				SumcheckMultilinear::Folded {
+					large_field_folded_multilin,
+				} => large_field_folded_multilin.size(),
+			})
+			.sum::<usize>();
+		query_size + folded_size
+	}
+
 	/// Fold all stored multilinears with the verifier challenge received in the previous round.
 	///
 	/// This manages whether to partially evaluate the multilinear at an extension point
@@ -0,0 +1,325 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{Error, VerificationError};
+use crate::{
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle},
+	protocols::gkr_gpa::{GrandProductClaim, GrandProductWitness},
+	witness::MultilinearWitness,
+};
+use binius_field::{Field, TowerField};
+use getset::{CopyGetters, Getters};
+use std::{collections::BTreeMap, iter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushDirection {
+	Push,
+	Pull,
+}
+
+/// The number of times each row of a flush is sent to or received from its channel.
+#[derive(Debug, Clone)]
+pub enum FlushMultiplicity<F: Field> {
+	/// Every row is flushed the same number of times.
+	Constant(u64),
+	/// Row $i$ is flushed $\sum_b 2^b s_b(i)$ times, given the little-endian bit columns $s_b$.
+	Bits(Vec<MultilinearPolyOracle<F>>),
+}
+
+/// The rows of a tuple of columns, sent to or received from a channel with multiplicities.
+#[derive(Debug, Clone, Getters, CopyGetters)]
+pub struct Flush<F: Field> {
+	#[get_copy = "pub"]
+	channel_id: usize,
+	#[get_copy = "pub"]
+	direction: FlushDirection,
+	#[get = "pub"]
+	oracles: Vec<MultilinearPolyOracle<F>>,
+	#[get = "pub"]
+	multiplicity: FlushMultiplicity<F>,
+}
+
+impl<F: TowerField> Flush<F> {
+	/// Construct a flush of the rows of `oracles`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::EmptyFlush`] if there are no oracles
+	/// * [`Error::NumVariablesMismatch`] if the oracles and multiplicity bits differ in size
+	/// * [`Error::TooManyMultiplicityBits`] if there are more than 64 multiplicity bits
+	/// * [`Error::MultiplicityNotBinary`] if a multiplicity bit oracle is not binary
+	pub fn new(
+		channel_id: usize,
+		direction: FlushDirection,
+		oracles: Vec<MultilinearPolyOracle<F>>,
+		multiplicity: FlushMultiplicity<F>,
+	) -> Result<Self, Error> {
+		let n_vars = oracles.first().ok_or(Error::EmptyFlush)?.n_vars();
+		if oracles.iter().any(|oracle| oracle.n_vars() != n_vars) {
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		if let FlushMultiplicity::Bits(bits) = &multiplicity {
+			if bits.len() > 64 {
+				return Err(Error::TooManyMultiplicityBits);
+			}
+			if bits.iter().any(|bit| bit.n_vars() != n_vars) {
+				return Err(Error::NumVariablesMismatch);
+			}
+			// Soundness relies on the bits being boolean, which only holds by construction for
+			// oracles of the smallest tower level
+			if bits.iter().any(|bit| bit.binary_tower_level() != 0) {
+				return Err(Error::MultiplicityNotBinary);
+			}
+		}
+
+		Ok(Self {
+			channel_id,
+			direction,
+			oracles,
+			multiplicity,
+		})
+	}
+}
+
+impl<F: Field> Flush<F> {
+	pub fn n_vars(&self) -> usize {
+		self.oracles[0].n_vars()
+	}
+
+	pub fn arity(&self) -> usize {
+		self.oracles.len()
+	}
+}
+
+/// A claim that every channel is balanced, ie. that the multiset of tuples pushed to each
+/// channel equals the multiset of tuples pulled from it.
+#[derive(Debug, Clone, Getters)]
+pub struct ChannelClaim<F: Field> {
+	#[get = "pub"]
+	flushes: Vec<Flush<F>>,
+}
+
+impl<F: Field> ChannelClaim<F> {
+	/// ## Throws
+	///
+	/// * [`Error::ChannelArityMismatch`] if the flushes to some channel have different arities
+	pub fn new(flushes: Vec<Flush<F>>) -> Result<Self, Error> {
+		check_channel_arities(&flushes)?;
+		Ok(Self { flushes })
+	}
+}
+
+#[derive(Debug, Getters)]
+pub struct FlushWitness<'a, FW: Field> {
+	#[get = "pub"]
+	oracles: Vec<MultilinearWitness<'a, FW>>,
+	/// Witnesses of the multiplicity bits, empty for constant multiplicities
+	#[get = "pub"]
+	multiplicity_bits: Vec<MultilinearWitness<'a, FW>>,
+}
+
+impl<'a, FW: Field> FlushWitness<'a, FW> {
+	pub fn new(
+		oracles: Vec<MultilinearWitness<'a, FW>>,
+		multiplicity_bits: Vec<MultilinearWitness<'a, FW>>,
+	) -> Result<Self, Error> {
+		let n_vars = oracles.first().ok_or(Error::EmptyFlush)?.n_vars();
+		if oracles
+			.iter()
+			.chain(multiplicity_bits.iter())
+			.any(|oracle| oracle.n_vars() != n_vars)
+		{
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		Ok(Self {
+			oracles,
+			multiplicity_bits,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.oracles[0].n_vars()
+	}
+}
+
+/// Witnesses of the flushes of a [`ChannelClaim`], in the same order.
+#[derive(Debug, Getters)]
+pub struct ChannelWitness<'a, FW: Field> {
+	#[get = "pub"]
+	flushes: Vec<FlushWitness<'a, FW>>,
+}
+
+impl<'a, FW: Field> ChannelWitness<'a, FW> {
+	pub fn new(flushes: Vec<FlushWitness<'a, FW>>) -> Self {
+		Self { flushes }
+	}
+}
+
+/// The grand products sent by the prover, which must be observed by the challenger before
+/// proving the grand product claims.
+#[derive(Debug, Clone)]
+pub struct ChannelProof<F> {
+	/// Grand products in the order of the grand product claims
+	pub products: Vec<F>,
+}
+
+#[derive(Debug)]
+pub struct ChannelProveOutput<F: Field, FW: Field> {
+	pub proof: ChannelProof<F>,
+	/// Grand product claims on the tuple oracle of every flush, each followed by the selected
+	/// oracles of the flush
+	pub grand_product_claims: Vec<GrandProductClaim<F>>,
+	pub grand_product_witnesses: Vec<GrandProductWitness<FW>>,
+}
+
+/// The linear combination oracles of a flush with $k$-ary tuples $(x_0, \ldots, x_{k-1})$.
+#[derive(Debug)]
+pub(super) struct FlushOracles<F: Field> {
+	/// $\gamma + \sum_j \alpha^j x_j$
+	pub tuple_oracle: MultilinearPolyOracle<F>,
+	/// $\gamma + \alpha^k s + \sum_j \alpha^j x_j$ for a selector $s$ of the flushed rows,
+	/// together with the number of times the selected rows are flushed
+	pub selected_oracles: Vec<(MultilinearPolyOracle<F>, u64)>,
+}
+
+/// The selectors of the flushed rows of a flush, `None` standing for all rows, together with the
+/// number of times the selected rows are flushed.
+fn selectors<F: Field>(
+	multiplicity: &FlushMultiplicity<F>,
+) -> Vec<(Option<&MultilinearPolyOracle<F>>, u64)> {
+	match multiplicity {
+		FlushMultiplicity::Constant(0) => Vec::new(),
+		FlushMultiplicity::Constant(count) => vec![(None, *count)],
+		FlushMultiplicity::Bits(bits) => bits
+			.iter()
+			.enumerate()
+			.map(|(i, bit)| (Some(bit), 1 << i))
+			.collect(),
+	}
+}
+
+/// Add the linear combination oracles of every flush.
+///
+/// The selected oracles of a flush are $\alpha^k$ away from the tuple oracle on the selected rows
+/// and equal to it elsewhere, so that the ratio of their grand products is the product of
+/// $(\gamma + \alpha^k + x) / (\gamma + x)$ over the selected tuples $x$. As $\alpha^k$ does not
+/// occur in the encoding of the tuples, this is an injective encoding of the selected multiset.
+pub(super) fn reduce_channel_claim<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	claim: &ChannelClaim<F>,
+	gamma: F,
+	alpha: F,
+) -> Result<Vec<FlushOracles<F>>, Error> {
+	claim
+		.flushes
+		.iter()
+		.map(|flush| -> Result<_, Error> {
+			let n_vars = flush.n_vars();
+			let tuple_terms = flush
+				.oracles
+				.iter()
+				.zip(iter::successors(Some(F::ONE), |&coeff| Some(coeff * alpha)))
+				.map(|(oracle, coeff)| (oracle.id(), coeff))
+				.collect::<Vec<_>>();
+			let selector_coeff = alpha.pow([flush.arity() as u64]);
+
+			let tuple_id = oracles.add_linear_combination_with_offset(
+				n_vars,
+				gamma,
+				tuple_terms.iter().copied(),
+			)?;
+
+			let selected_oracles = selectors(&flush.multiplicity)
+				.into_iter()
+				.map(|(selector, count)| -> Result<_, Error> {
+					let id = match selector {
+						Some(selector) => oracles.add_linear_combination_with_offset(
+							n_vars,
+							gamma,
+							tuple_terms
+								.iter()
+								.copied()
+								.chain([(selector.id(), selector_coeff)]),
+						)?,
+						None => oracles.add_linear_combination_with_offset(
+							n_vars,
+							gamma + selector_coeff,
+							tuple_terms.iter().copied(),
+						)?,
+					};
+					Ok((oracles.oracle(id), count))
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			Ok(FlushOracles {
+				tuple_oracle: oracles.oracle(tuple_id),
+				selected_oracles,
+			})
+		})
+		.collect()
+}
+
+/// Check that the grand products balance every channel and make the grand product claims.
+pub(super) fn make_grand_product_claims<F: TowerField>(
+	claim: &ChannelClaim<F>,
+	flush_oracles: Vec<FlushOracles<F>>,
+	proof: &ChannelProof<F>,
+) -> Result<Vec<GrandProductClaim<F>>, Error> {
+	let n_products = flush_oracles
+		.iter()
+		.map(|oracles| 1 + oracles.selected_oracles.len())
+		.sum::<usize>();
+	if proof.products.len() != n_products {
+		return Err(VerificationError::NumberOfProducts.into());
+	}
+
+	// Each channel is balanced iff the products of the ratios of the selected and tuple grand
+	// products, raised to the flush counts, agree on the push and pull sides. Both sides are
+	// multiplied through by the denominators to avoid inversions.
+	let mut balances = BTreeMap::<usize, [F; 2]>::new();
+	let mut products = proof.products.iter().copied();
+	let mut claims = Vec::with_capacity(n_products);
+	for (flush, oracles) in claim.flushes.iter().zip(flush_oracles) {
+		let balance = balances.entry(flush.channel_id).or_insert([F::ONE; 2]);
+		let (numerator_side, denominator_side) = match flush.direction {
+			FlushDirection::Push => (0, 1),
+			FlushDirection::Pull => (1, 0),
+		};
+
+		let tuple_product = products.next().expect("number of products checked above");
+		claims.push(GrandProductClaim {
+			poly: oracles.tuple_oracle,
+			product: tuple_product,
+		});
+
+		for (poly, count) in oracles.selected_oracles {
+			let selected_product = products.next().expect("number of products checked above");
+			balance[numerator_side] *= selected_product.pow([count]);
+			balance[denominator_side] *= tuple_product.pow([count]);
+			claims.push(GrandProductClaim {
+				poly,
+				product: selected_product,
+			});
+		}
+	}
+
+	if let Some((&channel_id, _)) = balances.iter().find(|(_, [lhs, rhs])| lhs != rhs) {
+		return Err(VerificationError::ChannelNotBalanced { channel_id }.into());
+	}
+
+	Ok(claims)
+}
+
+fn check_channel_arities<F: Field>(flushes: &[Flush<F>]) -> Result<(), Error> {
+	let mut arities = BTreeMap::new();
+	for flush in flushes {
+		let arity = *arities.entry(flush.channel_id).or_insert(flush.arity());
+		if arity != flush.arity() {
+			return Err(Error::ChannelArityMismatch {
+				channel_id: flush.channel_id,
+			});
+		}
+	}
+	Ok(())
+}
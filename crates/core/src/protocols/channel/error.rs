@@ -0,0 +1,38 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::Error as OracleError, polynomial::Error as PolynomialError,
+	protocols::gkr_gpa::Error as GkrGpaError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("a flush must have at least one oracle")]
+	EmptyFlush,
+	#[error("the number of variables in some multilinear oracle is not equal to the rest")]
+	NumVariablesMismatch,
+	#[error("multiplicity oracles must have binary tower level 0")]
+	MultiplicityNotBinary,
+	#[error("a multiplicity can have at most 64 bits")]
+	TooManyMultiplicityBits,
+	#[error("flushes to channel {channel_id} have different arities")]
+	ChannelArityMismatch { channel_id: usize },
+	#[error("the witness does not match the flushes of the claim")]
+	WitnessFlushesMismatch,
+	#[error("oracle error: {0}")]
+	Oracle(#[from] OracleError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("grand product error: {0}")]
+	GrandProduct(#[from] GkrGpaError),
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the number of grand products does not match the flushes")]
+	NumberOfProducts,
+	#[error("channel {channel_id} is not balanced")]
+	ChannelNotBalanced { channel_id: usize },
+}
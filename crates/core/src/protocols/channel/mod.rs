@@ -0,0 +1,27 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! The channel balancing polynomial protocol.
+//!
+//! Tables communicate by flushing tuples of their columns to shared channels: every row of a
+//! flush is pushed to or pulled from the channel a number of times given by either a constant or
+//! per-row multiplicity bit columns. The protocol checks that every channel balances, ie. that
+//! the multisets of pushed and pulled tuples agree, which generalizes the multiset check to
+//! relations of different sizes with multiplicities. Channel balancing is reduced to grand
+//! product claims proven with the GKR grand product argument (see
+//! [`prove`](self::prove::prove()) for an in-depth description).
+
+#[allow(clippy::module_inception)]
+mod channel;
+mod error;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use channel::{
+	ChannelClaim, ChannelProof, ChannelProveOutput, ChannelWitness, Flush, FlushDirection,
+	FlushMultiplicity, FlushWitness,
+};
+pub use error::*;
+pub use prove::*;
+pub use verify::*;
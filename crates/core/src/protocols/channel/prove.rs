@@ -0,0 +1,144 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	channel::{
+		make_grand_product_claims, reduce_channel_claim, ChannelClaim, ChannelProof,
+		ChannelProveOutput, ChannelWitness, FlushMultiplicity,
+	},
+	error::Error,
+};
+use crate::{
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle},
+	polynomial::{Error as PolynomialError, MultilinearExtension},
+	protocols::gkr_gpa::GrandProductWitness,
+	witness::MultilinearWitnessIndex,
+};
+use binius_field::TowerField;
+use rayon::prelude::*;
+use tracing::instrument;
+
+/// Prove a channel balancing instance reduction.
+///
+/// Every flush of $k$-ary tuples $(x_0, \ldots, x_{k-1})$ to a channel is encoded by the tuple
+/// polynomial $T(x) = \gamma + \sum_j \alpha^j x_j$ and, for every selector $s_b$ of rows
+/// flushed $e_b$ times, the selected polynomial $S_b(x) = T(x) + \alpha^k s_b(x)$. The selectors
+/// are the multiplicity bits of the flush with $e_b = 2^b$, or a single all-ones selector for
+/// constant multiplicities. The rows of the flush then contribute
+///
+/// $$\prod_b \left( \frac{\prod_x S_b(x)}{\prod_x T(x)} \right)^{e_b}$$
+///
+/// to their channel, which is the product of $(\gamma + \alpha^k + t) / (\gamma + t)$ over the
+/// flushed tuples $t$ with multiplicities. A channel is balanced iff the contributions of its
+/// pushes and pulls agree, which reduces it to grand product checks of the $T$ and $S_b$
+/// polynomials. Flushes may have different numbers of rows. $\gamma$ and $\alpha$ are large field
+/// challenges sampled via Fiat-Shamir after all flushed oracles are committed.
+///
+/// The grand products in the returned proof must be observed by the challenger before the
+/// returned grand product claims are proven with [`gkr_gpa::batch_prove`].
+///
+/// ## Throws
+///
+/// * [`Error::WitnessFlushesMismatch`] if the witness does not match the flushes of the claim
+/// * [`Error::Verification`] if some channel is not balanced
+///
+/// [`gkr_gpa::batch_prove`]: crate::protocols::gkr_gpa::batch_prove
+#[instrument(skip_all, name = "channel::prove")]
+pub fn prove<'a, F, FW>(
+	oracles: &mut MultilinearOracleSet<F>,
+	witness_index: &mut MultilinearWitnessIndex<'a, FW>,
+	claim: &ChannelClaim<F>,
+	witness: ChannelWitness<'a, FW>,
+	gamma: F,
+	alpha: F,
+) -> Result<ChannelProveOutput<F, FW>, Error>
+where
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F>,
+{
+	if witness.flushes().len() != claim.flushes().len() {
+		return Err(Error::WitnessFlushesMismatch);
+	}
+
+	for (flush, flush_witness) in claim.flushes().iter().zip(witness.flushes()) {
+		let n_multiplicity_bits = match flush.multiplicity() {
+			FlushMultiplicity::Constant(_) => 0,
+			FlushMultiplicity::Bits(bits) => bits.len(),
+		};
+
+		if flush_witness.oracles().len() != flush.arity()
+			|| flush_witness.n_vars() != flush.n_vars()
+			|| flush_witness.multiplicity_bits().len() != n_multiplicity_bits
+		{
+			return Err(Error::WitnessFlushesMismatch);
+		}
+	}
+
+	let flush_oracles = reduce_channel_claim(oracles, claim, gamma, alpha)?;
+
+	let fw_gamma = FW::from(gamma);
+	let fw_alpha = FW::from(alpha);
+
+	let mut products = Vec::new();
+	let mut grand_product_witnesses = Vec::new();
+	let mut add_grand_product =
+		|oracle: &MultilinearPolyOracle<F>, values: Vec<FW>| -> Result<(), Error> {
+			let poly = MultilinearExtension::from_values(values)?.specialize_arc_dyn();
+			witness_index.set(oracle.id(), poly.clone());
+
+			let grand_product_witness = GrandProductWitness::new(poly)?;
+			products.push(F::from(grand_product_witness.grand_product_evaluation()));
+			grand_product_witnesses.push(grand_product_witness);
+			Ok(())
+		};
+
+	for ((flush, flush_witness), flush_oracles) in claim
+		.flushes()
+		.iter()
+		.zip(witness.flushes())
+		.zip(flush_oracles.iter())
+	{
+		// Evaluate the tuple encodings by Horner's method
+		let tuple_values = (0..1 << flush.n_vars())
+			.into_par_iter()
+			.map(|i| {
+				flush_witness
+					.oracles()
+					.iter()
+					.rev()
+					.try_fold(FW::ZERO, |acc, oracle| {
+						Ok::<_, PolynomialError>(acc * fw_alpha + oracle.evaluate_on_hypercube(i)?)
+					})
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let tuple = tuple_values.par_iter().map(|&t| fw_gamma + t).collect();
+		add_grand_product(&flush_oracles.tuple_oracle, tuple)?;
+
+		let selector_coeff = fw_alpha.pow([flush.arity() as u64]);
+		for (i, (selected_oracle, _)) in flush_oracles.selected_oracles.iter().enumerate() {
+			let selected = match flush_witness.multiplicity_bits().get(i) {
+				Some(bit) => tuple_values
+					.par_iter()
+					.enumerate()
+					.map(|(j, &t)| -> Result<_, PolynomialError> {
+						Ok(fw_gamma + t + bit.evaluate_on_hypercube_and_scale(j, selector_coeff)?)
+					})
+					.collect::<Result<Vec<_>, _>>()?,
+				None => tuple_values
+					.par_iter()
+					.map(|&t| fw_gamma + selector_coeff + t)
+					.collect(),
+			};
+			add_grand_product(selected_oracle, selected)?;
+		}
+	}
+
+	let proof = ChannelProof { products };
+	let grand_product_claims = make_grand_product_claims(claim, flush_oracles, &proof)?;
+
+	Ok(ChannelProveOutput {
+		proof,
+		grand_product_claims,
+		grand_product_witnesses,
+	})
+}
@@ -0,0 +1,253 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	prove, verify, ChannelClaim, ChannelProveOutput, ChannelWitness, Error, Flush, FlushDirection,
+	FlushMultiplicity, FlushWitness, VerificationError,
+};
+use crate::{
+	challenger::{CanObserve, HashChallenger},
+	oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet, MultilinearPolyOracle},
+	polynomial::{IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery},
+	protocols::gkr_gpa::{batch_prove, batch_verify, GrandProductBatchProveOutput},
+	witness::{MultilinearWitness, MultilinearWitnessIndex},
+};
+use assert_matches::assert_matches;
+use binius_field::{
+	BinaryField128b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField, Field,
+	TowerField,
+};
+use binius_hash::GroestlHasher;
+use rand::{rngs::StdRng, SeedableRng};
+use std::iter::repeat_with;
+
+type F = BinaryField128b;
+type FS = BinaryField32b;
+
+fn committed_oracles(
+	oracles: &mut MultilinearOracleSet<F>,
+	n_vars: usize,
+	n_polys: usize,
+	tower_level: usize,
+) -> Vec<MultilinearPolyOracle<F>> {
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys,
+		tower_level,
+	});
+	(0..n_polys)
+		.map(|index| oracles.committed_oracle(CommittedId { batch_id, index }))
+		.collect()
+}
+
+fn witness<P: Field>(values: Vec<P>) -> MultilinearWitness<'static, F>
+where
+	F: ExtensionField<P>,
+{
+	MultilinearExtension::from_values(values)
+		.unwrap()
+		.specialize_arc_dyn()
+}
+
+/// A memory table of 4 rows read by an 8-row CPU table on channel 0, with per-row read counts,
+/// and a table of 2 rows pushed twice to channel 1 and pulled by two flushes of another table.
+struct Instance {
+	oracles: MultilinearOracleSet<F>,
+	claim: ChannelClaim<F>,
+	memory: Vec<Vec<FS>>,
+	counts: Vec<usize>,
+	cpu: Vec<Vec<FS>>,
+	doubled: Vec<FS>,
+}
+
+impl Instance {
+	fn new(rng: &mut StdRng) -> Self {
+		let memory = (0..2)
+			.map(|_| {
+				repeat_with(|| <FS as Field>::random(&mut *rng))
+					.take(4)
+					.collect()
+			})
+			.collect::<Vec<Vec<_>>>();
+		let counts = vec![3, 0, 1, 4];
+		let (cpu_addresses, cpu_values): (Vec<_>, Vec<_>) = counts
+			.iter()
+			.enumerate()
+			.flat_map(|(row, &count)| vec![(memory[0][row], memory[1][row]); count])
+			.unzip();
+		let cpu = vec![cpu_addresses, cpu_values];
+		let doubled = repeat_with(|| <FS as Field>::random(&mut *rng))
+			.take(2)
+			.collect();
+
+		let mut oracles = MultilinearOracleSet::new();
+		let memory_oracles = committed_oracles(&mut oracles, 2, 2, FS::TOWER_LEVEL);
+		let count_bits = committed_oracles(&mut oracles, 2, 2, 0);
+		let cpu_oracles = committed_oracles(&mut oracles, 3, 2, FS::TOWER_LEVEL);
+		let doubled_oracles = committed_oracles(&mut oracles, 1, 1, FS::TOWER_LEVEL);
+
+		let flushes = vec![
+			Flush::new(
+				0,
+				FlushDirection::Push,
+				memory_oracles,
+				FlushMultiplicity::Bits(count_bits),
+			)
+			.unwrap(),
+			Flush::new(0, FlushDirection::Pull, cpu_oracles, FlushMultiplicity::Constant(1))
+				.unwrap(),
+			Flush::new(
+				1,
+				FlushDirection::Push,
+				doubled_oracles.clone(),
+				FlushMultiplicity::Constant(2),
+			)
+			.unwrap(),
+			Flush::new(
+				1,
+				FlushDirection::Pull,
+				doubled_oracles.clone(),
+				FlushMultiplicity::Constant(1),
+			)
+			.unwrap(),
+			Flush::new(1, FlushDirection::Pull, doubled_oracles, FlushMultiplicity::Constant(1))
+				.unwrap(),
+		];
+		let claim = ChannelClaim::new(flushes).unwrap();
+
+		Self {
+			oracles,
+			claim,
+			memory,
+			counts,
+			cpu,
+			doubled,
+		}
+	}
+
+	fn witness(&self) -> ChannelWitness<'static, F> {
+		let count_bits = (0..2)
+			.map(|bit| {
+				let values = self
+					.counts
+					.iter()
+					.map(|&count| BinaryField1b::from((count >> bit) as u8 & 1))
+					.collect();
+				witness(values)
+			})
+			.collect();
+		let columns = |columns: &[Vec<FS>]| columns.iter().cloned().map(witness).collect();
+		let doubled = || vec![witness(self.doubled.clone())];
+
+		ChannelWitness::new(vec![
+			FlushWitness::new(columns(&self.memory), count_bits).unwrap(),
+			FlushWitness::new(columns(&self.cpu), vec![]).unwrap(),
+			FlushWitness::new(doubled(), vec![]).unwrap(),
+			FlushWitness::new(doubled(), vec![]).unwrap(),
+			FlushWitness::new(doubled(), vec![]).unwrap(),
+		])
+	}
+}
+
+#[test]
+fn test_prove_verify_interaction() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let instance = Instance::new(&mut rng);
+	let witness = instance.witness();
+	let Instance {
+		mut oracles, claim, ..
+	} = instance;
+
+	let gamma = <F as Field>::random(&mut rng);
+	let alpha = <F as Field>::random(&mut rng);
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let mut prover_oracles = oracles.clone();
+	let ChannelProveOutput {
+		proof,
+		grand_product_claims,
+		grand_product_witnesses,
+	} = prove(&mut prover_oracles, &mut witness_index, &claim, witness, gamma, alpha).unwrap();
+	// One tuple and two selected grand products for the memory flush, two for the others
+	assert_eq!(grand_product_claims.len(), 3 + 2 * 4);
+
+	let verifier_claims = verify(&mut oracles, &claim, gamma, alpha, &proof).unwrap();
+	assert_eq!(verifier_claims.len(), grand_product_claims.len());
+	for (prover_claim, verifier_claim) in grand_product_claims.iter().zip(verifier_claims.iter()) {
+		assert_eq!(prover_claim.poly, verifier_claim.poly);
+		assert_eq!(prover_claim.product, verifier_claim.product);
+	}
+
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	prover_challenger.observe_slice(&proof.products);
+	let verifier_challenger = prover_challenger.clone();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let GrandProductBatchProveOutput {
+		evalcheck_claims,
+		proof: gpa_proof,
+	} = batch_prove::<_, _, BinaryField8b, _>(
+		grand_product_witnesses,
+		grand_product_claims,
+		domain_factory,
+		|_| 1,
+		prover_challenger,
+	)
+	.unwrap();
+
+	let verified_evalcheck_claims =
+		batch_verify(verifier_claims, gpa_proof, verifier_challenger).unwrap();
+	for (prover_claim, verifier_claim) in evalcheck_claims.iter().zip(&verified_evalcheck_claims) {
+		assert_eq!(prover_claim.eval_point, verifier_claim.eval_point);
+		assert_eq!(prover_claim.eval, verifier_claim.eval);
+
+		let query = MultilinearQuery::with_full_query(&verifier_claim.eval_point).unwrap();
+		let witness = witness_index.get(verifier_claim.poly.id()).unwrap();
+		assert_eq!(witness.evaluate(&query).unwrap(), verifier_claim.eval);
+	}
+}
+
+#[test]
+fn test_prove_rejects_unbalanced_channel() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut instance = Instance::new(&mut rng);
+	// Read the first memory row once less than the CPU table does
+	instance.counts[0] -= 1;
+	let witness = instance.witness();
+
+	let gamma = <F as Field>::random(&mut rng);
+	let alpha = <F as Field>::random(&mut rng);
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	assert_matches!(
+		prove(&mut instance.oracles, &mut witness_index, &instance.claim, witness, gamma, alpha),
+		Err(Error::Verification(VerificationError::ChannelNotBalanced { channel_id: 0 }))
+	);
+}
+
+#[test]
+fn test_invalid_flushes() {
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let columns = committed_oracles(&mut oracles, 2, 2, FS::TOWER_LEVEL);
+
+	assert_matches!(
+		Flush::new(
+			0,
+			FlushDirection::Push,
+			columns.clone(),
+			FlushMultiplicity::Bits(columns[..1].to_vec()),
+		),
+		Err(Error::MultiplicityNotBinary)
+	);
+	assert_matches!(
+		Flush::new(0, FlushDirection::Push, vec![], FlushMultiplicity::Constant(1)),
+		Err(Error::EmptyFlush)
+	);
+
+	let flushes = vec![
+		Flush::new(0, FlushDirection::Push, columns.clone(), FlushMultiplicity::Constant(1))
+			.unwrap(),
+		Flush::new(0, FlushDirection::Pull, columns[..1].to_vec(), FlushMultiplicity::Constant(1))
+			.unwrap(),
+	];
+	assert_matches!(ChannelClaim::new(flushes), Err(Error::ChannelArityMismatch { channel_id: 0 }));
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	channel::{make_grand_product_claims, reduce_channel_claim, ChannelClaim, ChannelProof},
+	error::Error,
+};
+use crate::{oracle::MultilinearOracleSet, protocols::gkr_gpa::GrandProductClaim};
+use binius_field::TowerField;
+
+/// Verify a channel balancing instance reduction.
+///
+/// The grand products in `proof` must be observed by the challenger before the returned grand
+/// product claims are verified with [`gkr_gpa::batch_verify`].
+///
+/// ## Throws
+///
+/// * [`Error::Verification`] if the grand products do not balance some channel
+///
+/// [`gkr_gpa::batch_verify`]: crate::protocols::gkr_gpa::batch_verify
+pub fn verify<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	claim: &ChannelClaim<F>,
+	gamma: F,
+	alpha: F,
+	proof: &ChannelProof<F>,
+) -> Result<Vec<GrandProductClaim<F>>, Error> {
+	let flush_oracles = reduce_channel_claim(oracles, claim, gamma, alpha)?;
+	make_grand_product_claims(claim, flush_oracles, proof)
+}
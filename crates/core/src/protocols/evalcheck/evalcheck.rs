@@ -50,6 +50,10 @@ pub enum EvalcheckProof<F: Field> {
 		subproof1: Box<EvalcheckProof<F>>,
 		subproof2: Box<EvalcheckProof<F>>,
 	},
+	ZeroPadded {
+		eval: F,
+		subproof: Box<EvalcheckProof<F>>,
+	},
 	Composite {
 		subproofs: Vec<(F, EvalcheckProof<F>)>,
 	},
@@ -8,7 +8,8 @@ use super::{
 	},
 	subclaims::{
 		packed_sumcheck_meta, packed_sumcheck_witness, projected_bivariate_claim,
-		shifted_sumcheck_meta, shifted_sumcheck_witness, BivariateSumcheck, MemoizedQueries,
+		shifted_2d_sumcheck_meta, shifted_2d_sumcheck_witness, shifted_sumcheck_meta,
+		shifted_sumcheck_witness, BivariateSumcheck, MemoizedQueries,
 	},
 };
 use crate::{
@@ -222,7 +223,8 @@ where
 
 			Shifted(_id, shifted) => {
 				let meta = shifted_sumcheck_meta(self.oracles, &shifted, eval_point.as_slice())?;
-				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, eval)?;
+				let shift_eval = eval - shifted.fill_eval(&eval_point)?;
+				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, shift_eval)?;
 				let sumcheck_witness = shifted_sumcheck_witness(
 					self.witness_index,
 					&mut self.memoized_queries,
@@ -235,6 +237,21 @@ where
 				EvalcheckProof::Shifted
 			}
 
+			Shifted2D(_id, shifted) => {
+				let meta = shifted_2d_sumcheck_meta(self.oracles, &shifted, eval_point.as_slice())?;
+				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, eval)?;
+				let sumcheck_witness = shifted_2d_sumcheck_witness(
+					self.witness_index,
+					&mut self.memoized_queries,
+					meta,
+					&shifted,
+					&wf_eval_point,
+				)?;
+
+				self.new_sumchecks.push((sumcheck_claim, sumcheck_witness));
+				EvalcheckProof::Shifted
+			}
+
 			Packed(_id, packed) => {
 				let meta = packed_sumcheck_meta(self.oracles, &packed, eval_point.as_slice())?;
 				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, eval)?;
@@ -249,6 +266,22 @@ where
 				EvalcheckProof::Packed
 			}
 
+			ZeroPadded(_id, padded) => {
+				let inner = padded.inner();
+				let inner_n_vars = inner.n_vars();
+				let (inner_eval, subproof) = self.eval_and_proof(
+					*inner.clone(),
+					&eval_point[..inner_n_vars],
+					&wf_eval_point[..inner_n_vars],
+					is_random_point,
+				)?;
+
+				EvalcheckProof::ZeroPadded {
+					eval: inner_eval,
+					subproof: Box::new(subproof),
+				}
+			}
+
 			Projected(_id, projected) => {
 				let (inner, values) = (projected.inner(), projected.values());
 				let new_eval_point = match projected.projection_variant() {
@@ -274,6 +307,28 @@ where
 				self.prove_multilinear(subclaim)?
 			}
 
+			PermutedVars(_id, permuted) => {
+				let subclaim = EvalcheckMultilinearClaim {
+					poly: *permuted.inner().clone(),
+					eval_point: permuted.inner_eval_point(&eval_point),
+					eval,
+					is_random_point,
+				};
+
+				self.prove_multilinear(subclaim)?
+			}
+
+			Embedded(_id, embedded) => {
+				let subclaim = EvalcheckMultilinearClaim {
+					poly: *embedded.inner().clone(),
+					eval_point,
+					eval,
+					is_random_point,
+				};
+
+				self.prove_multilinear(subclaim)?
+			}
+
 			LinearCombination(_id, lin_com) => {
 				self.prove_composite(lin_com.polys().cloned(), eval_point, is_random_point)?
 			}
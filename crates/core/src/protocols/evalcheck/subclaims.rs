@@ -1,7 +1,7 @@
 // Copyright 2024 Ulvetanna Inc.
 
 //! This module contains helpers to create bivariate sumcheck instances originating from:
-//!  * products with shift indicators (shifted virtual polynomials)
+//!  * products with shift indicators (shifted and 2D shifted virtual polynomials)
 //!  * products with tower basis (packed virtual polynomials)
 //!  * products with equality indicator ([`CommittedEvalClaim`])
 //!
@@ -17,12 +17,13 @@ use super::{
 use crate::{
 	oracle::{
 		CompositePolyOracle, Error as OracleError, MultilinearOracleSet, OracleId, Packed,
-		ProjectionVariant, Shifted,
+		ProjectionVariant, Shifted, Shifted2D,
 	},
 	polynomial::{
 		composition::BivariateProduct,
 		transparent::{
-			eq_ind::EqIndPartialEval, shift_ind::ShiftIndPartialEval, tower_basis::TowerBasis,
+			eq_ind::EqIndPartialEval, shift_ind::ShiftIndPartialEval,
+			shift_ind_2d::Shift2DIndPartialEval, tower_basis::TowerBasis,
 		},
 		MultilinearQuery, MultivariatePoly,
 	},
@@ -93,6 +94,70 @@ pub fn shifted_sumcheck_witness<'a, F: Field, PW: PackedFieldIndexable>(
 	)
 }
 
+/// Create oracles for the bivariate product of an inner oracle with 2D shift indicator.
+///
+/// Projects to first `block_size()` vars.
+/// Returns metadata object with oracle identifiers. Pass this object to:
+///  - [`projected_bivariate_claim`] to obtain sumcheck claim
+///  - [`shifted_2d_sumcheck_witness`] to obtain sumcheck witness
+pub fn shifted_2d_sumcheck_meta<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	shifted: &Shifted2D<F>,
+	eval_point: &[F],
+) -> Result<ProjectedBivariateMeta, Error> {
+	projected_bivariate_meta(
+		oracles,
+		shifted.inner().id(),
+		shifted.block_size(),
+		eval_point,
+		|projected_eval_point| {
+			Ok(Shift2DIndPartialEval::new(
+				shifted.col_bits(),
+				shifted.row_bits(),
+				shifted.col_offset(),
+				shifted.row_offset(),
+				shifted.shift_variant(),
+				projected_eval_point.to_vec(),
+			)?)
+		},
+	)
+}
+
+/// Takes in metadata object and creates a witness for a bivariate claim on 2D shift indicator.
+///
+/// `wf_eval_point` should be isomorphic to `eval_point` in `shifted_2d_sumcheck_meta`.
+pub fn shifted_2d_sumcheck_witness<'a, F: Field, PW: PackedFieldIndexable>(
+	witness_index: &mut MultilinearWitnessIndex<'a, PW>,
+	memoized_queries: &mut MemoizedQueries<PW>,
+	meta: ProjectedBivariateMeta,
+	shifted: &Shifted2D<F>,
+	wf_eval_point: &[PW::Scalar],
+) -> Result<BivariateSumcheckWitness<'a, PW>, Error>
+where
+	PW::Scalar: TowerField,
+{
+	projected_bivariate_witness(
+		witness_index,
+		memoized_queries,
+		meta,
+		wf_eval_point,
+		|projected_eval_point| {
+			let shift_ind = Shift2DIndPartialEval::new(
+				shifted.col_bits(),
+				shifted.row_bits(),
+				shifted.col_offset(),
+				shifted.row_offset(),
+				shifted.shift_variant(),
+				projected_eval_point.to_vec(),
+			)?;
+
+			Ok(shift_ind
+				.multilinear_extension::<PW>()?
+				.specialize_arc_dyn())
+		},
+	)
+}
+
 /// Create oracles for the bivariate product of an inner oracle with the tower basis.
 ///
 /// Projects to first `log_degree()` vars.
@@ -121,7 +186,7 @@ pub fn packed_sumcheck_meta<F: TowerField>(
 /// Takes in metadata object and creates a witness for a bivariate claim on tower basis.
 ///
 /// `wf_eval_point` should be isomorphic to `eval_point` in `shifted_sumcheck_meta`.
-pub fn packed_sumcheck_witness<'a, F: Field, PW: PackedField>(
+pub fn packed_sumcheck_witness<'a, F: TowerField, PW: PackedField>(
 	witness_index: &mut MultilinearWitnessIndex<'a, PW>,
 	memoized_queries: &mut MemoizedQueries<PW>,
 	meta: ProjectedBivariateMeta,
@@ -3,7 +3,7 @@
 use crate::{
 	oracle::{
 		CommittedBatchSpec, CommittedId, CompositePolyOracle, MultilinearOracleSet,
-		MultilinearPolyOracle, ProjectionVariant, ShiftVariant,
+		MultilinearPolyOracle, OracleId, ProjectionVariant, ShiftVariant,
 	},
 	polynomial::{
 		composition::BivariateProduct, extrapolate_line, transparent::select_row::SelectRow,
@@ -200,6 +200,10 @@ fn shift_one<P: PackedField>(evals: &mut [P], bits: usize, variant: ShiftVariant
 				let last = get_packed_slice(evals, range.end - 1);
 				(Either::Left(range), last)
 			}
+			ShiftVariant::CircularRight => {
+				let last = get_packed_slice(evals, range.start);
+				(Either::Right(range.rev()), last)
+			}
 		};
 
 		for i in range {
@@ -644,6 +648,334 @@ fn test_evalcheck_merged() {
 	verifier_state.verify(claim, proof).unwrap();
 }
 
+/// Proves and verifies an evalcheck claim on a shifted oracle, and checks the reduced sumcheck
+/// claim by summing over the hypercube.
+fn check_shifted_sumcheck_claim(
+	mut oracles: MultilinearOracleSet<EF>,
+	inner_id: OracleId,
+	shifted_id: OracleId,
+	inner_values: Vec<EF>,
+	shifted_values: Vec<EF>,
+) {
+	let n_vars = oracles.n_vars(shifted_id);
+	let inner_witness = MultilinearExtension::from_values(inner_values).unwrap();
+	let shifted_witness = MultilinearExtension::from_values(shifted_values).unwrap();
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	let query = MultilinearQuery::<EF>::with_full_query(&eval_point).unwrap();
+	let claim = EvalcheckClaim {
+		poly: oracles.oracle(shifted_id).into_composite(),
+		eval_point,
+		eval: shifted_witness.evaluate(&query).unwrap(),
+		is_random_point: true,
+	};
+
+	let mut witness_index = MultilinearWitnessIndex::<EF>::new();
+	witness_index.set(inner_id, inner_witness.specialize_arc_dyn());
+	witness_index.set(shifted_id, shifted_witness.specialize_arc_dyn());
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	let proof = prover_state.prove(claim.clone()).unwrap();
+	let sumcheck_claims = prover_state
+		.take_new_sumchecks()
+		.into_iter()
+		.map(|(sumcheck_claim, _)| sumcheck_claim)
+		.collect::<Vec<_>>();
+	assert_eq!(sumcheck_claims.len(), 1);
+
+	let SumcheckClaim { poly, sum } = &sumcheck_claims[0];
+	let multilins = poly
+		.inner_polys()
+		.iter()
+		.map(|oracle| witness_index.get(oracle.id()).unwrap().clone())
+		.collect::<Vec<_>>();
+	let actual_sum = (0..1 << poly.n_vars())
+		.map(|i| {
+			multilins
+				.iter()
+				.map(|multilin| multilin.evaluate_on_hypercube(i).unwrap())
+				.product::<EF>()
+		})
+		.sum::<EF>();
+	assert_eq!(actual_sum, *sum);
+
+	let mut verifier_state = EvalcheckVerifier::new(&mut oracles);
+	verifier_state.verify(claim, proof).unwrap();
+	assert_eq!(verifier_state.new_sumcheck_claims().len(), 1);
+	assert_eq!(verifier_state.new_sumcheck_claims()[0].sum, *sum);
+}
+
+#[test]
+fn test_evalcheck_shifted_with_fill() {
+	let n_vars = 8;
+	let (offset, block_bits) = (3, 4);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let fill = <EF as Field>::random(&mut rng);
+	let inner_values = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+
+	for variant in [ShiftVariant::LogicalLeft, ShiftVariant::LogicalRight] {
+		let mut oracles = MultilinearOracleSet::<EF>::new();
+		let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 1,
+			tower_level: EF::TOWER_LEVEL,
+		});
+		let inner_id = oracles.committed_oracle_id(CommittedId { batch_id, index: 0 });
+		let shifted_id = oracles
+			.add_shifted_with_fill(inner_id, offset, block_bits, variant, fill)
+			.unwrap();
+
+		let block_size = 1 << block_bits;
+		let shifted_values = (0..1 << n_vars)
+			.map(|i| {
+				let x = i % block_size;
+				match variant {
+					ShiftVariant::LogicalLeft if x + offset < block_size => {
+						inner_values[i + offset]
+					}
+					ShiftVariant::LogicalRight if x >= offset => inner_values[i - offset],
+					_ => fill,
+				}
+			})
+			.collect();
+
+		check_shifted_sumcheck_claim(
+			oracles,
+			inner_id,
+			shifted_id,
+			inner_values.clone(),
+			shifted_values,
+		);
+	}
+}
+
+#[test]
+fn test_evalcheck_shifted_2d() {
+	let n_vars = 8;
+	let (col_bits, row_bits) = (2, 3);
+	let (col_offset, row_offset) = (1, 2);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let inner_values = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+
+	for variant in [ShiftVariant::CircularRight, ShiftVariant::LogicalLeft] {
+		let mut oracles = MultilinearOracleSet::<EF>::new();
+		let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 1,
+			tower_level: EF::TOWER_LEVEL,
+		});
+		let inner_id = oracles.committed_oracle_id(CommittedId { batch_id, index: 0 });
+		let shifted_id = oracles
+			.add_shifted_2d(inner_id, col_bits, row_bits, col_offset, row_offset, variant)
+			.unwrap();
+
+		let (n_cols, n_rows) = (1 << col_bits, 1 << row_bits);
+		let shifted_values = (0..1 << n_vars)
+			.map(|i| {
+				let (col, row) = (i % n_cols, (i / n_cols) % n_rows);
+				let matrix_start = i - i % (n_cols * n_rows);
+				let source = match variant {
+					ShiftVariant::CircularRight => Some((
+						(col + n_cols - col_offset) % n_cols,
+						(row + n_rows - row_offset) % n_rows,
+					)),
+					_ => (col + col_offset < n_cols && row + row_offset < n_rows)
+						.then_some((col + col_offset, row + row_offset)),
+				};
+				source
+					.map_or(EF::ZERO, |(col, row)| inner_values[matrix_start + row * n_cols + col])
+			})
+			.collect();
+
+		check_shifted_sumcheck_claim(
+			oracles,
+			inner_id,
+			shifted_id,
+			inner_values.clone(),
+			shifted_values,
+		);
+	}
+}
+
+#[test]
+/// Constructs a small ZeroPadded oracle, proves and verifies it.
+fn test_evalcheck_zero_padded() {
+	let n_vars = 7;
+	let n_pad_vars = 2;
+	let row_id = 9;
+	let block_index = 2;
+
+	let mut oracles = MultilinearOracleSet::new();
+
+	let select_row = SelectRow::new(n_vars, row_id).unwrap();
+	let select_row_subwitness = select_row
+		.multilinear_extension::<PackedBinaryField128x1b>()
+		.unwrap();
+
+	// Zero padding a row selector yields a row selector over the larger hypercube
+	let padded_row_id = (block_index << n_vars) | row_id;
+	let zero_padded_witness = SelectRow::new(n_vars + n_pad_vars, padded_row_id)
+		.unwrap()
+		.multilinear_extension::<PackedBinaryField128x1b>()
+		.unwrap();
+
+	let select_row_oracle_id = oracles.add_transparent(select_row.clone()).unwrap();
+	let zero_padded_id = oracles
+		.add_zero_padded(select_row_oracle_id, n_vars + n_pad_vars, block_index)
+		.unwrap();
+	let zero_padded = oracles.oracle(zero_padded_id);
+
+	let mut witness_index = MultilinearWitnessIndex::<EF>::new();
+	witness_index.set(select_row_oracle_id, select_row_subwitness.specialize_arc_dyn());
+	witness_index.set(zero_padded_id, zero_padded_witness.specialize_arc_dyn());
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(n_vars + n_pad_vars)
+		.collect::<Vec<_>>();
+
+	let eval = SelectRow::new(n_vars + n_pad_vars, padded_row_id)
+		.unwrap()
+		.evaluate(&eval_point)
+		.unwrap();
+	let claim = EvalcheckClaim {
+		poly: zero_padded.into_composite(),
+		eval_point,
+		eval,
+		is_random_point: true,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	let proof = prover_state.prove(claim.clone()).unwrap();
+
+	if let EvalcheckProof::Composite { ref subproofs } = proof {
+		assert_eq!(subproofs.len(), 1);
+		assert_matches!(subproofs[0].1, EvalcheckProof::ZeroPadded { .. });
+	} else {
+		panic!("Proof should be Composite.");
+	}
+
+	let mut verifier_state = EvalcheckVerifier::new(&mut oracles);
+	verifier_state.verify(claim, proof).unwrap();
+}
+
+#[test]
+/// Constructs a small PermutedVars oracle, proves and verifies it.
+fn test_evalcheck_permuted_vars() {
+	let n_vars = 7;
+	let row_id = 9;
+
+	let mut oracles = MultilinearOracleSet::new();
+
+	let select_row = SelectRow::new(n_vars, row_id).unwrap();
+	let select_row_subwitness = select_row
+		.multilinear_extension::<PackedBinaryField128x1b>()
+		.unwrap();
+
+	// Reversing the variables of a row selector selects the bit-reversed row
+	let reversed_row_id = row_id.reverse_bits() >> (usize::BITS as usize - n_vars);
+	let reversed_select_row = SelectRow::new(n_vars, reversed_row_id).unwrap();
+	let permuted_witness = reversed_select_row
+		.multilinear_extension::<PackedBinaryField128x1b>()
+		.unwrap();
+
+	let select_row_oracle_id = oracles.add_transparent(select_row.clone()).unwrap();
+	let permuted_id = oracles
+		.add_permuted_vars(select_row_oracle_id, (0..n_vars).rev().collect())
+		.unwrap();
+	let permuted = oracles.oracle(permuted_id);
+
+	let mut witness_index = MultilinearWitnessIndex::<EF>::new();
+	witness_index.set(select_row_oracle_id, select_row_subwitness.specialize_arc_dyn());
+	witness_index.set(permuted_id, permuted_witness.specialize_arc_dyn());
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+
+	let eval = reversed_select_row.evaluate(&eval_point).unwrap();
+	let claim = EvalcheckClaim {
+		poly: permuted.into_composite(),
+		eval_point,
+		eval,
+		is_random_point: true,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	let proof = prover_state.prove(claim.clone()).unwrap();
+
+	// The claim reduces to a claim on the inner oracle without an extra proof
+	if let EvalcheckProof::Composite { ref subproofs } = proof {
+		assert_eq!(subproofs.len(), 1);
+		assert_matches!(subproofs[0].1, EvalcheckProof::Transparent);
+	} else {
+		panic!("Proof should be Composite.");
+	}
+
+	let mut verifier_state = EvalcheckVerifier::new(&mut oracles);
+	verifier_state.verify(claim, proof).unwrap();
+}
+
+#[test]
+/// Constructs a small Embedded oracle, proves and verifies it.
+fn test_evalcheck_embedded() {
+	let n_vars = 7;
+	let row_id = 9;
+
+	let mut oracles = MultilinearOracleSet::new();
+
+	let select_row = SelectRow::new(n_vars, row_id).unwrap();
+	let select_row_subwitness = select_row
+		.multilinear_extension::<PackedBinaryField128x1b>()
+		.unwrap();
+
+	let select_row_oracle_id = oracles.add_transparent(select_row.clone()).unwrap();
+	let embedded_id = oracles.add_embedded(select_row_oracle_id, 3).unwrap();
+	assert_eq!(oracles.tower_level(embedded_id), 3);
+	let embedded = oracles.oracle(embedded_id);
+
+	let mut witness_index = MultilinearWitnessIndex::<EF>::new();
+	// The embedded oracle has the same values as the inner oracle
+	witness_index.set(select_row_oracle_id, select_row_subwitness.clone().specialize_arc_dyn());
+	witness_index.set(embedded_id, select_row_subwitness.specialize_arc_dyn());
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let eval_point = repeat_with(|| <EF as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+
+	let eval = select_row.evaluate(&eval_point).unwrap();
+	let claim = EvalcheckClaim {
+		poly: embedded.into_composite(),
+		eval_point,
+		eval,
+		is_random_point: true,
+	};
+
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index);
+	let proof = prover_state.prove(claim.clone()).unwrap();
+
+	if let EvalcheckProof::Composite { ref subproofs } = proof {
+		assert_eq!(subproofs.len(), 1);
+		assert_matches!(subproofs[0].1, EvalcheckProof::Transparent);
+	} else {
+		panic!("Proof should be Composite.");
+	}
+
+	let mut verifier_state = EvalcheckVerifier::new(&mut oracles);
+	verifier_state.verify(claim, proof).unwrap();
+}
+
 #[test]
 /// Constructs a small Interleaved oracle, proves and verifies it.
 fn test_evalcheck_interleaved() {
@@ -6,11 +6,14 @@ use super::{
 		BatchCommittedEvalClaims, CommittedEvalClaim, EvalcheckClaim, EvalcheckMultilinearClaim,
 		EvalcheckProof,
 	},
-	subclaims::{packed_sumcheck_meta, projected_bivariate_claim, shifted_sumcheck_meta},
+	subclaims::{
+		packed_sumcheck_meta, projected_bivariate_claim, shifted_2d_sumcheck_meta,
+		shifted_sumcheck_meta,
+	},
 };
 use crate::{
 	oracle::{MultilinearOracleSet, MultilinearPolyOracle, ProjectionVariant},
-	polynomial::extrapolate_line,
+	polynomial::{extrapolate_line, transparent::select_row::SelectRow, MultivariatePoly},
 	protocols::sumcheck::SumcheckClaim,
 };
 use binius_field::{util::inner_product_unchecked, TowerField};
@@ -250,6 +253,29 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 				self.verify_multilinear(new_claim, evalcheck_proof)?;
 			}
 
+			MultilinearPolyOracle::PermutedVars(_id, permuted) => {
+				let new_claim = EvalcheckMultilinearClaim {
+					poly: *permuted.inner().clone(),
+					eval_point: permuted.inner_eval_point(&eval_point),
+					eval,
+					is_random_point,
+				};
+
+				self.verify_multilinear(new_claim, evalcheck_proof)?;
+			}
+
+			MultilinearPolyOracle::Embedded(_id, embedded) => {
+				// Embedding into a tower extension does not change the multilinear extension
+				let new_claim = EvalcheckMultilinearClaim {
+					poly: *embedded.inner().clone(),
+					eval_point,
+					eval,
+					is_random_point,
+				};
+
+				self.verify_multilinear(new_claim, evalcheck_proof)?;
+			}
+
 			MultilinearPolyOracle::Shifted(_id, shifted) => {
 				match evalcheck_proof {
 					EvalcheckProof::Shifted => {}
@@ -257,6 +283,18 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 				};
 
 				let meta = shifted_sumcheck_meta(self.oracles, &shifted, eval_point.as_slice())?;
+				let shift_eval = eval - shifted.fill_eval(&eval_point)?;
+				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, shift_eval)?;
+				self.new_sumcheck_claims.push(sumcheck_claim);
+			}
+
+			MultilinearPolyOracle::Shifted2D(_id, shifted) => {
+				match evalcheck_proof {
+					EvalcheckProof::Shifted => {}
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				let meta = shifted_2d_sumcheck_meta(self.oracles, &shifted, eval_point.as_slice())?;
 				let sumcheck_claim = projected_bivariate_claim(self.oracles, meta, eval)?;
 				self.new_sumcheck_claims.push(sumcheck_claim);
 			}
@@ -272,6 +310,30 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 				self.new_sumcheck_claims.push(sumcheck_claim);
 			}
 
+			MultilinearPolyOracle::ZeroPadded(id, padded) => {
+				let (inner_eval, subproof) = match evalcheck_proof {
+					EvalcheckProof::ZeroPadded { eval, subproof } => (eval, subproof),
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				// The zero-padded oracle is the inner oracle times the indicator of its block
+				let inner_n_vars = padded.inner().n_vars();
+				let (inner_eval_point, block_eval_point) = eval_point.split_at(inner_n_vars);
+				let block_selector = SelectRow::new(block_eval_point.len(), padded.block_index())?;
+				let actual_eval = inner_eval * block_selector.evaluate(block_eval_point)?;
+				if actual_eval != eval {
+					return Err(VerificationError::IncorrectEvaluation(id).into());
+				}
+
+				self.verify_multilinear_subclaim(
+					inner_eval,
+					*subproof,
+					*padded.inner().clone(),
+					inner_eval_point,
+					is_random_point,
+				)?;
+			}
+
 			MultilinearPolyOracle::LinearCombination(id, lin_com) => {
 				let subproofs = match evalcheck_proof {
 					EvalcheckProof::Composite { subproofs } => subproofs,
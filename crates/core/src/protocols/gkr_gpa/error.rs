@@ -0,0 +1,36 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::Error as OracleError, polynomial::Error as PolynomialError,
+	protocols::gkr_sumcheck::Error as GkrSumcheckError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("prover has mismatch between claim and witness")]
+	ProverClaimWitnessMismatch,
+	#[error("the number of witnesses must match the number of claims")]
+	MismatchedWitnessClaimLength,
+	#[error("the number of variables of the two input polynomials do not match")]
+	NumVariablesMismatch,
+	#[error("oracle error: {0}")]
+	Oracle(#[from] OracleError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("gkr sumcheck failure: {0}")]
+	GkrSumcheck(#[from] GkrSumcheckError),
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the number of layer proofs must match the number of variables of the largest claim")]
+	NumberOfLayerProofs,
+	#[error("the number of layer evaluations must match the number of claims in the layer")]
+	NumberOfLayerEvals,
+	#[error("the product of the layer evaluations does not match the layer claim")]
+	IncorrectLayerProduct,
+	#[error("the layer 0 proof must not contain a GKR sumcheck proof")]
+	NonEmptyFirstLayerSumcheck,
+}
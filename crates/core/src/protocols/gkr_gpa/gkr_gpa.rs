@@ -0,0 +1,219 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{Error, VerificationError};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	oracle::MultilinearPolyOracle,
+	polynomial::extrapolate_line,
+	protocols::{
+		evalcheck::EvalcheckClaim,
+		gkr_sumcheck::GkrSumcheckBatchProof,
+		prodcheck::{ProdcheckClaim, ProdcheckWitness},
+	},
+	witness::MultilinearWitness,
+};
+use binius_field::{Field, TowerField};
+use rayon::prelude::*;
+
+/// A claim that the product of the hypercube evaluations of a multilinear equals `product`.
+#[derive(Debug, Clone)]
+pub struct GrandProductClaim<F: Field> {
+	pub poly: MultilinearPolyOracle<F>,
+	pub product: F,
+}
+
+impl<F: Field> GrandProductClaim<F> {
+	pub fn n_vars(&self) -> usize {
+		self.poly.n_vars()
+	}
+}
+
+/// Witness for a grand product claim, holding every layer of the multiplication circuit.
+#[derive(Debug, Clone)]
+pub struct GrandProductWitness<FW: Field> {
+	/// Layer $k$ holds the $2^k$ hypercube evaluations of $V_k$, the last layer being the
+	/// evaluations of the input multilinear.
+	layers: Vec<Vec<FW>>,
+}
+
+impl<FW: Field> GrandProductWitness<FW> {
+	pub fn new(poly: MultilinearWitness<FW>) -> Result<Self, Error> {
+		let n_vars = poly.n_vars();
+		let mut input_layer = vec![FW::ZERO; 1 << n_vars];
+		poly.subcube_evals(n_vars, 0, &mut input_layer)?;
+
+		let mut layers = Vec::with_capacity(n_vars + 1);
+		layers.push(input_layer);
+		for layer_n_vars in (0..n_vars).rev() {
+			let prev_layer = layers.last().expect("layers is not empty");
+			let (zero_half, one_half) = prev_layer.split_at(1 << layer_n_vars);
+			let layer = zero_half
+				.par_iter()
+				.zip(one_half.par_iter())
+				.map(|(&zero, &one)| zero * one)
+				.collect::<Vec<_>>();
+			layers.push(layer);
+		}
+		layers.reverse();
+
+		Ok(Self { layers })
+	}
+
+	/// Split the witnesses of a product check into the witnesses of the grand products of
+	/// $T$ and $U$ respectively.
+	pub fn from_prodcheck_witness(witness: ProdcheckWitness<FW>) -> Result<[Self; 2], Error> {
+		let ProdcheckWitness {
+			t_polynomial,
+			u_polynomial,
+		} = witness;
+
+		if t_polynomial.n_vars() != u_polynomial.n_vars() {
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		Ok([Self::new(t_polynomial)?, Self::new(u_polynomial)?])
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.layers.len() - 1
+	}
+
+	/// The product of the hypercube evaluations of the input multilinear.
+	pub fn grand_product_evaluation(&self) -> FW {
+		self.layers[0][0]
+	}
+
+	/// Hypercube evaluations of the $k$-th layer, which has $k$ variables.
+	pub(super) fn layer(&self, k: usize) -> &[FW] {
+		&self.layers[k]
+	}
+}
+
+/// An evaluation claim on one layer of a grand product circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerClaim<F: Field> {
+	pub eval_point: Vec<F>,
+	pub eval: F,
+}
+
+/// The reduction of the claims on layer $k$ to claims on layer $k + 1$.
+#[derive(Debug, Clone)]
+pub struct LayerProof<F> {
+	/// The batched GKR sumcheck over the layer claims, with no rounds for layer $0$.
+	pub gkr_sumcheck_batch_proof: GkrSumcheckBatchProof<F>,
+	/// Evaluations $V_{k+1}(\rho, 0)$ of the claims in the layer.
+	pub zero_evals: Vec<F>,
+	/// Evaluations $V_{k+1}(\rho, 1)$ of the claims in the layer.
+	pub one_evals: Vec<F>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GrandProductBatchProof<F> {
+	pub batch_layer_proofs: Vec<LayerProof<F>>,
+}
+
+#[derive(Debug)]
+pub struct GrandProductBatchProveOutput<F: Field> {
+	/// Evalcheck claims on the grand product polynomials, in the order of the claims.
+	pub evalcheck_claims: Vec<EvalcheckClaim<F>>,
+	pub proof: GrandProductBatchProof<F>,
+}
+
+/// Reduce a product check claim $\prod T = \prod U$ to grand product claims on $T$ and $U$.
+///
+/// The common `product` is sent by the prover and must be observed by the challenger before
+/// proving the grand product claims. The product is expected to be non-zero, which holds with
+/// overwhelming probability for the claims produced by the multiset check.
+///
+/// ## Throws
+///
+/// * [`Error::NumVariablesMismatch`] if $T$ and $U$ have a different number of variables
+pub fn prodcheck_to_grand_product_claims<F: Field>(
+	claim: &ProdcheckClaim<F>,
+	product: F,
+) -> Result<[GrandProductClaim<F>; 2], Error> {
+	if claim.n_vars().is_none() {
+		return Err(Error::NumVariablesMismatch);
+	}
+
+	Ok([
+		GrandProductClaim {
+			poly: claim.t_oracle.clone(),
+			product,
+		},
+		GrandProductClaim {
+			poly: claim.u_oracle.clone(),
+			product,
+		},
+	])
+}
+
+/// Reduce the claims on a layer, given their evaluations at a common point, to claims on the next
+/// layer.
+///
+/// Checks that `zero_evals[i] * one_evals[i] == evals[i]`, observes the layer evaluations and
+/// samples the challenge for the new highest variable.
+pub(super) fn reduce_layer_claims<F, CH>(
+	eval_point: &[F],
+	evals: &[F],
+	zero_evals: &[F],
+	one_evals: &[F],
+	mut challenger: CH,
+) -> Result<Vec<LayerClaim<F>>, Error>
+where
+	F: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	if zero_evals.len() != evals.len() || one_evals.len() != evals.len() {
+		return Err(VerificationError::NumberOfLayerEvals.into());
+	}
+
+	let products_match = evals
+		.iter()
+		.zip(zero_evals.iter().zip(one_evals.iter()))
+		.all(|(&eval, (&zero, &one))| zero * one == eval);
+	if !products_match {
+		return Err(VerificationError::IncorrectLayerProduct.into());
+	}
+
+	challenger.observe_slice(zero_evals);
+	challenger.observe_slice(one_evals);
+	let layer_challenge = challenger.sample();
+
+	let new_eval_point = eval_point
+		.iter()
+		.copied()
+		.chain([layer_challenge])
+		.collect::<Vec<_>>();
+
+	let layer_claims = zero_evals
+		.iter()
+		.zip(one_evals.iter())
+		.map(|(&zero, &one)| LayerClaim {
+			eval_point: new_eval_point.clone(),
+			eval: extrapolate_line(zero, one, layer_challenge),
+		})
+		.collect();
+
+	Ok(layer_claims)
+}
+
+/// Convert the final layer claims on the input multilinears into evalcheck claims.
+pub(super) fn make_evalcheck_claims<F: TowerField>(
+	claims: Vec<GrandProductClaim<F>>,
+	layer_claims: Vec<LayerClaim<F>>,
+) -> Vec<EvalcheckClaim<F>> {
+	claims
+		.into_iter()
+		.zip(layer_claims)
+		.map(|(claim, layer_claim)| {
+			debug_assert_eq!(layer_claim.eval_point.len(), claim.n_vars());
+			EvalcheckClaim {
+				poly: claim.poly.into_composite(),
+				eval_point: layer_claim.eval_point,
+				eval: layer_claim.eval,
+				is_random_point: true,
+			}
+		})
+		.collect()
+}
@@ -0,0 +1,41 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! The grand product argument, proven with a layered GKR circuit of multiplication gates.
+//!
+//! The grand product of an $n$-variate multilinear $f$ is the product of its evaluations over the
+//! boolean hypercube. The prover arranges the products in a binary tree of layers, where layer $n$
+//! holds the hypercube evaluations of $f$, and every layer $k < n$ is defined by
+//! $V_k(x) = V_{k+1}(x, 0) \cdot V_{k+1}(x, 1)$ for $x \in \{0, 1\}^k$. The single value of layer
+//! $0$ is the grand product.
+//!
+//! A claim $V_k(r) = s$ on layer $k$ is reduced to a claim on layer $k + 1$ with a GKR sumcheck
+//! (see [`gkr_sumcheck`](crate::protocols::gkr_sumcheck)) over
+//! $\sum_{x \in \{0, 1\}^k} \mathsf{eq}(x, r) \cdot V_{k+1}(x, 0) \cdot V_{k+1}(x, 1) = s$, after
+//! which the prover sends $V_{k+1}(\rho, 0)$ and $V_{k+1}(\rho, 1)$ at the sumcheck challenge
+//! point $\rho$. The verifier checks that their product matches the sumcheck reduced evaluation
+//! and samples $\mu$ to obtain the claim $V_{k+1}(\rho, \mu)$. After $n$ layers, the grand product
+//! claim is reduced to an evalcheck claim on $f$, without committing to any auxiliary polynomial.
+//!
+//! Several grand product claims are batched by sharing all challenges. Claims with fewer variables
+//! drop out of the batch once their last layer is reached, so each claim's evaluation point is a
+//! prefix of the evaluation point of the largest claim.
+//!
+//! A product check claim $\prod T = \prod U$ (see [`prodcheck`](crate::protocols::prodcheck))
+//! can be proven instead as a pair of grand product claims with a common product, see
+//! [`prodcheck_to_grand_product_claims`].
+
+mod error;
+#[allow(clippy::module_inception)]
+mod gkr_gpa;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use error::*;
+pub use gkr_gpa::{
+	prodcheck_to_grand_product_claims, GrandProductBatchProof, GrandProductBatchProveOutput,
+	GrandProductClaim, GrandProductWitness, LayerClaim, LayerProof,
+};
+pub use prove::batch_prove;
+pub use verify::batch_verify;
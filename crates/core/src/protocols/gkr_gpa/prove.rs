@@ -0,0 +1,216 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	gkr_gpa::{
+		make_evalcheck_claims, reduce_layer_claims, GrandProductBatchProof,
+		GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, LayerClaim,
+		LayerProof,
+	},
+	Error,
+};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	polynomial::{
+		composition::BivariateProduct, EvaluationDomain, EvaluationDomainFactory,
+		MultilinearComposite, MultilinearExtension, MultilinearQuery,
+	},
+	protocols::gkr_sumcheck::{
+		self, GkrSumcheckBatchProof, GkrSumcheckBatchProveOutput, GkrSumcheckClaim,
+		GkrSumcheckProver, GkrSumcheckWitness,
+	},
+};
+use binius_field::{ExtensionField, Field, TowerField};
+use tracing::instrument;
+
+/// Prove a batch of grand product claims.
+///
+/// See module documentation for details.
+///
+/// ## Throws
+///
+/// * [`Error::MismatchedWitnessClaimLength`] if the number of witnesses and claims differ
+/// * [`Error::ProverClaimWitnessMismatch`] if a witness does not match its claim
+#[instrument(skip_all, name = "gkr_gpa::batch_prove")]
+pub fn batch_prove<F, FW, DomainField, CH>(
+	witnesses: impl IntoIterator<Item = GrandProductWitness<FW>>,
+	claims: impl IntoIterator<Item = GrandProductClaim<F>>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	mut challenger: CH,
+) -> Result<GrandProductBatchProveOutput<F>, Error>
+where
+	F: TowerField + From<FW>,
+	FW: Field + From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let witnesses = witnesses.into_iter().collect::<Vec<_>>();
+	let claims = claims.into_iter().collect::<Vec<_>>();
+
+	if witnesses.len() != claims.len() {
+		return Err(Error::MismatchedWitnessClaimLength);
+	}
+	let witness_claim_mismatch = witnesses.iter().zip(claims.iter()).any(|(witness, claim)| {
+		witness.n_vars() != claim.n_vars()
+			|| F::from(witness.grand_product_evaluation()) != claim.product
+	});
+	if witness_claim_mismatch {
+		return Err(Error::ProverClaimWitnessMismatch);
+	}
+
+	// Every layer reduction is a sumcheck over a product of two multilinears
+	let domain = evaluation_domain_factory.create(3)?;
+	let max_n_vars = claims.iter().map(|claim| claim.n_vars()).max().unwrap_or(0);
+
+	let mut layer_claims = claims
+		.iter()
+		.map(|claim| LayerClaim {
+			eval_point: Vec::new(),
+			eval: claim.product,
+		})
+		.collect::<Vec<_>>();
+	let mut batch_layer_proofs = Vec::with_capacity(max_n_vars);
+
+	for layer_no in 0..max_n_vars {
+		// Claims that have not yet reached their input layer, all sharing the same evaluation point
+		let active_indices = (0..claims.len())
+			.filter(|&i| claims[i].n_vars() > layer_no)
+			.collect::<Vec<_>>();
+		let active_witnesses = active_indices
+			.iter()
+			.map(|&i| &witnesses[i])
+			.collect::<Vec<_>>();
+		let active_claims = active_indices
+			.iter()
+			.map(|&i| layer_claims[i].clone())
+			.collect::<Vec<_>>();
+
+		let (gkr_sumcheck_batch_proof, sumcheck_claims) = prove_layer_gkr_sumcheck(
+			layer_no,
+			&active_witnesses,
+			active_claims,
+			&domain,
+			switchover_fn.clone(),
+			&mut challenger,
+		)?;
+
+		let eval_point = sumcheck_claims[0].eval_point.clone();
+		let query = MultilinearQuery::<FW>::with_full_query(
+			&eval_point.iter().map(|&x| x.into()).collect::<Vec<_>>(),
+		)?;
+		let (zero_evals, one_evals): (Vec<F>, Vec<F>) = active_witnesses
+			.iter()
+			.map(|witness| {
+				let (zero_half, one_half) = witness.layer(layer_no + 1).split_at(1 << layer_no);
+				let zero_eval: FW =
+					MultilinearExtension::from_values_slice(zero_half)?.evaluate(&query)?;
+				let one_eval: FW =
+					MultilinearExtension::from_values_slice(one_half)?.evaluate(&query)?;
+				Ok((zero_eval.into(), one_eval.into()))
+			})
+			.collect::<Result<Vec<_>, Error>>()?
+			.into_iter()
+			.unzip();
+
+		let evals = sumcheck_claims
+			.iter()
+			.map(|claim| claim.eval)
+			.collect::<Vec<_>>();
+		let new_layer_claims =
+			reduce_layer_claims(&eval_point, &evals, &zero_evals, &one_evals, &mut challenger)?;
+		for (&i, new_layer_claim) in active_indices.iter().zip(new_layer_claims) {
+			layer_claims[i] = new_layer_claim;
+		}
+
+		batch_layer_proofs.push(LayerProof {
+			gkr_sumcheck_batch_proof,
+			zero_evals,
+			one_evals,
+		});
+	}
+
+	Ok(GrandProductBatchProveOutput {
+		evalcheck_claims: make_evalcheck_claims(claims, layer_claims),
+		proof: GrandProductBatchProof { batch_layer_proofs },
+	})
+}
+
+/// Run the batched GKR sumcheck reducing claims on layer `layer_no` to evaluations of the
+/// products of the next layer's halves.
+///
+/// Layer $0$ has no variables, in which case the claims are returned as they are.
+fn prove_layer_gkr_sumcheck<F, FW, DomainField, CH>(
+	layer_no: usize,
+	witnesses: &[&GrandProductWitness<FW>],
+	layer_claims: Vec<LayerClaim<F>>,
+	domain: &EvaluationDomain<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	challenger: CH,
+) -> Result<(GkrSumcheckBatchProof<F>, Vec<LayerClaim<F>>), Error>
+where
+	F: TowerField + From<FW>,
+	FW: Field + From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	if layer_no == 0 {
+		let empty_proof = GkrSumcheckBatchProof {
+			rounds: Vec::new(),
+			sorted_evals: Vec::new(),
+		};
+		return Ok((empty_proof, layer_claims));
+	}
+
+	let provers = witnesses
+		.iter()
+		.zip(layer_claims)
+		.map(|(witness, layer_claim)| {
+			let (zero_half, one_half) = witness.layer(layer_no + 1).split_at(1 << layer_no);
+			let poly = MultilinearComposite::<FW, _, _>::new(
+				layer_no,
+				BivariateProduct,
+				vec![
+					MultilinearExtension::from_values_slice(zero_half)?.specialize_arc_dyn(),
+					MultilinearExtension::from_values_slice(one_half)?.specialize_arc_dyn(),
+				],
+			)?;
+			let current_layer =
+				MultilinearExtension::from_values(witness.layer(layer_no).to_vec())?;
+
+			let claim = GkrSumcheckClaim {
+				n_vars: layer_no,
+				degree: 2,
+				sum: layer_claim.eval,
+				r: layer_claim.eval_point.clone(),
+			};
+			let witness = GkrSumcheckWitness {
+				poly,
+				current_layer,
+			};
+
+			let prover = GkrSumcheckProver::new(
+				domain,
+				claim,
+				witness,
+				&layer_claim.eval_point,
+				switchover_fn.clone(),
+			)?;
+			Ok(prover)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let GkrSumcheckBatchProveOutput {
+		proof,
+		reduced_claims,
+	} = gkr_sumcheck::batch_prove(provers, challenger)?;
+
+	let sumcheck_claims = reduced_claims
+		.into_iter()
+		.map(|claim| LayerClaim {
+			eval_point: claim.eval_point,
+			eval: claim.eval,
+		})
+		.collect();
+
+	Ok((proof, sumcheck_claims))
+}
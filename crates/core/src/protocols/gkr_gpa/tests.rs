@@ -0,0 +1,239 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	batch_prove, batch_verify, prodcheck_to_grand_product_claims, Error, GrandProductBatchProof,
+	GrandProductBatchProveOutput, GrandProductClaim, GrandProductWitness, VerificationError,
+};
+use crate::{
+	challenger::HashChallenger,
+	oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet},
+	polynomial::{IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery},
+	protocols::{
+		evalcheck::EvalcheckClaim,
+		prodcheck::{ProdcheckClaim, ProdcheckWitness},
+	},
+	witness::MultilinearWitness,
+};
+use assert_matches::assert_matches;
+use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b, Field, TowerField};
+use binius_hash::GroestlHasher;
+use rand::{rngs::StdRng, SeedableRng};
+use std::iter::repeat_with;
+
+type F = BinaryField32b;
+type FE = BinaryField128b;
+
+fn random_multilinear(rng: &mut StdRng, n_vars: usize) -> MultilinearWitness<'static, FE> {
+	let values = repeat_with(|| <F as Field>::random(&mut *rng))
+		.take(1 << n_vars)
+		.collect();
+	MultilinearExtension::from_values(values)
+		.unwrap()
+		.specialize_arc_dyn()
+}
+
+fn prove_helper(
+	witnesses: Vec<GrandProductWitness<FE>>,
+	claims: Vec<GrandProductClaim<FE>>,
+) -> GrandProductBatchProveOutput<FE> {
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	batch_prove::<_, _, BinaryField8b, _>(witnesses, claims, domain_factory, |_| 1, challenger)
+		.unwrap()
+}
+
+fn verify_helper(
+	claims: Vec<GrandProductClaim<FE>>,
+	proof: GrandProductBatchProof<FE>,
+) -> Result<Vec<EvalcheckClaim<FE>>, Error> {
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	batch_verify(claims, proof, challenger)
+}
+
+#[test]
+fn test_grand_product_witness_layers() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 4;
+	let poly = random_multilinear(&mut rng, n_vars);
+
+	let witness = GrandProductWitness::new(poly.clone()).unwrap();
+	assert_eq!(witness.n_vars(), n_vars);
+
+	let expected_product = (0..1 << n_vars)
+		.map(|i| poly.evaluate_on_hypercube(i).unwrap())
+		.product::<FE>();
+	assert_eq!(witness.grand_product_evaluation(), expected_product);
+
+	for k in 0..n_vars {
+		let layer = witness.layer(k);
+		let next_layer = witness.layer(k + 1);
+		assert_eq!(layer.len(), 1 << k);
+		for (i, &eval) in layer.iter().enumerate() {
+			assert_eq!(eval, next_layer[i] * next_layer[i + (1 << k)]);
+		}
+	}
+}
+
+#[test]
+fn test_prove_verify_batch() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+
+	let all_n_vars = [5, 0, 3, 5, 1];
+
+	let polys = all_n_vars
+		.iter()
+		.map(|&n_vars| random_multilinear(&mut rng, n_vars))
+		.collect::<Vec<_>>();
+	let witnesses = polys
+		.iter()
+		.map(|poly| GrandProductWitness::new(poly.clone()).unwrap())
+		.collect::<Vec<_>>();
+	let claims = all_n_vars
+		.iter()
+		.zip(witnesses.iter())
+		.map(|(&n_vars, witness)| {
+			let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+				n_vars,
+				n_polys: 1,
+				tower_level: F::TOWER_LEVEL,
+			});
+			GrandProductClaim {
+				poly: oracles.committed_oracle(CommittedId { batch_id, index: 0 }),
+				product: witness.grand_product_evaluation(),
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let GrandProductBatchProveOutput {
+		evalcheck_claims,
+		proof,
+	} = prove_helper(witnesses, claims.clone());
+	assert_eq!(proof.batch_layer_proofs.len(), 5);
+
+	let verified_evalcheck_claims = verify_helper(claims, proof).unwrap();
+	assert_eq!(verified_evalcheck_claims.len(), all_n_vars.len());
+
+	for ((prover_claim, verifier_claim), poly) in evalcheck_claims
+		.iter()
+		.zip(verified_evalcheck_claims.iter())
+		.zip(polys.iter())
+	{
+		assert_eq!(prover_claim.eval_point, verifier_claim.eval_point);
+		assert_eq!(prover_claim.eval, verifier_claim.eval);
+		assert_eq!(verifier_claim.eval_point.len(), poly.n_vars());
+
+		let query = MultilinearQuery::with_full_query(&verifier_claim.eval_point).unwrap();
+		assert_eq!(poly.evaluate(&query).unwrap(), verifier_claim.eval);
+	}
+
+	// Evaluation points are prefixes of the point of the largest claim
+	let largest_point = &verified_evalcheck_claims[0].eval_point;
+	for claim in verified_evalcheck_claims.iter() {
+		assert_eq!(claim.eval_point[..], largest_point[..claim.eval_point.len()]);
+	}
+}
+
+#[test]
+fn test_verify_rejects_incorrect_product() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+	let n_vars = 4;
+
+	let poly = random_multilinear(&mut rng, n_vars);
+	let witness = GrandProductWitness::new(poly).unwrap();
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: 1,
+		tower_level: F::TOWER_LEVEL,
+	});
+	let claim = GrandProductClaim {
+		poly: oracles.committed_oracle(CommittedId { batch_id, index: 0 }),
+		product: witness.grand_product_evaluation(),
+	};
+
+	let GrandProductBatchProveOutput { proof, .. } =
+		prove_helper(vec![witness], vec![claim.clone()]);
+
+	let wrong_claim = GrandProductClaim {
+		product: claim.product + FE::ONE,
+		..claim
+	};
+	assert_matches!(
+		verify_helper(vec![wrong_claim], proof),
+		Err(Error::Verification(VerificationError::IncorrectLayerProduct))
+	);
+}
+
+#[test]
+fn test_verify_rejects_first_layer_sumcheck() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+	let n_vars = 4;
+
+	let poly = random_multilinear(&mut rng, n_vars);
+	let witness = GrandProductWitness::new(poly).unwrap();
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: 1,
+		tower_level: F::TOWER_LEVEL,
+	});
+	let claim = GrandProductClaim {
+		poly: oracles.committed_oracle(CommittedId { batch_id, index: 0 }),
+		product: witness.grand_product_evaluation(),
+	};
+
+	let GrandProductBatchProveOutput { mut proof, .. } =
+		prove_helper(vec![witness], vec![claim.clone()]);
+	proof.batch_layer_proofs[0]
+		.gkr_sumcheck_batch_proof
+		.sorted_evals
+		.push(FE::ONE);
+
+	assert_matches!(
+		verify_helper(vec![claim], proof),
+		Err(Error::Verification(VerificationError::NonEmptyFirstLayerSumcheck))
+	);
+}
+
+#[test]
+fn test_prodcheck_via_grand_products() {
+	let n_vars = 6;
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+
+	// U is a permutation of T, so their grand products agree
+	let t_values = (1..=1 << n_vars)
+		.map(|i| FE::new(i as u128))
+		.collect::<Vec<_>>();
+	let u_values = t_values.iter().rev().copied().collect::<Vec<_>>();
+	let prodcheck_witness = ProdcheckWitness {
+		t_polynomial: MultilinearExtension::from_values(t_values)
+			.unwrap()
+			.specialize_arc_dyn(),
+		u_polynomial: MultilinearExtension::from_values(u_values)
+			.unwrap()
+			.specialize_arc_dyn(),
+	};
+
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: 2,
+		tower_level: FE::TOWER_LEVEL,
+	});
+	let prodcheck_claim = ProdcheckClaim {
+		t_oracle: oracles.committed_oracle(CommittedId { batch_id, index: 0 }),
+		u_oracle: oracles.committed_oracle(CommittedId { batch_id, index: 1 }),
+	};
+
+	let witnesses = GrandProductWitness::from_prodcheck_witness(prodcheck_witness).unwrap();
+	let product = witnesses[0].grand_product_evaluation();
+	assert_eq!(product, witnesses[1].grand_product_evaluation());
+
+	let claims = prodcheck_to_grand_product_claims(&prodcheck_claim, product).unwrap();
+	let GrandProductBatchProveOutput { proof, .. } =
+		prove_helper(witnesses.into(), claims.to_vec());
+	let evalcheck_claims = verify_helper(claims.to_vec(), proof).unwrap();
+
+	assert_eq!(evalcheck_claims.len(), 2);
+	assert_eq!(evalcheck_claims[0].eval_point, evalcheck_claims[1].eval_point);
+}
@@ -0,0 +1,113 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	gkr_gpa::{
+		make_evalcheck_claims, reduce_layer_claims, GrandProductBatchProof, GrandProductClaim,
+		LayerClaim, LayerProof,
+	},
+	Error, VerificationError,
+};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	protocols::{
+		evalcheck::EvalcheckClaim,
+		gkr_sumcheck::{self, GkrSumcheckClaim},
+	},
+};
+use binius_field::TowerField;
+use tracing::instrument;
+
+/// Verify a batch of grand product claims.
+///
+/// Returns evalcheck claims on the grand product polynomials, in the order of the claims.
+///
+/// ## Throws
+///
+/// * [`VerificationError::NumberOfLayerProofs`] if the proof has the wrong number of layers
+/// * [`VerificationError::IncorrectLayerProduct`] if a layer reduction fails
+/// * [`VerificationError::NonEmptyFirstLayerSumcheck`] if the layer 0 proof has sumcheck rounds or
+///   evaluations
+#[instrument(skip_all, name = "gkr_gpa::batch_verify")]
+pub fn batch_verify<F, CH>(
+	claims: impl IntoIterator<Item = GrandProductClaim<F>>,
+	proof: GrandProductBatchProof<F>,
+	mut challenger: CH,
+) -> Result<Vec<EvalcheckClaim<F>>, Error>
+where
+	F: TowerField,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let claims = claims.into_iter().collect::<Vec<_>>();
+	let max_n_vars = claims.iter().map(|claim| claim.n_vars()).max().unwrap_or(0);
+
+	let GrandProductBatchProof { batch_layer_proofs } = proof;
+	if batch_layer_proofs.len() != max_n_vars {
+		return Err(VerificationError::NumberOfLayerProofs.into());
+	}
+
+	let mut layer_claims = claims
+		.iter()
+		.map(|claim| LayerClaim {
+			eval_point: Vec::new(),
+			eval: claim.product,
+		})
+		.collect::<Vec<_>>();
+
+	for (layer_no, layer_proof) in batch_layer_proofs.into_iter().enumerate() {
+		let LayerProof {
+			gkr_sumcheck_batch_proof,
+			zero_evals,
+			one_evals,
+		} = layer_proof;
+
+		// Claims that have not yet reached their input layer, all sharing the same evaluation point
+		let active_indices = (0..claims.len())
+			.filter(|&i| claims[i].n_vars() > layer_no)
+			.collect::<Vec<_>>();
+		let active_claims = active_indices
+			.iter()
+			.map(|&i| layer_claims[i].clone())
+			.collect::<Vec<_>>();
+		let eval_point = active_claims[0].eval_point.clone();
+
+		// Layer 0 has no variables, so there is no sumcheck to run
+		let (eval_point, evals) = if layer_no == 0 {
+			if !gkr_sumcheck_batch_proof.rounds.is_empty()
+				|| !gkr_sumcheck_batch_proof.sorted_evals.is_empty()
+			{
+				return Err(VerificationError::NonEmptyFirstLayerSumcheck.into());
+			}
+			let evals = active_claims
+				.iter()
+				.map(|claim| claim.eval)
+				.collect::<Vec<_>>();
+			(eval_point, evals)
+		} else {
+			let gkr_sumcheck_claims = active_claims.iter().map(|claim| GkrSumcheckClaim {
+				n_vars: layer_no,
+				degree: 2,
+				sum: claim.eval,
+				r: eval_point.clone(),
+			});
+			let reduced_claims = gkr_sumcheck::batch_verify(
+				gkr_sumcheck_claims,
+				gkr_sumcheck_batch_proof,
+				&mut challenger,
+			)?;
+			let eval_point = reduced_claims[0].eval_point.clone();
+			let evals = reduced_claims
+				.iter()
+				.map(|claim| claim.eval)
+				.collect::<Vec<_>>();
+			(eval_point, evals)
+		};
+
+		let new_layer_claims =
+			reduce_layer_claims(&eval_point, &evals, &zero_evals, &one_evals, &mut challenger)?;
+		for (&i, new_layer_claim) in active_indices.iter().zip(new_layer_claims) {
+			layer_claims[i] = new_layer_claim;
+		}
+	}
+
+	Ok(make_evalcheck_claims(claims, layer_claims))
+}
@@ -6,7 +6,8 @@ use p3_challenger::{CanObserve, CanSample};
 use crate::{
 	polynomial::CompositionPoly,
 	protocols::abstract_sumcheck::{
-		self, AbstractSumcheckBatchProof, AbstractSumcheckBatchProveOutput, ReducedClaim,
+		self, AbstractSumcheckBatchProof, AbstractSumcheckBatchProveOutput, BatchBindingOrder,
+		ReducedClaim,
 	},
 };
 
@@ -33,7 +34,7 @@ where
 	CW: CompositionPoly<PW::Scalar>,
 	CH: CanObserve<F> + CanSample<F>,
 {
-	abstract_sumcheck::batch_prove(provers, challenger)
+	abstract_sumcheck::batch_prove(provers, BatchBindingOrder::BackLoaded, challenger)
 }
 
 /// Verify a batched GkrSumcheck instance.
@@ -70,6 +71,7 @@ where
 	abstract_sumcheck::batch_verify(
 		claims_vec.into_iter().map(|c| c.into()),
 		proof,
+		BatchBindingOrder::BackLoaded,
 		reductor,
 		challenger,
 	)
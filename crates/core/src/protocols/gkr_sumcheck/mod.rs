@@ -25,4 +25,8 @@ mod tests;
 
 pub use batch::*;
 pub use error::*;
+pub use gkr_sumcheck::{
+	GkrSumcheckClaim, GkrSumcheckReductor, GkrSumcheckRound, GkrSumcheckRoundClaim,
+	GkrSumcheckWitness,
+};
 pub use prove::*;
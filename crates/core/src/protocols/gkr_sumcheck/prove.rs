@@ -14,7 +14,8 @@ use crate::{
 	},
 	protocols::abstract_sumcheck::{
 		check_evaluation_domain, validate_rd_challenge, AbstractSumcheckEvaluator,
-		AbstractSumcheckProver, AbstractSumcheckReductor, ProverState, ReducedClaim,
+		AbstractSumcheckProver, AbstractSumcheckReductor, BatchBindingOrder, ProverState,
+		ReducedClaim,
 	},
 	witness::MultilinearWitness,
 };
@@ -275,7 +276,7 @@ where
 		GkrSumcheckProver::finalize(self, prev_rd_challenge)
 	}
 
-	fn batch_proving_consistent(&self, other: &Self) -> bool {
+	fn batch_proving_consistent(&self, other: &Self, _binding_order: BatchBindingOrder) -> bool {
 		self.gkr_challenge_point == other.gkr_challenge_point
 	}
 
@@ -5,6 +5,7 @@ use crate::{
 	challenger::{CanObserve, CanSample},
 	oracle::{BatchId, MultilinearOracleSet},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		evalcheck::{EvalcheckClaim, EvalcheckVerifier, SameQueryPcsClaim},
 		sumcheck::batch_verify,
 		test_utils::make_non_same_query_pcs_sumcheck_claims,
@@ -45,8 +46,12 @@ where
 		}
 
 		// Reduce the new sumcheck claims for virtual polynomial openings to new evalcheck claims.
-		let new_evalcheck_claims =
-			batch_verify(new_sumchecks, sumcheck_batch_proof, &mut challenger)?;
+		let new_evalcheck_claims = batch_verify(
+			new_sumchecks,
+			sumcheck_batch_proof,
+			BatchBindingOrder::BackLoaded,
+			&mut challenger,
+		)?;
 
 		if new_evalcheck_claims.len() < evalcheck_proofs.len() {
 			return Err(Error::ExtraVirtualOpeningProof);
@@ -95,8 +100,12 @@ where
 					&non_sqpcs_claims,
 				)?;
 
-				let evalcheck_claims =
-					batch_verify(non_sqpcs_sumchecks, sumcheck_proof, &mut challenger)?;
+				let evalcheck_claims = batch_verify(
+					non_sqpcs_sumchecks,
+					sumcheck_proof,
+					BatchBindingOrder::BackLoaded,
+					&mut challenger,
+				)?;
 
 				if evalcheck_claims.len() < evalcheck_proofs.len() {
 					return Err(Error::ExtraBatchOpeningProof);
@@ -0,0 +1,255 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{error::Error, lasso::LassoClaim};
+use crate::oracle::{Error as OracleError, MultilinearOracleSet, MultilinearPolyOracle, OracleId};
+use binius_field::{BinaryField1b, ExtensionField, TowerField};
+use getset::CopyGetters;
+use p3_util::log2_ceil_usize;
+
+/// Decomposition of lookups into a large table into lookups of chunks into small subtables.
+///
+/// A value of tower level $\iota + k$ is split into $c = 2^k$ chunks $u_0, \ldots, u_{c-1}$ of
+/// tower level $\iota$, its coordinates in the tower basis $\beta_0, \ldots, \beta_{c-1}$ over
+/// $T_{\iota}$. Each chunk is looked up into a subtable of size $2^{2^\iota}$ and the value is
+/// recovered by the combining function $g(u_0, \ldots, u_{c-1}) = \sum_j \beta_j u_j$, which is
+/// linear and therefore a virtual oracle. As the tower basis splits a value into its bit chunks,
+/// this covers range checks, where the subtable holds every chunk value.
+///
+/// Operations acting chunk-wise, such as bitwise AND or XOR, decompose the operand and result
+/// columns alike. The $j$-th chunks of the $k$ columns form a tuple, packed in the tower basis into
+/// an element of tower level $\iota + \lceil \log_2 k \rceil$, which is looked up into the
+/// subtable of all operand chunk tuples along with the operation on them. Each column is then
+/// recovered from its chunks by $g$, without ever materializing the large table.
+///
+/// The chunk lookups are proven together with [`batch_prove`](super::batch_prove).
+#[derive(Debug, Clone, Copy, CopyGetters)]
+pub struct LassoDecomposition {
+	/// The tower level $\iota$ of the chunks
+	#[get_copy = "pub"]
+	chunk_tower_level: usize,
+	/// The base 2 logarithm of the number of chunks
+	#[get_copy = "pub"]
+	log_n_chunks: usize,
+}
+
+impl LassoDecomposition {
+	pub fn new(chunk_tower_level: usize, log_n_chunks: usize) -> Self {
+		Self {
+			chunk_tower_level,
+			log_n_chunks,
+		}
+	}
+
+	pub fn n_chunks(&self) -> usize {
+		1 << self.log_n_chunks
+	}
+
+	/// The tower level of the decomposed values.
+	pub fn tower_level(&self) -> usize {
+		self.chunk_tower_level + self.log_n_chunks
+	}
+
+	/// Add the virtual oracle $g(u_0, \ldots, u_{c-1})$ combining the chunk oracles, lowest chunk
+	/// first.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionChunksMismatch`] if the number of chunks is incorrect
+	pub fn add_combined_oracle<F: TowerField>(
+		&self,
+		oracles: &mut MultilinearOracleSet<F>,
+		chunk_ids: &[OracleId],
+	) -> Result<OracleId, Error> {
+		if chunk_ids.len() != self.n_chunks() {
+			return Err(Error::DecompositionChunksMismatch);
+		}
+
+		if chunk_ids[0] >= oracles.size() {
+			return Err(OracleError::InvalidOracleId(chunk_ids[0]).into());
+		}
+
+		let n_vars = oracles.n_vars(chunk_ids[0]);
+		let inner = chunk_ids
+			.iter()
+			.enumerate()
+			.map(|(j, &chunk_id)| {
+				Ok((chunk_id, <F as TowerField>::basis(self.chunk_tower_level, j)?))
+			})
+			.collect::<Result<Vec<_>, binius_field::Error>>()
+			.map_err(|_| Error::DecompositionTowerLevelMismatch)?;
+
+		Ok(oracles.add_linear_combination(n_vars, inner)?)
+	}
+
+	/// The tower level of the packed tuples of `arity` column chunks.
+	pub fn tuple_tower_level(&self, arity: usize) -> usize {
+		self.chunk_tower_level + log2_ceil_usize(arity)
+	}
+
+	/// Add the Lasso claims of the chunk tuples of several columns into `table_oracle`.
+	///
+	/// `column_chunk_ids` holds the chunk oracles of each column, lowest chunk first, operands
+	/// before the result. The $j$-th claim looks up the $j$-th chunks of the columns packed in
+	/// the tower basis, or the chunk itself for a single column.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionChunksMismatch`] if there are no columns or the number of chunks of
+	///   a column is incorrect
+	/// * [`Error::DecompositionTowerLevelMismatch`] if the tuples do not fit into the field
+	/// * [`Error::NumVariablesMismatch`] if the chunks and the table sizes do not match
+	pub fn add_chunk_claims<F: TowerField>(
+		&self,
+		oracles: &mut MultilinearOracleSet<F>,
+		table_oracle: MultilinearPolyOracle<F>,
+		column_chunk_ids: &[Vec<OracleId>],
+	) -> Result<Vec<LassoClaim<F>>, Error> {
+		if column_chunk_ids.is_empty()
+			|| column_chunk_ids
+				.iter()
+				.any(|chunk_ids| chunk_ids.len() != self.n_chunks())
+		{
+			return Err(Error::DecompositionChunksMismatch);
+		}
+
+		let basis = (0..column_chunk_ids.len())
+			.map(|i| <F as TowerField>::basis(self.chunk_tower_level, i))
+			.collect::<Result<Vec<_>, binius_field::Error>>()
+			.map_err(|_| Error::DecompositionTowerLevelMismatch)?;
+
+		(0..self.n_chunks())
+			.map(|j| -> Result<_, Error> {
+				let chunk_id = column_chunk_ids[0][j];
+				if chunk_id >= oracles.size() {
+					return Err(OracleError::InvalidOracleId(chunk_id).into());
+				}
+
+				let tuple_id = if column_chunk_ids.len() == 1 {
+					chunk_id
+				} else {
+					let n_vars = oracles.n_vars(chunk_id);
+					let inner = column_chunk_ids
+						.iter()
+						.zip(basis.iter())
+						.map(|(chunk_ids, &basis_elem)| (chunk_ids[j], basis_elem));
+					oracles.add_linear_combination(n_vars, inner)?
+				};
+
+				LassoClaim::new(table_oracle.clone(), oracles.oracle(tuple_id))
+			})
+			.collect()
+	}
+
+	/// Pack a tuple of chunks in the tower basis, lowest column first.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionTowerLevelMismatch`] if `FC` is not of the chunk tower level or the
+	///   tuple does not fit into `FT`
+	pub fn pack_tuple<FC, FT>(&self, tuple: &[FC]) -> Result<FT, Error>
+	where
+		FC: TowerField,
+		FT: TowerField + ExtensionField<FC>,
+	{
+		if FC::TOWER_LEVEL != self.chunk_tower_level {
+			return Err(Error::DecompositionTowerLevelMismatch);
+		}
+
+		<FT as ExtensionField<FC>>::from_bases(tuple)
+			.map_err(|_| Error::DecompositionTowerLevelMismatch)
+	}
+
+	/// The subtable of a chunk-wise operation over `n_operands` operands.
+	///
+	/// Row $\sum_i x_i 2^{i 2^\iota}$ holds the packed tuple $(x_0, \ldots, x_{k-1}, op(x))$ of the
+	/// operand chunks and the operation on them.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionTowerLevelMismatch`] if `FC` is not of the chunk tower level or the
+	///   tuples do not fit into `FT`
+	pub fn subtable<FC, FT>(
+		&self,
+		n_operands: usize,
+		op: impl Fn(&[FC]) -> FC,
+	) -> Result<Vec<FT>, Error>
+	where
+		FC: TowerField,
+		FT: TowerField + ExtensionField<FC>,
+	{
+		if FC::TOWER_LEVEL != self.chunk_tower_level {
+			return Err(Error::DecompositionTowerLevelMismatch);
+		}
+
+		let chunk_bits = FC::N_BITS;
+		let chunk_from_index = |index: usize| {
+			let bits = (0..chunk_bits)
+				.map(|b| BinaryField1b::from(((index >> b) & 1) as u8))
+				.collect::<Vec<_>>();
+			<FC as ExtensionField<BinaryField1b>>::from_bases(&bits)
+				.expect("the chunk has as many bits as the field")
+		};
+
+		(0..1 << (n_operands * chunk_bits))
+			.map(|row| {
+				let mut tuple = (0..n_operands)
+					.map(|i| chunk_from_index(row >> (i * chunk_bits)))
+					.collect::<Vec<_>>();
+				tuple.push(op(&tuple));
+				self.pack_tuple(&tuple)
+			})
+			.collect()
+	}
+
+	/// Split a value into its chunks, lowest chunk first.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionTowerLevelMismatch`] if `FC` is not of the chunk tower level
+	/// * [`Error::DecompositionValueOutOfRange`] if the value exceeds the decomposed tower level
+	pub fn decompose<FC, FV>(&self, value: FV) -> Result<Vec<FC>, Error>
+	where
+		FC: TowerField,
+		FV: TowerField + ExtensionField<FC>,
+	{
+		if FC::TOWER_LEVEL != self.chunk_tower_level {
+			return Err(Error::DecompositionTowerLevelMismatch);
+		}
+
+		let mut chunks = <FV as ExtensionField<FC>>::iter_bases(&value).collect::<Vec<_>>();
+		if chunks.len() < self.n_chunks() {
+			chunks.resize(self.n_chunks(), FC::ZERO);
+		}
+		if chunks[self.n_chunks()..]
+			.iter()
+			.any(|&chunk| chunk != FC::ZERO)
+		{
+			return Err(Error::DecompositionValueOutOfRange);
+		}
+		chunks.truncate(self.n_chunks());
+		Ok(chunks)
+	}
+
+	/// Evaluate the combining function $g$ on the chunks, lowest chunk first.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::DecompositionTowerLevelMismatch`] if `FC` is not of the chunk tower level
+	/// * [`Error::DecompositionChunksMismatch`] if the number of chunks is incorrect
+	pub fn combine<FC, FV>(&self, chunks: &[FC]) -> Result<FV, Error>
+	where
+		FC: TowerField,
+		FV: TowerField + ExtensionField<FC>,
+	{
+		if FC::TOWER_LEVEL != self.chunk_tower_level {
+			return Err(Error::DecompositionTowerLevelMismatch);
+		}
+
+		if chunks.len() != self.n_chunks() {
+			return Err(Error::DecompositionChunksMismatch);
+		}
+
+		<FV as ExtensionField<FC>>::from_bases(chunks)
+			.map_err(|_| Error::DecompositionValueOutOfRange)
+	}
+}
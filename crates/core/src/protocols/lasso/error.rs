@@ -21,6 +21,16 @@ pub enum Error {
 	MergedWitnessNumVariablesMismatch,
 	#[error("actual Lasso counts may not fit into the chosen count integer type")]
 	LassoCountTypeTooSmall,
+	#[error("the number of claims, witnesses and Lasso batches must match")]
+	MismatchedBatchLength,
+	#[error("at least one Lasso claim is required")]
+	EmptyBatch,
+	#[error("the number of chunks does not match the decomposition")]
+	DecompositionChunksMismatch,
+	#[error("the chunk field does not match the tower level of the decomposition")]
+	DecompositionTowerLevelMismatch,
+	#[error("the value does not fit into the chunks of the decomposition")]
+	DecompositionValueOutOfRange,
 	#[error("oracle error: {0}")]
 	Oracle(#[from] OracleError),
 	#[error("polynomial error: {0}")]
@@ -16,8 +16,10 @@ use crate::{
 	},
 	witness::MultilinearWitness,
 };
-use binius_field::{BinaryField16b, BinaryField32b, Field, PackedField, TowerField};
+use binius_field::{BinaryField, BinaryField16b, BinaryField32b, Field, PackedField, TowerField};
 use getset::{CopyGetters, Getters};
+use p3_util::log2_ceil_usize;
+use std::iter;
 
 pub trait LassoCount: TowerField {
 	fn overflowing_add(self, rhs: Self) -> (Self, bool);
@@ -211,6 +213,21 @@ pub struct LassoProveOutput<'a, F: Field, FW: TowerField, PB: PackedField> {
 	pub committed_polys: [MultilinearExtension<PB>; 3],
 }
 
+pub struct ReducedLassoBatchClaims<F: Field> {
+	/// Unary carry zerochecks of the claims, in the order of the claims
+	pub zerocheck_claims: Vec<ZerocheckClaim<F>>,
+	/// A single multiset check over the relations of all claims
+	pub msetcheck_claim: MsetcheckClaim<F>,
+}
+
+pub struct LassoBatchProveOutput<'a, F: Field, FW: TowerField, PB: PackedField> {
+	pub reduced_lasso_claims: ReducedLassoBatchClaims<F>,
+	pub zerocheck_witnesses: Vec<ZerocheckWitness<'a, FW, UnaryCarryConstraint>>,
+	pub msetcheck_witness: MsetcheckWitness<'a, FW>,
+	/// Committed polynomials of each Lasso batch, in the order of the claims
+	pub committed_polys: Vec<[MultilinearExtension<PB>; 3]>,
+}
+
 pub(super) struct LassoReducedClaimOracleIds {
 	pub tu_merged_oracle_id: OracleId,
 	pub final_counts_and_counts_oracle_id: OracleId,
@@ -331,3 +348,97 @@ pub fn reduce_lasso_claim<C: LassoCount, F: TowerField>(
 
 	Ok((reduced_lasso_claims, lasso_claim_oracles))
 }
+
+pub(super) struct CombinedRelationOracleIds {
+	/// Constant table id columns of each claim
+	pub table_id_oracle_ids: Vec<OracleId>,
+	/// Zero-padded `[values, T counts, U counts, table ids]` columns of each claim
+	pub padded_oracle_ids: Vec<[OracleId; 4]>,
+	/// Sums of the zero-padded columns over all claims
+	pub combined_oracle_ids: [OracleId; 4],
+}
+
+/// Distinct non-zero table ids $g^i$, where $g$ is the multiplicative generator.
+pub(super) fn table_ids<F: BinaryField>() -> impl Iterator<Item = F> {
+	iter::successors(Some(F::ONE), |&table_id| Some(table_id * F::MULTIPLICATIVE_GENERATOR))
+}
+
+/// The number of variables of the combined relation, given the number of variables of the
+/// relation of each claim.
+pub(super) fn combined_relation_n_vars(relation_n_vars: &[usize]) -> Result<usize, Error> {
+	let max_n_vars = relation_n_vars
+		.iter()
+		.copied()
+		.max()
+		.ok_or(Error::EmptyBatch)?;
+	Ok(max_n_vars + log2_ceil_usize(relation_n_vars.len()))
+}
+
+/// Combine the multiset checks of several Lasso claims into a single one.
+///
+/// The relation $(merge(T, U), merge(F, C))$ vs. $(merge(T, U), merge(0, P))$ of the $i$-th claim
+/// is extended with a constant column of table id $g^i$ and zero-padded into the $i$-th block of
+/// a common hypercube, with blocks as large as the largest relation. The combined relation is the
+/// sum of the padded columns. The padding rows are all zero on both sides, while the table ids
+/// keep rows of different claims apart, so the combined multisets are equal iff the multisets of
+/// every claim are.
+pub(super) fn combine_msetcheck_claims<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	msetcheck_claims: &[MsetcheckClaim<F>],
+) -> Result<(MsetcheckClaim<F>, CombinedRelationOracleIds), Error> {
+	let relation_n_vars = msetcheck_claims
+		.iter()
+		.map(|claim| claim.n_vars())
+		.collect::<Vec<_>>();
+	let combined_n_vars = combined_relation_n_vars(&relation_n_vars)?;
+	let max_n_vars = combined_n_vars - log2_ceil_usize(msetcheck_claims.len());
+
+	let mut table_id_oracle_ids = Vec::with_capacity(msetcheck_claims.len());
+	let mut padded_oracle_ids = Vec::with_capacity(msetcheck_claims.len());
+	for (i, (msetcheck_claim, table_id)) in msetcheck_claims.iter().zip(table_ids()).enumerate() {
+		let n_vars = msetcheck_claim.n_vars();
+		let table_id_oracle_id =
+			oracles.add_linear_combination_with_offset(n_vars, table_id, [])?;
+
+		// Blocks of the smaller relations are spaced as if they were as large as the largest one
+		let block_index = i << (max_n_vars - n_vars);
+		let relation_oracle_ids = [
+			msetcheck_claim.t_oracles()[0].id(),
+			msetcheck_claim.t_oracles()[1].id(),
+			msetcheck_claim.u_oracles()[1].id(),
+			table_id_oracle_id,
+		];
+		let mut padded = [0; 4];
+		for (padded_id, oracle_id) in padded.iter_mut().zip(relation_oracle_ids) {
+			*padded_id = oracles.add_zero_padded(oracle_id, combined_n_vars, block_index)?;
+		}
+
+		table_id_oracle_ids.push(table_id_oracle_id);
+		padded_oracle_ids.push(padded);
+	}
+
+	let mut combined_oracle_ids = [0; 4];
+	for (column, combined_id) in combined_oracle_ids.iter_mut().enumerate() {
+		*combined_id = oracles.add_linear_combination(
+			combined_n_vars,
+			padded_oracle_ids
+				.iter()
+				.map(|padded| (padded[column], F::ONE)),
+		)?;
+	}
+
+	let [values, t_counts, u_counts, table_id_column] =
+		combined_oracle_ids.map(|id| oracles.oracle(id));
+	let msetcheck_claim = MsetcheckClaim::new(
+		[values.clone(), t_counts, table_id_column.clone()],
+		[values, u_counts, table_id_column],
+	)?;
+
+	let combined_relation_oracle_ids = CombinedRelationOracleIds {
+		table_id_oracle_ids,
+		padded_oracle_ids,
+		combined_oracle_ids,
+	};
+
+	Ok((msetcheck_claim, combined_relation_oracle_ids))
+}
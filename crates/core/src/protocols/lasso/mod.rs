@@ -8,8 +8,13 @@
 //! this implementation relies on addition gadget in place of multiplicative group for the "counts".
 //! See [`prove`](self::prove::prove()) for in-depth details.
 //!
+//! Lookups into several tables are proven together with [`batch_prove`], which combines their
+//! multiset checks into one. Lookups into tables too large to commit are split into lookups into
+//! small subtables with a [`LassoDecomposition`].
+//!
 //! [DP23]: <https://eprint.iacr.org/2023/1784>
 
+mod decomposition;
 mod error;
 #[allow(clippy::module_inception)]
 mod lasso;
@@ -18,7 +23,11 @@ mod prove;
 mod tests;
 mod verify;
 
+pub use decomposition::LassoDecomposition;
 pub use error::*;
-pub use lasso::{LassoBatch, LassoClaim, LassoProveOutput, LassoWitness};
+pub use lasso::{
+	LassoBatch, LassoBatchProveOutput, LassoClaim, LassoProveOutput, LassoWitness,
+	ReducedLassoBatchClaims,
+};
 pub use prove::*;
 pub use verify::*;
@@ -3,8 +3,10 @@
 use super::{
 	error::Error,
 	lasso::{
-		reduce_lasso_claim, LassoBatch, LassoClaim, LassoCount, LassoProveOutput,
-		LassoReducedClaimOracleIds, LassoWitness, UnaryCarryConstraint,
+		combine_msetcheck_claims, combined_relation_n_vars, reduce_lasso_claim, table_ids,
+		CombinedRelationOracleIds, LassoBatch, LassoBatchProveOutput, LassoClaim, LassoCount,
+		LassoProveOutput, LassoReducedClaimOracleIds, LassoWitness, ReducedLassoBatchClaims,
+		UnaryCarryConstraint,
 	},
 };
 use crate::{
@@ -19,6 +21,7 @@ use binius_field::{
 };
 use bytemuck::{must_cast_slice_mut, Pod};
 use itertools::izip;
+use p3_util::log2_ceil_usize;
 use std::{array, borrow::Borrow, fmt::Debug};
 use tracing::instrument;
 
@@ -270,3 +273,155 @@ where
 		committed_polys,
 	})
 }
+
+/// Prove a batch of Lasso instance reductions, possibly into different tables.
+///
+/// Each claim is reduced as in [`prove`], with its own Lasso batch and unary carry zerocheck. The
+/// multiset checks of all claims are then combined into a single one, with each relation tagged
+/// by a distinct table id and zero-padded into its own block of a common hypercube.
+///
+/// ## Throws
+///
+/// * [`Error::MismatchedBatchLength`] if the numbers of claims, witnesses and batches differ
+/// * [`Error::EmptyBatch`] if there are no claims
+#[instrument(skip_all, name = "lasso::batch_prove")]
+pub fn batch_prove<'a, PC, PB, F, FW, L>(
+	oracles: &mut MultilinearOracleSet<F>,
+	witness_index: &mut MultilinearWitnessIndex<'a, FW>,
+	lasso_claims: &[LassoClaim<F>],
+	lasso_witnesses: impl IntoIterator<Item = LassoWitness<'a, FW, L>>,
+	lasso_batches: &[LassoBatch],
+) -> Result<LassoBatchProveOutput<'a, F, FW, PB>, Error>
+where
+	PC: RepackedExtension<PB, Scalar: LassoCount> + PackedFieldIndexable + Pod,
+	PB: PackedField<Scalar = BinaryField1b> + Pod,
+	F: TowerField,
+	FW: TowerField + ExtensionField<PC::Scalar> + From<F>,
+	L: AsRef<[usize]>,
+{
+	let lasso_witnesses = lasso_witnesses.into_iter().collect::<Vec<_>>();
+	if lasso_witnesses.len() != lasso_claims.len() || lasso_batches.len() != lasso_claims.len() {
+		return Err(Error::MismatchedBatchLength);
+	}
+
+	let mut zerocheck_claims = Vec::with_capacity(lasso_claims.len());
+	let mut zerocheck_witnesses = Vec::with_capacity(lasso_claims.len());
+	let mut msetcheck_claims = Vec::with_capacity(lasso_claims.len());
+	let mut msetcheck_witnesses = Vec::with_capacity(lasso_claims.len());
+	let mut committed_polys = Vec::with_capacity(lasso_claims.len());
+
+	for (lasso_claim, lasso_witness, lasso_batch) in
+		izip!(lasso_claims, lasso_witnesses, lasso_batches)
+	{
+		let LassoProveOutput {
+			reduced_lasso_claims,
+			zerocheck_witness,
+			msetcheck_witness,
+			committed_polys: batch_committed_polys,
+		} = prove::<PC, PB, F, FW, L>(
+			oracles,
+			witness_index,
+			lasso_claim,
+			lasso_witness,
+			lasso_batch,
+		)?;
+
+		zerocheck_claims.push(reduced_lasso_claims.zerocheck_claim);
+		zerocheck_witnesses.push(zerocheck_witness);
+		msetcheck_claims.push(reduced_lasso_claims.msetcheck_claim);
+		msetcheck_witnesses.push(msetcheck_witness);
+		committed_polys.push(batch_committed_polys);
+	}
+
+	let (msetcheck_claim, combined_relation_oracle_ids) =
+		combine_msetcheck_claims(oracles, &msetcheck_claims)?;
+	let msetcheck_witness = combine_msetcheck_witnesses::<F, FW>(
+		witness_index,
+		&msetcheck_witnesses,
+		&combined_relation_oracle_ids,
+	)?;
+
+	Ok(LassoBatchProveOutput {
+		reduced_lasso_claims: ReducedLassoBatchClaims {
+			zerocheck_claims,
+			msetcheck_claim,
+		},
+		zerocheck_witnesses,
+		msetcheck_witness,
+		committed_polys,
+	})
+}
+
+/// Build the witnesses of the oracles added by
+/// [`combine_msetcheck_claims`](super::lasso::combine_msetcheck_claims).
+fn combine_msetcheck_witnesses<'a, F, FW>(
+	witness_index: &mut MultilinearWitnessIndex<'a, FW>,
+	msetcheck_witnesses: &[MsetcheckWitness<'a, FW>],
+	oracle_ids: &CombinedRelationOracleIds,
+) -> Result<MsetcheckWitness<'a, FW>, Error>
+where
+	F: TowerField,
+	FW: TowerField + From<F>,
+{
+	let relation_n_vars = msetcheck_witnesses
+		.iter()
+		.map(|witness| witness.n_vars())
+		.collect::<Vec<_>>();
+	let combined_n_vars = combined_relation_n_vars(&relation_n_vars)?;
+	let max_n_vars = combined_n_vars - log2_ceil_usize(msetcheck_witnesses.len());
+
+	let mut combined: [_; 4] = array::from_fn(|_| vec![FW::ZERO; 1 << combined_n_vars]);
+
+	for (i, (witness, padded_oracle_ids, &table_id_oracle_id, table_id)) in izip!(
+		msetcheck_witnesses,
+		&oracle_ids.padded_oracle_ids,
+		&oracle_ids.table_id_oracle_ids,
+		table_ids::<F>()
+	)
+	.enumerate()
+	{
+		let n_vars = witness.n_vars();
+		let block = (i << max_n_vars)..(i << max_n_vars) + (1 << n_vars);
+
+		let [values, t_counts, u_counts, table_id_column] = combined.each_mut();
+		let relation_columns = [
+			(&witness.t_polynomials()[0], values),
+			(&witness.t_polynomials()[1], t_counts),
+			(&witness.u_polynomials()[1], u_counts),
+		];
+		for (poly, column) in relation_columns {
+			poly.subcube_evals(n_vars, 0, &mut column[block.clone()])?;
+		}
+
+		let table_ids = vec![FW::from(table_id); 1 << n_vars];
+		table_id_column[block.clone()].copy_from_slice(&table_ids);
+		witness_index.set(
+			table_id_oracle_id,
+			MultilinearExtension::from_values(table_ids)?.specialize_arc_dyn(),
+		);
+
+		for (&padded_oracle_id, column) in padded_oracle_ids.iter().zip(combined.iter()) {
+			let mut padded = vec![FW::ZERO; 1 << combined_n_vars];
+			padded[block.clone()].copy_from_slice(&column[block.clone()]);
+			witness_index.set(
+				padded_oracle_id,
+				MultilinearExtension::from_values(padded)?.specialize_arc_dyn(),
+			);
+		}
+	}
+
+	let [values, t_counts, u_counts, table_id_column] = array_try_map(combined, |column| {
+		Ok(MultilinearExtension::from_values(column)?.specialize_arc_dyn())
+	})?;
+	witness_index.set_many(oracle_ids.combined_oracle_ids.into_iter().zip([
+		values.clone(),
+		t_counts.clone(),
+		u_counts.clone(),
+		table_id_column.clone(),
+	]));
+
+	Ok(MsetcheckWitness::new(
+		[values.clone(), t_counts, table_id_column.clone()],
+		[values, u_counts, table_id_column],
+	)?)
+}
@@ -3,21 +3,31 @@
 use crate::{
 	oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet},
 	polynomial::MultilinearExtension,
-	protocols::lasso::{prove, verify, LassoBatch, LassoClaim, LassoWitness},
-	witness::MultilinearWitnessIndex,
+	protocols::{
+		lasso::{
+			batch_prove, batch_verify, prove, verify, Error, LassoBatch, LassoBatchProveOutput,
+			LassoClaim, LassoDecomposition, LassoWitness,
+		},
+		msetcheck::MsetcheckWitness,
+	},
+	witness::{MultilinearWitness, MultilinearWitnessIndex},
 };
+use assert_matches::assert_matches;
 use binius_field::{
-	BinaryField128b, BinaryField16b, BinaryField64b, PackedBinaryField128x1b,
-	PackedBinaryField8x16b, TowerField,
+	BinaryField128b, BinaryField16b, BinaryField32b, BinaryField4b, BinaryField64b, BinaryField8b,
+	Field, PackedBinaryField128x1b, PackedBinaryField8x16b, TowerField,
 };
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::iter::repeat_with;
+
+type F = BinaryField128b;
+type C = BinaryField16b;
+type PC = PackedBinaryField8x16b;
+type PB = PackedBinaryField128x1b;
 
 #[test]
 fn test_prove_verify_interaction() {
-	type F = BinaryField128b;
 	type E = BinaryField64b;
-	type C = BinaryField16b;
-	type PC = PackedBinaryField8x16b;
-	type PB = PackedBinaryField128x1b;
 
 	let n_vars = 10;
 
@@ -84,3 +94,356 @@ fn test_prove_verify_interaction() {
 	let _verified_reduced_claim =
 		verify::<C, _>(&mut oracles.clone(), &claim, &lasso_batch).unwrap();
 }
+
+fn mle_column(values: Vec<F>) -> MultilinearWitness<'static, F> {
+	MultilinearExtension::from_values(values)
+		.unwrap()
+		.specialize_arc_dyn()
+}
+
+/// Check that the relations of a multiset check witness are equal as multisets, by comparing the
+/// grand products of $\gamma + T_1 + \alpha T_2 + \ldots$ at random challenges.
+fn assert_multisets_equal(witness: &MsetcheckWitness<F>, rng: &mut StdRng) {
+	let gamma = <F as Field>::random(&mut *rng);
+	let alpha = <F as Field>::random(&mut *rng);
+
+	let grand_product =
+		|polys: &[MultilinearWitness<F>]| {
+			(0..1 << witness.n_vars())
+				.map(|i| {
+					polys.iter().rev().fold(F::ZERO, |acc, poly| {
+						acc * alpha + poly.evaluate_on_hypercube(i).unwrap()
+					}) + gamma
+				})
+				.product::<F>()
+		};
+
+	assert_eq!(grand_product(witness.t_polynomials()), grand_product(witness.u_polynomials()));
+}
+
+#[test]
+fn test_batch_prove_verify_multiple_tables() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<F>::new();
+
+	// Two tables of different sizes with overlapping values
+	let all_n_vars = [6, 8];
+	let mut claims = Vec::new();
+	let mut witnesses = Vec::new();
+	let mut batches = Vec::new();
+	for (i, &n_vars) in all_n_vars.iter().enumerate() {
+		let t_values = (0..1 << n_vars)
+			.map(|x| F::new((x * (i as u128 + 1)) % 100))
+			.collect::<Vec<_>>();
+		let u_to_t_mapping = repeat_with(|| rng.gen_range(0..1 << n_vars))
+			.take(1 << n_vars)
+			.collect::<Vec<usize>>();
+		let u_values = u_to_t_mapping
+			.iter()
+			.map(|&index| t_values[index])
+			.collect::<Vec<_>>();
+
+		let lookup_batch = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 2,
+			tower_level: BinaryField8b::TOWER_LEVEL,
+		});
+		let [t_oracle, u_oracle] = [0, 1].map(|index| {
+			oracles.committed_oracle(CommittedId {
+				batch_id: lookup_batch,
+				index,
+			})
+		});
+
+		claims.push(LassoClaim::new(t_oracle, u_oracle).unwrap());
+		witnesses.push(
+			LassoWitness::new(mle_column(t_values), mle_column(u_values), u_to_t_mapping).unwrap(),
+		);
+		batches.push(LassoBatch::new_in::<C, _>(&mut oracles, n_vars));
+	}
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let mut prover_oracles = oracles.clone();
+	let LassoBatchProveOutput {
+		reduced_lasso_claims,
+		zerocheck_witnesses,
+		msetcheck_witness,
+		committed_polys,
+	} = batch_prove::<PC, PB, F, F, _>(
+		&mut prover_oracles,
+		&mut witness_index,
+		&claims,
+		witnesses,
+		&batches,
+	)
+	.unwrap();
+
+	assert_eq!(reduced_lasso_claims.zerocheck_claims.len(), 2);
+	assert_eq!(zerocheck_witnesses.len(), 2);
+	assert_eq!(committed_polys.len(), 2);
+
+	// Relations of 9 variables are padded into two blocks
+	assert_eq!(msetcheck_witness.n_vars(), 10);
+	assert_eq!(reduced_lasso_claims.msetcheck_claim.dimensions(), 3);
+	assert_multisets_equal(&msetcheck_witness, &mut rng);
+
+	for oracle in reduced_lasso_claims
+		.msetcheck_claim
+		.t_oracles()
+		.iter()
+		.chain(reduced_lasso_claims.msetcheck_claim.u_oracles())
+	{
+		assert!(witness_index.get(oracle.id()).is_some());
+	}
+
+	let mut verifier_oracles = oracles.clone();
+	let verified_claims = batch_verify::<C, _>(&mut verifier_oracles, &claims, &batches).unwrap();
+	assert_eq!(verified_claims.zerocheck_claims.len(), 2);
+	assert_eq!(
+		verified_claims
+			.msetcheck_claim
+			.t_oracles()
+			.iter()
+			.map(|oracle| oracle.id())
+			.collect::<Vec<_>>(),
+		reduced_lasso_claims
+			.msetcheck_claim
+			.t_oracles()
+			.iter()
+			.map(|oracle| oracle.id())
+			.collect::<Vec<_>>()
+	);
+
+	assert_matches!(
+		batch_verify::<C, _>(&mut oracles.clone(), &claims, &batches[..1]),
+		Err(Error::MismatchedBatchLength)
+	);
+}
+
+#[test]
+fn test_decomposed_range_check() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<F>::new();
+
+	// 32-bit values split into four 8-bit chunks, each looked up into the table of all bytes
+	let decomposition = LassoDecomposition::new(BinaryField8b::TOWER_LEVEL, 2);
+	assert_eq!(decomposition.tower_level(), BinaryField32b::TOWER_LEVEL);
+
+	let n_vars = 8;
+	let values = repeat_with(|| BinaryField32b::new(rng.gen()))
+		.take(1 << n_vars)
+		.collect::<Vec<_>>();
+	let chunks = values
+		.iter()
+		.map(|&value| decomposition.decompose::<BinaryField8b, _>(value).unwrap())
+		.collect::<Vec<_>>();
+	for (&value, value_chunks) in values.iter().zip(chunks.iter()) {
+		assert_eq!(
+			decomposition
+				.combine::<_, BinaryField32b>(value_chunks)
+				.unwrap(),
+			value
+		);
+	}
+
+	let table_batch = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: 1,
+		tower_level: BinaryField8b::TOWER_LEVEL,
+	});
+	let chunk_batch = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: decomposition.n_chunks(),
+		tower_level: BinaryField8b::TOWER_LEVEL,
+	});
+	let chunk_ids = oracles
+		.committed_oracle_ids(chunk_batch)
+		.collect::<Vec<_>>();
+	let value_id = decomposition
+		.add_combined_oracle(&mut oracles, &chunk_ids)
+		.unwrap();
+	assert_eq!(oracles.n_vars(value_id), n_vars);
+
+	let t_oracle = oracles.committed_oracle(CommittedId {
+		batch_id: table_batch,
+		index: 0,
+	});
+	let t_polynomial = mle_column((0..1 << n_vars).map(F::new).collect());
+
+	let mut claims = Vec::new();
+	let mut witnesses = Vec::new();
+	let mut batches = Vec::new();
+	for (j, &chunk_id) in chunk_ids.iter().enumerate() {
+		let chunk_values = chunks.iter().map(|chunks| chunks[j]).collect::<Vec<_>>();
+		let u_to_t_mapping = chunk_values
+			.iter()
+			.map(|&chunk| u8::from(chunk) as usize)
+			.collect::<Vec<_>>();
+		let u_polynomial = mle_column(chunk_values.into_iter().map(F::from).collect());
+
+		claims.push(LassoClaim::new(t_oracle.clone(), oracles.oracle(chunk_id)).unwrap());
+		witnesses
+			.push(LassoWitness::new(t_polynomial.clone(), u_polynomial, u_to_t_mapping).unwrap());
+		batches.push(LassoBatch::new_in::<C, _>(&mut oracles, n_vars));
+	}
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let LassoBatchProveOutput {
+		msetcheck_witness, ..
+	} = batch_prove::<PC, PB, F, F, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		&claims,
+		witnesses,
+		&batches,
+	)
+	.unwrap();
+	assert_multisets_equal(&msetcheck_witness, &mut rng);
+
+	batch_verify::<C, _>(&mut oracles.clone(), &claims, &batches).unwrap();
+}
+
+#[test]
+fn test_decomposed_bitwise_ops() {
+	type FC = BinaryField4b;
+	type FT = BinaryField16b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<F>::new();
+
+	// u32 operands and results split into eight 4-bit chunks, with the (a, b, op(a, b)) chunk
+	// tuples looked up into a subtable of 2^8 rows
+	let decomposition = LassoDecomposition::new(FC::TOWER_LEVEL, 3);
+	assert_eq!(decomposition.tower_level(), BinaryField32b::TOWER_LEVEL);
+	assert_eq!(decomposition.tuple_tower_level(3), FT::TOWER_LEVEL);
+
+	let n_vars = 8;
+	let ops: [(fn(u32, u32) -> u32, fn(&[FC]) -> FC); 2] = [
+		(|a, b| a & b, |x| FC::from(u8::from(x[0]) & u8::from(x[1]))),
+		(|a, b| a ^ b, |x| x[0] + x[1]),
+	];
+
+	let mut claims = Vec::new();
+	let mut witnesses = Vec::new();
+	let mut batches = Vec::new();
+	for (op_u32, op_chunk) in ops {
+		let operands = repeat_with(|| (rng.gen::<u32>(), rng.gen::<u32>()))
+			.take(1 << n_vars)
+			.collect::<Vec<_>>();
+		let column_chunks = [
+			operands.iter().map(|&(a, _)| a).collect::<Vec<_>>(),
+			operands.iter().map(|&(_, b)| b).collect(),
+			operands.iter().map(|&(a, b)| op_u32(a, b)).collect(),
+		]
+		.map(|column| {
+			column
+				.into_iter()
+				.map(|value| {
+					decomposition
+						.decompose::<FC, _>(BinaryField32b::new(value))
+						.unwrap()
+				})
+				.collect::<Vec<_>>()
+		});
+
+		let subtable = decomposition.subtable::<FC, FT>(2, op_chunk).unwrap();
+		assert_eq!(subtable.len(), 1 << n_vars);
+
+		let table_batch = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 1,
+			tower_level: FT::TOWER_LEVEL,
+		});
+		let chunk_batch = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: 3 * decomposition.n_chunks(),
+			tower_level: FC::TOWER_LEVEL,
+		});
+		let chunk_ids = oracles
+			.committed_oracle_ids(chunk_batch)
+			.collect::<Vec<_>>();
+		let column_chunk_ids = chunk_ids
+			.chunks(decomposition.n_chunks())
+			.map(|chunk_ids| chunk_ids.to_vec())
+			.collect::<Vec<_>>();
+		for chunk_ids in &column_chunk_ids {
+			let column_id = decomposition
+				.add_combined_oracle(&mut oracles, chunk_ids)
+				.unwrap();
+			assert_eq!(oracles.n_vars(column_id), n_vars);
+		}
+
+		let t_oracle = oracles.committed_oracle(CommittedId {
+			batch_id: table_batch,
+			index: 0,
+		});
+		let op_claims = decomposition
+			.add_chunk_claims(&mut oracles, t_oracle, &column_chunk_ids)
+			.unwrap();
+		assert_eq!(op_claims.len(), decomposition.n_chunks());
+		claims.extend(op_claims);
+
+		let t_polynomial = mle_column(subtable.iter().copied().map(F::from).collect());
+		for j in 0..decomposition.n_chunks() {
+			let tuples = (0..1 << n_vars)
+				.map(|row| column_chunks.each_ref().map(|chunks| chunks[row][j]))
+				.collect::<Vec<_>>();
+			let u_to_t_mapping = tuples
+				.iter()
+				.map(|&[a, b, _]| (u8::from(a) as usize) | ((u8::from(b) as usize) << 4))
+				.collect::<Vec<_>>();
+			let u_values = tuples
+				.iter()
+				.map(|tuple| decomposition.pack_tuple::<FC, FT>(tuple).unwrap())
+				.collect::<Vec<_>>();
+			for (&u_value, &index) in u_values.iter().zip(u_to_t_mapping.iter()) {
+				assert_eq!(u_value, subtable[index]);
+			}
+
+			let u_polynomial = mle_column(u_values.into_iter().map(F::from).collect());
+			witnesses.push(
+				LassoWitness::new(t_polynomial.clone(), u_polynomial, u_to_t_mapping).unwrap(),
+			);
+			batches.push(LassoBatch::new_in::<C, _>(&mut oracles, n_vars));
+		}
+	}
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let LassoBatchProveOutput {
+		msetcheck_witness, ..
+	} = batch_prove::<PC, PB, F, F, _>(
+		&mut oracles.clone(),
+		&mut witness_index,
+		&claims,
+		witnesses,
+		&batches,
+	)
+	.unwrap();
+	assert_multisets_equal(&msetcheck_witness, &mut rng);
+
+	batch_verify::<C, _>(&mut oracles.clone(), &claims, &batches).unwrap();
+}
+
+#[test]
+fn test_decomposition_errors() {
+	let decomposition = LassoDecomposition::new(BinaryField8b::TOWER_LEVEL, 1);
+
+	assert_matches!(
+		decomposition.decompose::<BinaryField8b, _>(BinaryField32b::new(1 << 16)),
+		Err(Error::DecompositionValueOutOfRange)
+	);
+	assert_matches!(
+		decomposition.decompose::<BinaryField16b, _>(BinaryField32b::new(1)),
+		Err(Error::DecompositionTowerLevelMismatch)
+	);
+	assert_matches!(
+		decomposition.combine::<_, BinaryField16b>(&[BinaryField8b::new(1)]),
+		Err(Error::DecompositionChunksMismatch)
+	);
+	assert_eq!(
+		decomposition
+			.decompose::<BinaryField8b, _>(BinaryField16b::new(0x1234))
+			.unwrap(),
+		vec![BinaryField8b::new(0x34), BinaryField8b::new(0x12)]
+	);
+}
@@ -2,7 +2,10 @@
 
 use super::{
 	error::Error,
-	lasso::{reduce_lasso_claim, LassoBatch, LassoClaim, LassoCount, ReducedLassoClaims},
+	lasso::{
+		combine_msetcheck_claims, reduce_lasso_claim, LassoBatch, LassoClaim, LassoCount,
+		ReducedLassoBatchClaims, ReducedLassoClaims,
+	},
 };
 use crate::oracle::MultilinearOracleSet;
 use binius_field::{BinaryField, TowerField};
@@ -22,3 +25,39 @@ pub fn verify<C: LassoCount, F: TowerField>(
 	let (reduced, _) = reduce_lasso_claim::<C, _>(oracles, claim, batch)?;
 	Ok(reduced)
 }
+
+/// Verify a batch of Lasso instance reductions, possibly into different tables.
+///
+/// See [`batch_prove`](super::batch_prove) for the combination of the multiset checks.
+///
+/// ## Throws
+///
+/// * [`Error::MismatchedBatchLength`] if the numbers of claims and batches differ
+/// * [`Error::EmptyBatch`] if there are no claims
+pub fn batch_verify<C: LassoCount, F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	claims: &[LassoClaim<F>],
+	batches: &[LassoBatch],
+) -> Result<ReducedLassoBatchClaims<F>, Error> {
+	if batches.len() != claims.len() {
+		return Err(Error::MismatchedBatchLength);
+	}
+
+	let mut zerocheck_claims = Vec::with_capacity(claims.len());
+	let mut msetcheck_claims = Vec::with_capacity(claims.len());
+	for (claim, batch) in claims.iter().zip(batches) {
+		let ReducedLassoClaims {
+			zerocheck_claim,
+			msetcheck_claim,
+		} = verify::<C, F>(oracles, claim, batch)?;
+		zerocheck_claims.push(zerocheck_claim);
+		msetcheck_claims.push(msetcheck_claim);
+	}
+
+	let (msetcheck_claim, _) = combine_msetcheck_claims(oracles, &msetcheck_claims)?;
+
+	Ok(ReducedLassoBatchClaims {
+		zerocheck_claims,
+		msetcheck_claim,
+	})
+}
@@ -0,0 +1,58 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::Error as OracleError, polynomial::Error as PolynomialError,
+	protocols::gkr_sumcheck::Error as GkrSumcheckError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("a logUp claim must have at least one witness column")]
+	EmptyWitnesses,
+	#[error("the number of variables of the table and the multiplicities do not match")]
+	NumVariablesMismatch,
+	#[error("the number of mappings must match the number of witness columns")]
+	MismatchedWitnessMappingLength,
+	#[error("provided mapping length does not conform to multilinear witnesses size")]
+	MappingSizeMismatch,
+	#[error("provided mapping indexes out of table bounds")]
+	MappingIndexOutOfBounds,
+	#[error(
+		"the weighting challenges do not match the number of variables of the witness columns"
+	)]
+	IncorrectBetasLength,
+	#[error("fraction numerators and denominators must have the same power of two length")]
+	FractionSizeMismatch,
+	#[error("prover has mismatch between claim and witness")]
+	ProverClaimWitnessMismatch,
+	#[error("oracle error: {0}")]
+	Oracle(#[from] OracleError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("gkr sumcheck failure: {0}")]
+	GkrSumcheck(#[from] GkrSumcheckError),
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the number of fraction roots must be one more than the number of witness columns")]
+	NumberOfRoots,
+	#[error("a fraction root has a zero denominator")]
+	ZeroDenominator,
+	#[error("the table fraction does not equal the sum of the witness fractions")]
+	LookupSumMismatch,
+	#[error(
+		"the number of layer proofs must match the number of variables of the largest fraction"
+	)]
+	NumberOfLayerProofs,
+	#[error("the layer 0 proof must not contain a GKR sumcheck proof")]
+	NonEmptyFirstLayerSumcheck,
+	#[error("the number of layer evaluations must match the number of fractions in the layer")]
+	NumberOfLayerEvals,
+	#[error("the layer evaluations do not add up to the layer claim")]
+	IncorrectLayerFraction,
+	#[error("the witness numerator evaluation does not match the row weights")]
+	IncorrectWeightEvaluation,
+}
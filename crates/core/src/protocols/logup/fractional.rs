@@ -0,0 +1,506 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{Error, VerificationError};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	polynomial::{
+		extrapolate_line, CompositionPoly, Error as PolynomialError, EvaluationDomain,
+		EvaluationDomainFactory, MultilinearComposite, MultilinearExtension, MultilinearQuery,
+	},
+	protocols::gkr_sumcheck::{
+		self, GkrSumcheckBatchProof, GkrSumcheckBatchProveOutput, GkrSumcheckClaim,
+		GkrSumcheckProver, GkrSumcheckWitness,
+	},
+};
+use binius_field::{ExtensionField, Field, PackedField, TowerField};
+use rayon::prelude::*;
+
+/// Composition over the halves $[p_0, p_1, q_0, q_1]$ of a fractional layer, computing the
+/// numerator $p_0 q_1 + p_1 q_0$ plus `mix` times the denominator $q_0 q_1$ of
+/// $p_0 / q_0 + p_1 / q_1$.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct FractionComposition<F> {
+	mix: F,
+}
+
+impl<P> CompositionPoly<P> for FractionComposition<P::Scalar>
+where
+	P: PackedField<Scalar: TowerField>,
+{
+	fn n_vars(&self) -> usize {
+		4
+	}
+
+	fn degree(&self) -> usize {
+		2
+	}
+
+	fn evaluate_scalar(&self, query: &[P::Scalar]) -> Result<P::Scalar, PolynomialError> {
+		if query.len() != 4 {
+			return Err(PolynomialError::IncorrectQuerySize { expected: 4 });
+		}
+
+		Ok(query[0] * query[3] + query[1] * query[2] + query[2] * query[3] * self.mix)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, PolynomialError> {
+		if query.len() != 4 {
+			return Err(PolynomialError::IncorrectQuerySize { expected: 4 });
+		}
+
+		Ok(query[0] * query[3] + query[1] * query[2] + query[2] * query[3] * self.mix)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.mix.min_tower_level()
+	}
+}
+
+/// Witness for a fractional sum $\sum_x p(x) / q(x)$, holding every layer of the fraction
+/// addition circuit.
+#[derive(Debug, Clone)]
+pub(super) struct FractionalSumWitness<FW: Field> {
+	/// Layer $k$ holds the $2^k$ hypercube evaluations of the numerator $p_k$, the last layer
+	/// being the evaluations of the input numerator.
+	numerators: Vec<Vec<FW>>,
+	/// Layer $k$ holds the $2^k$ hypercube evaluations of the denominator $q_k$.
+	denominators: Vec<Vec<FW>>,
+}
+
+impl<FW: Field> FractionalSumWitness<FW> {
+	pub fn new(numerators: Vec<FW>, denominators: Vec<FW>) -> Result<Self, Error> {
+		if numerators.len() != denominators.len() || !numerators.len().is_power_of_two() {
+			return Err(Error::FractionSizeMismatch);
+		}
+
+		let n_vars = numerators.len().trailing_zeros() as usize;
+		let mut numerator_layers = Vec::with_capacity(n_vars + 1);
+		let mut denominator_layers = Vec::with_capacity(n_vars + 1);
+		numerator_layers.push(numerators);
+		denominator_layers.push(denominators);
+
+		for layer_n_vars in (0..n_vars).rev() {
+			let prev_numerators = numerator_layers.last().expect("layers is not empty");
+			let prev_denominators = denominator_layers.last().expect("layers is not empty");
+			let (p_zero, p_one) = prev_numerators.split_at(1 << layer_n_vars);
+			let (q_zero, q_one) = prev_denominators.split_at(1 << layer_n_vars);
+
+			let (numerators, denominators) = (p_zero, p_one, q_zero, q_one)
+				.into_par_iter()
+				.map(|(&p_0, &p_1, &q_0, &q_1)| (p_0 * q_1 + p_1 * q_0, q_0 * q_1))
+				.unzip::<_, _, Vec<_>, Vec<_>>();
+			numerator_layers.push(numerators);
+			denominator_layers.push(denominators);
+		}
+		numerator_layers.reverse();
+		denominator_layers.reverse();
+
+		Ok(Self {
+			numerators: numerator_layers,
+			denominators: denominator_layers,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.numerators.len() - 1
+	}
+
+	/// The numerator and denominator of the whole fractional sum.
+	pub fn root(&self) -> (FW, FW) {
+		(self.numerators[0][0], self.denominators[0][0])
+	}
+}
+
+/// An evaluation claim on the numerator and denominator of one layer of a fractional sum circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FractionClaim<F: Field> {
+	pub eval_point: Vec<F>,
+	pub numerator_eval: F,
+	pub denominator_eval: F,
+}
+
+/// The sums that the halves of each fraction in a layer must add up to.
+#[derive(Debug, Clone)]
+enum LayerSums<F: Field> {
+	/// Separate numerator and denominator claims, on layer $0$ which has no sumcheck.
+	Fractions(Vec<FractionClaim<F>>),
+	/// Evaluations of the numerator plus `mix` times the denominator, output by the layer
+	/// sumcheck.
+	Mixed { mix: F, evals: Vec<F> },
+}
+
+/// The reduction of the fraction claims on layer $k$ to claims on layer $k + 1$.
+#[derive(Debug, Clone)]
+pub struct FractionalLayerProof<F> {
+	/// The batched GKR sumcheck over the claims on the numerator plus a random multiple of the
+	/// denominator of each fraction in the layer, empty for layer $0$.
+	pub gkr_sumcheck_batch_proof: GkrSumcheckBatchProof<F>,
+	/// Evaluations $p_{k+1}(\rho, 0)$ of the fractions in the layer.
+	pub numerator_zero_evals: Vec<F>,
+	/// Evaluations $p_{k+1}(\rho, 1)$ of the fractions in the layer.
+	pub numerator_one_evals: Vec<F>,
+	/// Evaluations $q_{k+1}(\rho, 0)$ of the fractions in the layer.
+	pub denominator_zero_evals: Vec<F>,
+	/// Evaluations $q_{k+1}(\rho, 1)$ of the fractions in the layer.
+	pub denominator_one_evals: Vec<F>,
+}
+
+/// Prove the reduction of the root claims of a batch of fractional sums to claims on their input
+/// numerators and denominators.
+///
+/// The roots must have been observed by the challenger. Returns the layer proofs along with the
+/// final claims, in the order of the witnesses.
+pub(super) fn prove_fractional_sums<F, FW, DomainField, CH>(
+	witnesses: &[FractionalSumWitness<FW>],
+	evaluation_domain_factory: impl EvaluationDomainFactory<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	mut challenger: CH,
+) -> Result<(Vec<FractionalLayerProof<F>>, Vec<FractionClaim<F>>), Error>
+where
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	// Every layer reduction is a sumcheck over a quadratic composition
+	let domain = evaluation_domain_factory.create(3)?;
+	let max_n_vars = witnesses
+		.iter()
+		.map(|witness| witness.n_vars())
+		.max()
+		.unwrap_or(0);
+
+	let mut layer_claims = witnesses
+		.iter()
+		.map(|witness| {
+			let (numerator, denominator) = witness.root();
+			FractionClaim {
+				eval_point: Vec::new(),
+				numerator_eval: numerator.into(),
+				denominator_eval: denominator.into(),
+			}
+		})
+		.collect::<Vec<_>>();
+	let mut layer_proofs = Vec::with_capacity(max_n_vars);
+
+	for layer_no in 0..max_n_vars {
+		// Fractions that have not yet reached their input layer, all sharing the same point
+		let active_indices = (0..witnesses.len())
+			.filter(|&i| witnesses[i].n_vars() > layer_no)
+			.collect::<Vec<_>>();
+		let active_witnesses = active_indices
+			.iter()
+			.map(|&i| &witnesses[i])
+			.collect::<Vec<_>>();
+		let active_claims = active_indices
+			.iter()
+			.map(|&i| layer_claims[i].clone())
+			.collect::<Vec<_>>();
+
+		let (gkr_sumcheck_batch_proof, eval_point, layer_sums) = prove_layer_gkr_sumcheck(
+			layer_no,
+			&active_witnesses,
+			active_claims,
+			&domain,
+			switchover_fn.clone(),
+			&mut challenger,
+		)?;
+
+		let query = MultilinearQuery::<FW>::with_full_query(
+			&eval_point.iter().map(|&x| x.into()).collect::<Vec<_>>(),
+		)?;
+		let evaluate_halves = |layer: &[FW]| -> Result<(F, F), Error> {
+			let (zero_half, one_half) = layer.split_at(1 << layer_no);
+			let zero_eval: FW =
+				MultilinearExtension::from_values_slice(zero_half)?.evaluate(&query)?;
+			let one_eval: FW =
+				MultilinearExtension::from_values_slice(one_half)?.evaluate(&query)?;
+			Ok((zero_eval.into(), one_eval.into()))
+		};
+		let (numerator_zero_evals, numerator_one_evals): (Vec<F>, Vec<F>) = active_witnesses
+			.iter()
+			.map(|witness| evaluate_halves(&witness.numerators[layer_no + 1]))
+			.collect::<Result<Vec<_>, Error>>()?
+			.into_iter()
+			.unzip();
+		let (denominator_zero_evals, denominator_one_evals): (Vec<F>, Vec<F>) = active_witnesses
+			.iter()
+			.map(|witness| evaluate_halves(&witness.denominators[layer_no + 1]))
+			.collect::<Result<Vec<_>, Error>>()?
+			.into_iter()
+			.unzip();
+
+		let new_layer_claims = reduce_layer_claims(
+			&eval_point,
+			&layer_sums,
+			&numerator_zero_evals,
+			&numerator_one_evals,
+			&denominator_zero_evals,
+			&denominator_one_evals,
+			&mut challenger,
+		)?;
+		for (&i, new_layer_claim) in active_indices.iter().zip(new_layer_claims) {
+			layer_claims[i] = new_layer_claim;
+		}
+
+		layer_proofs.push(FractionalLayerProof {
+			gkr_sumcheck_batch_proof,
+			numerator_zero_evals,
+			numerator_one_evals,
+			denominator_zero_evals,
+			denominator_one_evals,
+		});
+	}
+
+	Ok((layer_proofs, layer_claims))
+}
+
+/// Run the batched GKR sumcheck reducing the fraction claims on layer `layer_no` to evaluations of
+/// the mixed fraction composition of the next layer's halves.
+///
+/// Each fraction is proven with a single sumcheck over its numerator plus a random multiple of its
+/// denominator. Layer $0$ has no variables, in which case the claims are returned as they are.
+/// Returns the proof, the common evaluation point and the sums the next layer's halves must meet.
+fn prove_layer_gkr_sumcheck<F, FW, DomainField, CH>(
+	layer_no: usize,
+	witnesses: &[&FractionalSumWitness<FW>],
+	layer_claims: Vec<FractionClaim<F>>,
+	domain: &EvaluationDomain<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	mut challenger: CH,
+) -> Result<(GkrSumcheckBatchProof<F>, Vec<F>, LayerSums<F>), Error>
+where
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	if layer_no == 0 {
+		let empty_proof = GkrSumcheckBatchProof {
+			rounds: Vec::new(),
+			sorted_evals: Vec::new(),
+		};
+		return Ok((empty_proof, Vec::new(), LayerSums::Fractions(layer_claims)));
+	}
+
+	let mix: F = challenger.sample();
+	let composition = FractionComposition { mix: FW::from(mix) };
+
+	let provers = witnesses
+		.iter()
+		.zip(layer_claims)
+		.map(|(witness, layer_claim)| {
+			let (p_zero, p_one) = witness.numerators[layer_no + 1].split_at(1 << layer_no);
+			let (q_zero, q_one) = witness.denominators[layer_no + 1].split_at(1 << layer_no);
+
+			let poly = MultilinearComposite::<FW, _, _>::new(
+				layer_no,
+				composition,
+				[p_zero, p_one, q_zero, q_one]
+					.into_iter()
+					.map(|half| {
+						Ok(MultilinearExtension::from_values_slice(half)?.specialize_arc_dyn())
+					})
+					.collect::<Result<Vec<_>, PolynomialError>>()?,
+			)?;
+			let current_layer = MultilinearExtension::from_values(
+				(&witness.numerators[layer_no], &witness.denominators[layer_no])
+					.into_par_iter()
+					.map(|(&numerator, &denominator)| numerator + composition.mix * denominator)
+					.collect(),
+			)?;
+
+			let claim = GkrSumcheckClaim {
+				n_vars: layer_no,
+				degree: 2,
+				sum: layer_claim.numerator_eval + mix * layer_claim.denominator_eval,
+				r: layer_claim.eval_point.clone(),
+			};
+			let witness = GkrSumcheckWitness {
+				poly,
+				current_layer,
+			};
+
+			let prover = GkrSumcheckProver::new(
+				domain,
+				claim,
+				witness,
+				&layer_claim.eval_point,
+				switchover_fn.clone(),
+			)?;
+			Ok(prover)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let GkrSumcheckBatchProveOutput {
+		proof,
+		reduced_claims,
+	} = gkr_sumcheck::batch_prove(provers, &mut challenger)?;
+
+	let eval_point = reduced_claims[0].eval_point.clone();
+	let evals = reduced_claims.into_iter().map(|claim| claim.eval).collect();
+
+	Ok((proof, eval_point, LayerSums::Mixed { mix, evals }))
+}
+
+/// Verify the reduction of the root claims of a batch of fractional sums, given the number of
+/// variables of each fraction, to claims on their input numerators and denominators.
+///
+/// The roots must have been observed by the challenger. Returns the final claims in the order of
+/// the roots.
+pub(super) fn verify_fractional_sums<F, CH>(
+	all_n_vars: &[usize],
+	roots: Vec<FractionClaim<F>>,
+	layer_proofs: Vec<FractionalLayerProof<F>>,
+	mut challenger: CH,
+) -> Result<Vec<FractionClaim<F>>, Error>
+where
+	F: TowerField,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let max_n_vars = all_n_vars.iter().copied().max().unwrap_or(0);
+	if layer_proofs.len() != max_n_vars {
+		return Err(VerificationError::NumberOfLayerProofs.into());
+	}
+
+	let mut layer_claims = roots;
+	for (layer_no, layer_proof) in layer_proofs.into_iter().enumerate() {
+		let FractionalLayerProof {
+			gkr_sumcheck_batch_proof,
+			numerator_zero_evals,
+			numerator_one_evals,
+			denominator_zero_evals,
+			denominator_one_evals,
+		} = layer_proof;
+
+		// Fractions that have not yet reached their input layer, all sharing the same point
+		let active_indices = (0..all_n_vars.len())
+			.filter(|&i| all_n_vars[i] > layer_no)
+			.collect::<Vec<_>>();
+		let active_claims = active_indices
+			.iter()
+			.map(|&i| layer_claims[i].clone())
+			.collect::<Vec<_>>();
+
+		// Layer 0 has no variables, so there is no sumcheck to run
+		let (eval_point, layer_sums) = if layer_no == 0 {
+			let empty_proof = gkr_sumcheck_batch_proof.rounds.is_empty()
+				&& gkr_sumcheck_batch_proof.sorted_evals.is_empty();
+			if !empty_proof {
+				return Err(VerificationError::NonEmptyFirstLayerSumcheck.into());
+			}
+			(Vec::new(), LayerSums::Fractions(active_claims))
+		} else {
+			let mix: F = challenger.sample();
+			let eval_point = active_claims[0].eval_point.clone();
+			let gkr_sumcheck_claims = active_claims
+				.iter()
+				.map(|claim| GkrSumcheckClaim {
+					n_vars: layer_no,
+					degree: 2,
+					sum: claim.numerator_eval + mix * claim.denominator_eval,
+					r: eval_point.clone(),
+				})
+				.collect::<Vec<_>>();
+			let reduced_claims = gkr_sumcheck::batch_verify(
+				gkr_sumcheck_claims,
+				gkr_sumcheck_batch_proof,
+				&mut challenger,
+			)?;
+			let eval_point = reduced_claims[0].eval_point.clone();
+			let evals = reduced_claims.into_iter().map(|claim| claim.eval).collect();
+			(eval_point, LayerSums::Mixed { mix, evals })
+		};
+
+		let new_layer_claims = reduce_layer_claims(
+			&eval_point,
+			&layer_sums,
+			&numerator_zero_evals,
+			&numerator_one_evals,
+			&denominator_zero_evals,
+			&denominator_one_evals,
+			&mut challenger,
+		)?;
+		for (&i, new_layer_claim) in active_indices.iter().zip(new_layer_claims) {
+			layer_claims[i] = new_layer_claim;
+		}
+	}
+
+	Ok(layer_claims)
+}
+
+/// Reduce the fraction claims on a layer, given their evaluations at a common point, to claims on
+/// the next layer.
+///
+/// Checks that the halves of each fraction add up to its layer sums, observes the layer
+/// evaluations and samples the challenge for the new highest variable.
+fn reduce_layer_claims<F, CH>(
+	eval_point: &[F],
+	layer_sums: &LayerSums<F>,
+	numerator_zero_evals: &[F],
+	numerator_one_evals: &[F],
+	denominator_zero_evals: &[F],
+	denominator_one_evals: &[F],
+	mut challenger: CH,
+) -> Result<Vec<FractionClaim<F>>, Error>
+where
+	F: Field,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let n_claims = match layer_sums {
+		LayerSums::Fractions(claims) => claims.len(),
+		LayerSums::Mixed { evals, .. } => evals.len(),
+	};
+	if numerator_zero_evals.len() != n_claims
+		|| numerator_one_evals.len() != n_claims
+		|| denominator_zero_evals.len() != n_claims
+		|| denominator_one_evals.len() != n_claims
+	{
+		return Err(VerificationError::NumberOfLayerEvals.into());
+	}
+
+	let fractions_match = (0..n_claims).all(|i| {
+		let (p_0, p_1) = (numerator_zero_evals[i], numerator_one_evals[i]);
+		let (q_0, q_1) = (denominator_zero_evals[i], denominator_one_evals[i]);
+		let (numerator, denominator) = (p_0 * q_1 + p_1 * q_0, q_0 * q_1);
+		match layer_sums {
+			LayerSums::Fractions(claims) => {
+				numerator == claims[i].numerator_eval && denominator == claims[i].denominator_eval
+			}
+			LayerSums::Mixed { mix, evals } => numerator + *mix * denominator == evals[i],
+		}
+	});
+	if !fractions_match {
+		return Err(VerificationError::IncorrectLayerFraction.into());
+	}
+
+	challenger.observe_slice(numerator_zero_evals);
+	challenger.observe_slice(numerator_one_evals);
+	challenger.observe_slice(denominator_zero_evals);
+	challenger.observe_slice(denominator_one_evals);
+	let layer_challenge = challenger.sample();
+
+	let new_eval_point = eval_point
+		.iter()
+		.copied()
+		.chain([layer_challenge])
+		.collect::<Vec<_>>();
+
+	let layer_claims = (0..n_claims)
+		.map(|i| FractionClaim {
+			eval_point: new_eval_point.clone(),
+			numerator_eval: extrapolate_line(
+				numerator_zero_evals[i],
+				numerator_one_evals[i],
+				layer_challenge,
+			),
+			denominator_eval: extrapolate_line(
+				denominator_zero_evals[i],
+				denominator_one_evals[i],
+				layer_challenge,
+			),
+		})
+		.collect();
+
+	Ok(layer_claims)
+}
@@ -0,0 +1,253 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	fractional::{FractionClaim, FractionalLayerProof, FractionalSumWitness},
+	Error, VerificationError,
+};
+use crate::{
+	oracle::MultilinearPolyOracle,
+	polynomial::{
+		transparent::eq_ind::EqIndPartialEval, MultilinearExtension, MultilinearQuery,
+		MultivariatePoly,
+	},
+	protocols::evalcheck::EvalcheckClaim,
+	witness::MultilinearWitness,
+};
+use binius_field::{Field, TowerField};
+use getset::Getters;
+
+/// A claim that every hypercube evaluation of each witness column is contained in the table.
+#[derive(Debug, Clone, Getters)]
+pub struct LogUpClaim<F: Field> {
+	/// T polynomial - the table being "looked up"
+	#[get = "pub"]
+	table: MultilinearPolyOracle<F>,
+	/// The committed weighted multiplicities of the table entries
+	#[get = "pub"]
+	multiplicities: MultilinearPolyOracle<F>,
+	/// The columns looked up into the table, possibly of different sizes
+	#[get = "pub"]
+	witnesses: Vec<MultilinearPolyOracle<F>>,
+}
+
+impl<F: Field> LogUpClaim<F> {
+	pub fn new(
+		table: MultilinearPolyOracle<F>,
+		multiplicities: MultilinearPolyOracle<F>,
+		witnesses: Vec<MultilinearPolyOracle<F>>,
+	) -> Result<Self, Error> {
+		if table.n_vars() != multiplicities.n_vars() {
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		if witnesses.is_empty() {
+			return Err(Error::EmptyWitnesses);
+		}
+
+		Ok(Self {
+			table,
+			multiplicities,
+			witnesses,
+		})
+	}
+
+	/// Check that the weighting challenges match the witness column sizes.
+	pub(super) fn check_betas(&self, betas: &[Vec<F>]) -> Result<(), Error> {
+		let betas_match = betas.len() == self.witnesses.len()
+			&& betas
+				.iter()
+				.zip(self.witnesses.iter())
+				.all(|(beta, witness)| beta.len() == witness.n_vars());
+		if !betas_match {
+			return Err(Error::IncorrectBetasLength);
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Getters)]
+pub struct LogUpWitness<'a, FW: Field, L: AsRef<[usize]>> {
+	#[get = "pub"]
+	table: MultilinearWitness<'a, FW>,
+	#[get = "pub"]
+	witnesses: Vec<MultilinearWitness<'a, FW>>,
+	/// For each witness column, the table index of every row
+	#[get = "pub"]
+	mappings: Vec<L>,
+}
+
+impl<'a, FW: Field, L: AsRef<[usize]>> LogUpWitness<'a, FW, L> {
+	pub fn new(
+		table: MultilinearWitness<'a, FW>,
+		witnesses: Vec<MultilinearWitness<'a, FW>>,
+		mappings: Vec<L>,
+	) -> Result<Self, Error> {
+		if witnesses.len() != mappings.len() {
+			return Err(Error::MismatchedWitnessMappingLength);
+		}
+
+		for (witness, mapping) in witnesses.iter().zip(mappings.iter()) {
+			if witness.size() != mapping.as_ref().len() {
+				return Err(Error::MappingSizeMismatch);
+			}
+
+			if mapping.as_ref().iter().any(|&index| index >= table.size()) {
+				return Err(Error::MappingIndexOutOfBounds);
+			}
+		}
+
+		Ok(Self {
+			table,
+			witnesses,
+			mappings,
+		})
+	}
+
+	/// Compute the weighted multiplicities $m(i) = \sum_c \sum_{j : w_c(j) = T(i)} eq(\beta_c, j)$
+	/// of the table entries, which the prover commits to after receiving the challenges `betas`.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectBetasLength`] if `betas` do not match the witness column sizes
+	pub fn weighted_multiplicities(
+		&self,
+		betas: &[Vec<FW>],
+	) -> Result<MultilinearExtension<FW>, Error> {
+		if betas.len() != self.witnesses.len()
+			|| betas
+				.iter()
+				.zip(self.witnesses.iter())
+				.any(|(beta, witness)| beta.len() != witness.n_vars())
+		{
+			return Err(Error::IncorrectBetasLength);
+		}
+
+		let mut multiplicities = vec![FW::ZERO; self.table.size()];
+		for (beta, mapping) in betas.iter().zip(self.mappings.iter()) {
+			let weights = MultilinearQuery::<FW>::with_full_query(beta)?.into_expansion();
+			for (&index, weight) in mapping.as_ref().iter().zip(weights) {
+				multiplicities[index] += weight;
+			}
+		}
+
+		Ok(MultilinearExtension::from_values(multiplicities)?)
+	}
+
+	/// Build the fractional sum witnesses of the table fraction $m(x) / (\gamma - T(x))$ and of
+	/// the witness fractions $eq(\beta_c, x) / (\gamma - w_c(x))$, in that order.
+	pub(super) fn fractional_sum_witnesses(
+		&self,
+		betas: &[Vec<FW>],
+		gamma: FW,
+	) -> Result<Vec<FractionalSumWitness<FW>>, Error> {
+		let multiplicities = self.weighted_multiplicities(betas)?;
+		let denominators = |poly: &MultilinearWitness<'a, FW>| -> Result<Vec<FW>, Error> {
+			let mut evals = vec![FW::ZERO; poly.size()];
+			poly.subcube_evals(poly.n_vars(), 0, &mut evals)?;
+			Ok(evals.into_iter().map(|eval| gamma - eval).collect())
+		};
+
+		let mut fractions = Vec::with_capacity(1 + self.witnesses.len());
+		fractions.push(FractionalSumWitness::new(
+			multiplicities.evals().to_vec(),
+			denominators(&self.table)?,
+		)?);
+		for (beta, witness) in betas.iter().zip(self.witnesses.iter()) {
+			let weights = MultilinearQuery::<FW>::with_full_query(beta)?.into_expansion();
+			fractions.push(FractionalSumWitness::new(weights, denominators(witness)?)?);
+		}
+		Ok(fractions)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct LogUpProof<F> {
+	/// Numerators of the fractional sums, the table fraction first.
+	pub root_numerators: Vec<F>,
+	/// Denominators of the fractional sums, the table fraction first.
+	pub root_denominators: Vec<F>,
+	pub batch_layer_proofs: Vec<FractionalLayerProof<F>>,
+}
+
+#[derive(Debug)]
+pub struct LogUpProveOutput<F: Field> {
+	/// Evalcheck claims on the multiplicities, the table and the witness columns, in that order.
+	pub evalcheck_claims: Vec<EvalcheckClaim<F>>,
+	pub proof: LogUpProof<F>,
+}
+
+/// Check that the table fraction equals the sum of the witness fractions at the roots.
+pub(super) fn check_root_sums<F: Field>(
+	root_numerators: &[F],
+	root_denominators: &[F],
+	n_witnesses: usize,
+) -> Result<(), Error> {
+	if root_numerators.len() != n_witnesses + 1 || root_denominators.len() != n_witnesses + 1 {
+		return Err(VerificationError::NumberOfRoots.into());
+	}
+
+	let inverses = root_denominators
+		.iter()
+		.map(|denominator| denominator.invert())
+		.collect::<Option<Vec<_>>>()
+		.ok_or(VerificationError::ZeroDenominator)?;
+
+	let table_sum = root_numerators[0] * inverses[0];
+	let witness_sum = root_numerators[1..]
+		.iter()
+		.zip(inverses[1..].iter())
+		.map(|(&numerator, &inverse)| numerator * inverse)
+		.sum::<F>();
+	if table_sum != witness_sum {
+		return Err(VerificationError::LookupSumMismatch.into());
+	}
+	Ok(())
+}
+
+/// Convert the final fraction claims into evalcheck claims on the multiplicities, the table and
+/// the witness columns.
+///
+/// The witness numerators are the transparent row weights, which are evaluated directly.
+pub(super) fn make_evalcheck_claims<F: TowerField>(
+	claim: &LogUpClaim<F>,
+	betas: &[Vec<F>],
+	gamma: F,
+	fraction_claims: Vec<FractionClaim<F>>,
+) -> Result<Vec<EvalcheckClaim<F>>, Error> {
+	let mut fraction_claims = fraction_claims.into_iter();
+	let table_claim = fraction_claims
+		.next()
+		.expect("there is always a table fraction");
+
+	let mut evalcheck_claims = Vec::with_capacity(2 + claim.witnesses.len());
+	evalcheck_claims.push(EvalcheckClaim {
+		poly: claim.multiplicities.clone().into_composite(),
+		eval_point: table_claim.eval_point.clone(),
+		eval: table_claim.numerator_eval,
+		is_random_point: true,
+	});
+	evalcheck_claims.push(EvalcheckClaim {
+		poly: claim.table.clone().into_composite(),
+		eval_point: table_claim.eval_point,
+		eval: gamma - table_claim.denominator_eval,
+		is_random_point: true,
+	});
+
+	for ((witness, beta), fraction_claim) in claim.witnesses.iter().zip(betas).zip(fraction_claims)
+	{
+		let weight = EqIndPartialEval::new(witness.n_vars(), beta.clone())?
+			.evaluate(&fraction_claim.eval_point)?;
+		if weight != fraction_claim.numerator_eval {
+			return Err(VerificationError::IncorrectWeightEvaluation.into());
+		}
+
+		evalcheck_claims.push(EvalcheckClaim {
+			poly: witness.clone().into_composite(),
+			eval_point: fraction_claim.eval_point,
+			eval: gamma - fraction_claim.denominator_eval,
+			is_random_point: true,
+		});
+	}
+
+	Ok(evalcheck_claims)
+}
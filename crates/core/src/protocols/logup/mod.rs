@@ -0,0 +1,48 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! logUp lookup via a fractional sumcheck.
+//!
+//! A logUp claim states that every hypercube evaluation of the witness columns $w_0, \ldots,
+//! w_{C-1}$ is contained in the set of hypercube evaluations of the table $T$. The protocol is
+//! based on the logarithmic derivative identity of [Hab22], proven with the GKR-based fractional
+//! sumcheck of [PH23].
+//!
+//! In characteristic 2 the identity $\sum_c \sum_x \frac{1}{\gamma - w_c(x)} =
+//! \sum_x \frac{m(x)}{\gamma - T(x)}$ only determines the multiplicities $m$ modulo 2, so it is
+//! unsound on its own. Instead, every row $j$ of column $c$ is weighted by $eq(\beta_c, j)$ for
+//! challenges $\beta_c$ sampled after the table and witness columns are committed, and the
+//! prover commits to the weighted multiplicities
+//!
+//! $$m(i) = \sum_c \sum_{j : w_c(j) = T(i)} eq(\beta_c, j)$$
+//!
+//! before $\gamma$ is sampled. The rational identity
+//!
+//! $$\sum_x \frac{m(x)}{\gamma - T(x)} = \sum_c \sum_x \frac{eq(\beta_c, x)}{\gamma - w_c(x)}$$
+//!
+//! then holds with high probability only if every witness value occurs in the table.
+//!
+//! Each side is a sum of fractions $p(x) / q(x)$, computed by a layered circuit where
+//! $p_k(x) = p_{k+1}(x, 0) q_{k+1}(x, 1) + p_{k+1}(x, 1) q_{k+1}(x, 0)$ and
+//! $q_k(x) = q_{k+1}(x, 0) q_{k+1}(x, 1)$. The prover sends the roots $p_0, q_0$ of all
+//! fractions, and claims on each layer are reduced to claims on the next one with a batched GKR
+//! sumcheck, as in the [GKR grand product argument](crate::protocols::gkr_gpa). The final claims
+//! on the numerator of the witness fractions are checked directly, while the remaining ones
+//! become evalcheck claims on $m$, $T$ and $w_c$.
+//!
+//! [Hab22]: <https://eprint.iacr.org/2022/1530>
+//! [PH23]: <https://eprint.iacr.org/2023/1284>
+
+mod error;
+mod fractional;
+#[allow(clippy::module_inception)]
+mod logup;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use error::*;
+pub use fractional::FractionalLayerProof;
+pub use logup::{LogUpClaim, LogUpProof, LogUpProveOutput, LogUpWitness};
+pub use prove::*;
+pub use verify::*;
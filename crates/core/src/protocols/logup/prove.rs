@@ -0,0 +1,89 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	fractional::prove_fractional_sums,
+	logup::{check_root_sums, make_evalcheck_claims, LogUpClaim, LogUpProof, LogUpProveOutput},
+	Error, LogUpWitness,
+};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	polynomial::EvaluationDomainFactory,
+};
+use binius_field::{ExtensionField, Field, TowerField};
+use tracing::instrument;
+
+/// Prove a logUp lookup claim.
+///
+/// The weighting challenges `betas`, one per witness column, must be sampled after the table and
+/// witness columns are committed, and `gamma` after the weighted multiplicities computed by
+/// [`LogUpWitness::weighted_multiplicities`] are committed. See module documentation for details.
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectBetasLength`] if `betas` do not match the witness column sizes
+/// * [`Error::ProverClaimWitnessMismatch`] if the witness does not match the claim
+/// * [`Error::Verification`] if the witness columns are not contained in the table
+#[instrument(skip_all, name = "logup::prove")]
+pub fn prove<'a, F, FW, DomainField, L, CH>(
+	claim: &LogUpClaim<F>,
+	witness: &LogUpWitness<'a, FW, L>,
+	betas: &[Vec<F>],
+	gamma: F,
+	evaluation_domain_factory: impl EvaluationDomainFactory<DomainField>,
+	switchover_fn: impl Fn(usize) -> usize + Clone,
+	mut challenger: CH,
+) -> Result<LogUpProveOutput<F>, Error>
+where
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	L: AsRef<[usize]>,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	claim.check_betas(betas)?;
+
+	let witness_claim_mismatch = witness.table().n_vars() != claim.table().n_vars()
+		|| witness.witnesses().len() != claim.witnesses().len()
+		|| witness
+			.witnesses()
+			.iter()
+			.zip(claim.witnesses().iter())
+			.any(|(witness, oracle)| witness.n_vars() != oracle.n_vars());
+	if witness_claim_mismatch {
+		return Err(Error::ProverClaimWitnessMismatch);
+	}
+
+	let betas_fw = betas
+		.iter()
+		.map(|beta| beta.iter().map(|&x| x.into()).collect::<Vec<FW>>())
+		.collect::<Vec<_>>();
+	let fraction_witnesses = witness.fractional_sum_witnesses(&betas_fw, gamma.into())?;
+
+	let (root_numerators, root_denominators): (Vec<F>, Vec<F>) = fraction_witnesses
+		.iter()
+		.map(|fraction| {
+			let (numerator, denominator) = fraction.root();
+			(F::from(numerator), F::from(denominator))
+		})
+		.unzip();
+	check_root_sums(&root_numerators, &root_denominators, claim.witnesses().len())?;
+
+	challenger.observe_slice(&root_numerators);
+	challenger.observe_slice(&root_denominators);
+
+	let (batch_layer_proofs, fraction_claims) = prove_fractional_sums(
+		&fraction_witnesses,
+		evaluation_domain_factory,
+		switchover_fn,
+		&mut challenger,
+	)?;
+
+	Ok(LogUpProveOutput {
+		evalcheck_claims: make_evalcheck_claims(claim, betas, gamma, fraction_claims)?,
+		proof: LogUpProof {
+			root_numerators,
+			root_denominators,
+			batch_layer_proofs,
+		},
+	})
+}
@@ -0,0 +1,241 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	prove, verify, Error, LogUpClaim, LogUpProof, LogUpProveOutput, LogUpWitness, VerificationError,
+};
+use crate::{
+	challenger::HashChallenger,
+	oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet},
+	polynomial::{IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery},
+	protocols::evalcheck::EvalcheckClaim,
+	witness::MultilinearWitness,
+};
+use assert_matches::assert_matches;
+use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b, Field, TowerField};
+use binius_hash::GroestlHasher;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::iter::repeat_with;
+
+type F = BinaryField32b;
+type FE = BinaryField128b;
+
+const TABLE_N_VARS: usize = 4;
+
+struct LookupInstance {
+	claim: LogUpClaim<FE>,
+	witness: LogUpWitness<'static, FE, Vec<usize>>,
+	betas: Vec<Vec<FE>>,
+	gamma: FE,
+}
+
+fn multilinear(values: Vec<FE>) -> MultilinearWitness<'static, FE> {
+	MultilinearExtension::from_values(values)
+		.unwrap()
+		.specialize_arc_dyn()
+}
+
+/// Create a lookup of random table rows into a table of distinct values, with one witness column
+/// per entry of `all_n_vars`.
+fn create_instance(rng: &mut StdRng, all_n_vars: &[usize]) -> LookupInstance {
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+
+	let table_values = (1..=1 << TABLE_N_VARS)
+		.map(|i| FE::from(F::new(i * 0x1234567)))
+		.collect::<Vec<_>>();
+	let mappings = all_n_vars
+		.iter()
+		.map(|&n_vars| {
+			repeat_with(|| rng.gen_range(0..1 << TABLE_N_VARS))
+				.take(1 << n_vars)
+				.collect::<Vec<usize>>()
+		})
+		.collect::<Vec<_>>();
+	let witness_polys = mappings
+		.iter()
+		.map(|mapping| multilinear(mapping.iter().map(|&i| table_values[i]).collect()))
+		.collect::<Vec<_>>();
+
+	let table_batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars: TABLE_N_VARS,
+		n_polys: 2,
+		tower_level: F::TOWER_LEVEL,
+	});
+	let witness_oracles = all_n_vars
+		.iter()
+		.map(|&n_vars| {
+			let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+				n_vars,
+				n_polys: 1,
+				tower_level: F::TOWER_LEVEL,
+			});
+			oracles.committed_oracle(CommittedId { batch_id, index: 0 })
+		})
+		.collect();
+	let claim = LogUpClaim::new(
+		oracles.committed_oracle(CommittedId {
+			batch_id: table_batch_id,
+			index: 0,
+		}),
+		oracles.committed_oracle(CommittedId {
+			batch_id: table_batch_id,
+			index: 1,
+		}),
+		witness_oracles,
+	)
+	.unwrap();
+
+	let witness = LogUpWitness::new(multilinear(table_values), witness_polys, mappings).unwrap();
+	let betas = all_n_vars
+		.iter()
+		.map(|&n_vars| {
+			repeat_with(|| <FE as Field>::random(&mut *rng))
+				.take(n_vars)
+				.collect()
+		})
+		.collect();
+	let gamma = <FE as Field>::random(&mut *rng);
+
+	LookupInstance {
+		claim,
+		witness,
+		betas,
+		gamma,
+	}
+}
+
+fn prove_helper(instance: &LookupInstance) -> Result<LogUpProveOutput<FE>, Error> {
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	prove::<_, _, BinaryField8b, _, _>(
+		&instance.claim,
+		&instance.witness,
+		&instance.betas,
+		instance.gamma,
+		domain_factory,
+		|_| 1,
+		challenger,
+	)
+}
+
+fn verify_helper(
+	instance: &LookupInstance,
+	proof: LogUpProof<FE>,
+) -> Result<Vec<EvalcheckClaim<FE>>, Error> {
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	verify(&instance.claim, &instance.betas, instance.gamma, proof, challenger)
+}
+
+#[test]
+fn test_weighted_multiplicities() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let instance = create_instance(&mut rng, &[3]);
+
+	let multiplicities = instance
+		.witness
+		.weighted_multiplicities(&instance.betas)
+		.unwrap();
+
+	// The weights of every column sum to one, so the multiplicities do too
+	let total = multiplicities.evals().iter().copied().sum::<FE>();
+	assert_eq!(total, FE::ONE + FE::ONE);
+
+	assert_matches!(
+		instance
+			.witness
+			.weighted_multiplicities(&instance.betas[..1]),
+		Err(Error::IncorrectBetasLength)
+	);
+}
+
+#[test]
+fn test_prove_verify() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let all_n_vars = [5, 0, 3, 6];
+	let instance = create_instance(&mut rng, &all_n_vars);
+
+	let LogUpProveOutput {
+		evalcheck_claims,
+		proof,
+	} = prove_helper(&instance).unwrap();
+	assert_eq!(proof.batch_layer_proofs.len(), 6);
+
+	let verified_evalcheck_claims = verify_helper(&instance, proof).unwrap();
+	assert_eq!(verified_evalcheck_claims.len(), 2 + all_n_vars.len());
+
+	let multiplicities = instance
+		.witness
+		.weighted_multiplicities(&instance.betas)
+		.unwrap()
+		.specialize_arc_dyn();
+	let polys = [multiplicities, instance.witness.table().clone()]
+		.into_iter()
+		.chain(instance.witness.witnesses().iter().cloned());
+
+	for ((prover_claim, verifier_claim), poly) in evalcheck_claims
+		.iter()
+		.zip(verified_evalcheck_claims.iter())
+		.zip(polys)
+	{
+		assert_eq!(prover_claim.eval_point, verifier_claim.eval_point);
+		assert_eq!(prover_claim.eval, verifier_claim.eval);
+		assert_eq!(verifier_claim.eval_point.len(), poly.n_vars());
+
+		let query = MultilinearQuery::with_full_query(&verifier_claim.eval_point).unwrap();
+		assert_eq!(poly.evaluate(&query).unwrap(), verifier_claim.eval);
+	}
+}
+
+#[test]
+fn test_prove_rejects_missing_value() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut instance = create_instance(&mut rng, &[3]);
+
+	// Replace a witness value with one that is not in the table, keeping the mapping
+	let mut values = vec![FE::ZERO; 1 << 3];
+	instance.witness.witnesses()[0]
+		.subcube_evals(3, 0, &mut values)
+		.unwrap();
+	values[2] = FE::ZERO;
+	instance.witness = LogUpWitness::new(
+		instance.witness.table().clone(),
+		vec![multilinear(values)],
+		instance.witness.mappings().clone(),
+	)
+	.unwrap();
+
+	assert_matches!(
+		prove_helper(&instance),
+		Err(Error::Verification(VerificationError::LookupSumMismatch))
+	);
+}
+
+#[test]
+fn test_verify_rejects_incorrect_layer() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let instance = create_instance(&mut rng, &[4, 2]);
+
+	let LogUpProveOutput { mut proof, .. } = prove_helper(&instance).unwrap();
+	proof.batch_layer_proofs[1].numerator_zero_evals[0] += FE::ONE;
+
+	assert_matches!(
+		verify_helper(&instance, proof),
+		Err(Error::Verification(VerificationError::IncorrectLayerFraction))
+	);
+}
+
+#[test]
+fn test_verify_rejects_first_layer_sumcheck() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let instance = create_instance(&mut rng, &[4, 2]);
+
+	let LogUpProveOutput { mut proof, .. } = prove_helper(&instance).unwrap();
+	proof.batch_layer_proofs[0]
+		.gkr_sumcheck_batch_proof
+		.sorted_evals
+		.push(FE::ONE);
+
+	assert_matches!(
+		verify_helper(&instance, proof),
+		Err(Error::Verification(VerificationError::NonEmptyFirstLayerSumcheck))
+	);
+}
@@ -0,0 +1,68 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	fractional::{verify_fractional_sums, FractionClaim},
+	logup::{check_root_sums, make_evalcheck_claims, LogUpClaim, LogUpProof},
+	Error,
+};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	protocols::evalcheck::EvalcheckClaim,
+};
+use binius_field::TowerField;
+use std::iter;
+use tracing::instrument;
+
+/// Verify a logUp lookup claim.
+///
+/// Returns evalcheck claims on the multiplicities, the table and the witness columns, in that
+/// order.
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectBetasLength`] if `betas` do not match the witness column sizes
+/// * [`Error::Verification`] if the root fractions do not match, a layer reduction fails or a
+///   witness numerator evaluation is not the row weight
+#[instrument(skip_all, name = "logup::verify")]
+pub fn verify<F, CH>(
+	claim: &LogUpClaim<F>,
+	betas: &[Vec<F>],
+	gamma: F,
+	proof: LogUpProof<F>,
+	mut challenger: CH,
+) -> Result<Vec<EvalcheckClaim<F>>, Error>
+where
+	F: TowerField,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	claim.check_betas(betas)?;
+
+	let LogUpProof {
+		root_numerators,
+		root_denominators,
+		batch_layer_proofs,
+	} = proof;
+	check_root_sums(&root_numerators, &root_denominators, claim.witnesses().len())?;
+
+	challenger.observe_slice(&root_numerators);
+	challenger.observe_slice(&root_denominators);
+
+	let all_n_vars = iter::once(claim.table())
+		.chain(claim.witnesses().iter())
+		.map(|oracle| oracle.n_vars())
+		.collect::<Vec<_>>();
+	let roots = root_numerators
+		.into_iter()
+		.zip(root_denominators)
+		.map(|(numerator_eval, denominator_eval)| FractionClaim {
+			eval_point: Vec::new(),
+			numerator_eval,
+			denominator_eval,
+		})
+		.collect();
+
+	let fraction_claims =
+		verify_fractional_sums(&all_n_vars, roots, batch_layer_proofs, &mut challenger)?;
+
+	make_evalcheck_claims(claim, betas, gamma, fraction_claims)
+}
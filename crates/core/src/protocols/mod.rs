@@ -28,11 +28,15 @@
 //! [DP23]: https://eprint.iacr.org/2023/1784
 
 pub mod abstract_sumcheck;
+pub mod channel;
 pub mod evalcheck;
+pub mod gkr_gpa;
 pub mod gkr_sumcheck;
 pub mod greedy_evalcheck;
 pub mod lasso;
+pub mod logup;
 pub mod msetcheck;
+pub mod permcheck;
 pub mod prodcheck;
 pub mod sumcheck;
 #[allow(dead_code)]
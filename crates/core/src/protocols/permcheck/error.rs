@@ -0,0 +1,38 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use crate::{
+	oracle::Error as OracleError, polynomial::Error as PolynomialError,
+	protocols::gkr_gpa::Error as GkrGpaError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("a permutation check must have at least one column")]
+	EmptyColumns,
+	#[error("the number of variables in some multilinear oracle is not equal to the rest")]
+	NumVariablesMismatch,
+	#[error("sigma is not a permutation of the cells of the columns")]
+	InvalidPermutation,
+	#[error("the cell ({column}, {row}) is out of range")]
+	CellOutOfRange { column: usize, row: usize },
+	#[error("the number of witness columns does not match the claim")]
+	WitnessNumColumnsMismatch,
+	#[error("the number of variables in some witness multilinear does not match the claim")]
+	WitnessNumVariablesMismatch,
+	#[error("oracle error: {0}")]
+	Oracle(#[from] OracleError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("grand product error: {0}")]
+	GrandProduct(#[from] GkrGpaError),
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the number of grand products must match the number of columns")]
+	NumberOfProducts,
+	#[error("the grand products of the identity and sigma sides differ")]
+	ProductMismatch,
+}
@@ -0,0 +1,27 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! The permutation check polynomial protocol.
+//!
+//! Permutation check proves that the hypercube evaluations of a set of columns are invariant
+//! under a wiring permutation of their cells, which expresses copy constraints between arbitrary
+//! cells of the columns. The wiring is encoded into transparent identity and sigma polynomials,
+//! and the claim is reduced to grand product claims proven with the GKR grand product argument
+//! (see [`prove`](self::prove::prove()) for an in-depth description).
+//!
+//! The sigma polynomials are multilinear extensions of the wiring, so the verifier evaluates them
+//! in time linear in the total number of cells. The identity polynomials are succinct.
+
+mod error;
+#[allow(clippy::module_inception)]
+mod permcheck;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use error::*;
+pub use permcheck::{
+	CopyConstraints, PermcheckClaim, PermcheckProof, PermcheckProveOutput, PermcheckWitness,
+};
+pub use prove::*;
+pub use verify::*;
@@ -0,0 +1,264 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{Error, VerificationError};
+use crate::{
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle},
+	polynomial::{
+		transparent::{
+			identity::{encode_index, Identity},
+			multilinear_extension::MultilinearExtensionTransparent,
+		},
+		MultilinearExtension,
+	},
+	protocols::gkr_gpa::{GrandProductClaim, GrandProductWitness},
+	witness::MultilinearWitness,
+};
+use binius_field::{Field, TowerField};
+use getset::{CopyGetters, Getters};
+use std::mem;
+
+/// Copy constraints between the cells of a set of equally sized columns.
+///
+/// Cell $(c, r)$ of $2^{n\_vars}$-row columns has the index $2^{n\_vars} c + r$. Connected cells
+/// form the cycles of the wiring permutation $\sigma$ on cell indices, so that the values of the
+/// columns are invariant under $\sigma$ iff all connected cells are equal.
+#[derive(Debug, Clone, CopyGetters)]
+pub struct CopyConstraints {
+	#[get_copy = "pub"]
+	n_columns: usize,
+	#[get_copy = "pub"]
+	n_vars: usize,
+	sigma: Vec<usize>,
+	/// Union-find forest of the cycles of `sigma`
+	parents: Vec<usize>,
+}
+
+impl CopyConstraints {
+	/// Copy constraints without any connected cells, ie. with the identity wiring.
+	pub fn new(n_columns: usize, n_vars: usize) -> Self {
+		let n_cells = n_columns << n_vars;
+		Self {
+			n_columns,
+			n_vars,
+			sigma: (0..n_cells).collect(),
+			parents: (0..n_cells).collect(),
+		}
+	}
+
+	/// Constrain the cells `a` and `b`, given as `(column, row)` pairs, to be equal.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::CellOutOfRange`] if either cell is not in the columns
+	pub fn connect(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), Error> {
+		let a = self.cell_index(a)?;
+		let b = self.cell_index(b)?;
+
+		let (root_a, root_b) = (self.find(a), self.find(b));
+		if root_a != root_b {
+			// Swapping the successors of two cells in different cycles merges the cycles
+			self.sigma.swap(a, b);
+			self.parents[root_a] = root_b;
+		}
+		Ok(())
+	}
+
+	/// The wiring permutation on cell indices.
+	pub fn sigma(&self) -> &[usize] {
+		&self.sigma
+	}
+
+	pub fn into_sigma(self) -> Vec<usize> {
+		self.sigma
+	}
+
+	fn cell_index(&self, (column, row): (usize, usize)) -> Result<usize, Error> {
+		if column >= self.n_columns || row >= 1 << self.n_vars {
+			return Err(Error::CellOutOfRange { column, row });
+		}
+		Ok((column << self.n_vars) | row)
+	}
+
+	fn find(&mut self, mut index: usize) -> usize {
+		while self.parents[index] != index {
+			self.parents[index] = self.parents[self.parents[index]];
+			index = self.parents[index];
+		}
+		index
+	}
+}
+
+/// A claim that the hypercube evaluations of the columns are invariant under the wiring
+/// permutation $\sigma$ of their cells.
+#[derive(Debug, Clone, Getters)]
+pub struct PermcheckClaim<F: Field> {
+	#[get = "pub"]
+	columns: Vec<MultilinearPolyOracle<F>>,
+	/// Transparent identity polynomials $id_c$ of the columns
+	#[get = "pub"]
+	identity_oracles: Vec<MultilinearPolyOracle<F>>,
+	/// Transparent polynomials $\sigma_c$ of the columns, evaluating to the encoded $\sigma$
+	/// images of their cells
+	#[get = "pub"]
+	sigma_oracles: Vec<MultilinearPolyOracle<F>>,
+	/// The wiring permutation on cell indices
+	#[get = "pub"]
+	sigma: Vec<usize>,
+}
+
+impl<F: TowerField> PermcheckClaim<F> {
+	/// Add the transparent identity and sigma oracles of the wiring `sigma` to the oracle set and
+	/// construct the claim.
+	///
+	/// Every sigma oracle is a [`MultilinearExtensionTransparent`] holding the encoded $\sigma$
+	/// images of the cells of its column. Evaluating it takes time linear in the number of rows,
+	/// so the cost of verifying the claim is linear in the total number of cells, unlike the
+	/// succinct identity oracles.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::EmptyColumns`] if there are no columns
+	/// * [`Error::NumVariablesMismatch`] if the columns have different numbers of variables
+	/// * [`Error::InvalidPermutation`] if `sigma` is not a permutation of the cells
+	pub fn new_in(
+		oracles: &mut MultilinearOracleSet<F>,
+		columns: Vec<MultilinearPolyOracle<F>>,
+		sigma: Vec<usize>,
+	) -> Result<Self, Error> {
+		let n_vars = columns.first().ok_or(Error::EmptyColumns)?.n_vars();
+		if columns.iter().any(|column| column.n_vars() != n_vars) {
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		let n_cells = columns.len() << n_vars;
+		if sigma.len() != n_cells || !is_permutation(&sigma) {
+			return Err(Error::InvalidPermutation);
+		}
+
+		let mut identity_oracles = Vec::with_capacity(columns.len());
+		let mut sigma_oracles = Vec::with_capacity(columns.len());
+		for (column_index, column_sigma) in sigma.chunks(1 << n_vars).enumerate() {
+			let identity_id = oracles.add_transparent(Identity::new(n_vars, column_index))?;
+
+			let sigma_values = column_sigma
+				.iter()
+				.map(|&index| encode_index::<F>(index))
+				.collect::<Result<Vec<_>, _>>()?;
+			let sigma_poly = MultilinearExtension::from_values(sigma_values)?.specialize::<F>();
+			let sigma_id = oracles.add_transparent(MultilinearExtensionTransparent(sigma_poly))?;
+
+			identity_oracles.push(oracles.oracle(identity_id));
+			sigma_oracles.push(oracles.oracle(sigma_id));
+		}
+
+		Ok(Self {
+			columns,
+			identity_oracles,
+			sigma_oracles,
+			sigma,
+		})
+	}
+
+	pub fn n_vars(&self) -> usize {
+		self.columns[0].n_vars()
+	}
+}
+
+#[derive(Debug, Getters)]
+pub struct PermcheckWitness<'a, FW: Field> {
+	#[get = "pub"]
+	columns: Vec<MultilinearWitness<'a, FW>>,
+}
+
+impl<'a, FW: Field> PermcheckWitness<'a, FW> {
+	pub fn new(columns: Vec<MultilinearWitness<'a, FW>>) -> Result<Self, Error> {
+		let n_vars = columns.first().ok_or(Error::EmptyColumns)?.n_vars();
+		if columns.iter().any(|column| column.n_vars() != n_vars) {
+			return Err(Error::NumVariablesMismatch);
+		}
+
+		Ok(Self { columns })
+	}
+}
+
+/// The grand products sent by the prover, which must be observed by the challenger before
+/// proving the grand product claims.
+#[derive(Debug, Clone)]
+pub struct PermcheckProof<F> {
+	/// Products of $\gamma + w_c + \alpha \cdot id_c$ over the hypercube, for every column $c$
+	pub identity_products: Vec<F>,
+	/// Products of $\gamma + w_c + \alpha \cdot \sigma_c$ over the hypercube, for every column $c$
+	pub sigma_products: Vec<F>,
+}
+
+#[derive(Debug)]
+pub struct PermcheckProveOutput<F: Field, FW: Field> {
+	pub proof: PermcheckProof<F>,
+	/// Grand product claims on the identity sides of the columns followed by the sigma sides
+	pub grand_product_claims: Vec<GrandProductClaim<F>>,
+	pub grand_product_witnesses: Vec<GrandProductWitness<FW>>,
+}
+
+/// Add the linear combination oracles $\gamma + w_c + \alpha \cdot id_c$ and
+/// $\gamma + w_c + \alpha \cdot \sigma_c$ of every column $c$.
+pub(super) fn reduce_permcheck_claim<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	claim: &PermcheckClaim<F>,
+	gamma: F,
+	alpha: F,
+) -> Result<[Vec<MultilinearPolyOracle<F>>; 2], Error> {
+	let n_vars = claim.n_vars();
+	let mut lincom_oracles = |permuted: &[MultilinearPolyOracle<F>]| {
+		claim
+			.columns
+			.iter()
+			.zip(permuted)
+			.map(|(column, permuted)| -> Result<_, Error> {
+				let id = oracles.add_linear_combination_with_offset(
+					n_vars,
+					gamma,
+					[(column.id(), F::ONE), (permuted.id(), alpha)],
+				)?;
+				Ok(oracles.oracle(id))
+			})
+			.collect::<Result<Vec<_>, _>>()
+	};
+
+	let identity_lincoms = lincom_oracles(&claim.identity_oracles)?;
+	let sigma_lincoms = lincom_oracles(&claim.sigma_oracles)?;
+	Ok([identity_lincoms, sigma_lincoms])
+}
+
+/// Check the grand products and make the grand product claims on the linear combinations.
+pub(super) fn make_grand_product_claims<F: TowerField>(
+	lincom_oracles: [Vec<MultilinearPolyOracle<F>>; 2],
+	proof: &PermcheckProof<F>,
+) -> Result<Vec<GrandProductClaim<F>>, Error> {
+	let [identity_lincoms, sigma_lincoms] = lincom_oracles;
+	if proof.identity_products.len() != identity_lincoms.len()
+		|| proof.sigma_products.len() != sigma_lincoms.len()
+	{
+		return Err(VerificationError::NumberOfProducts.into());
+	}
+
+	let identity_product = proof.identity_products.iter().product::<F>();
+	let sigma_product = proof.sigma_products.iter().product::<F>();
+	if identity_product != sigma_product {
+		return Err(VerificationError::ProductMismatch.into());
+	}
+
+	let claims = identity_lincoms
+		.into_iter()
+		.zip(proof.identity_products.iter())
+		.chain(sigma_lincoms.into_iter().zip(proof.sigma_products.iter()))
+		.map(|(poly, &product)| GrandProductClaim { poly, product })
+		.collect();
+	Ok(claims)
+}
+
+fn is_permutation(sigma: &[usize]) -> bool {
+	let mut seen = vec![false; sigma.len()];
+	sigma
+		.iter()
+		.all(|&index| index < seen.len() && !mem::replace(&mut seen[index], true))
+}
@@ -0,0 +1,144 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	error::Error,
+	permcheck::{
+		make_grand_product_claims, reduce_permcheck_claim, PermcheckClaim, PermcheckProof,
+		PermcheckProveOutput, PermcheckWitness,
+	},
+};
+use crate::{
+	oracle::MultilinearOracleSet,
+	polynomial::{
+		transparent::identity::encode_index, Error as PolynomialError, MultilinearExtension,
+	},
+	protocols::gkr_gpa::GrandProductWitness,
+	witness::{MultilinearWitness, MultilinearWitnessIndex},
+};
+use binius_field::TowerField;
+use rayon::prelude::*;
+use tracing::instrument;
+
+/// Prove a permutation check instance reduction.
+///
+/// Given columns $w_0, \ldots, w_{k-1}$ of $\nu$-variate multilins and a wiring permutation
+/// $\sigma$ on their $k 2^{\nu}$ cells, this protocol reduces the claim that the columns are
+/// invariant under $\sigma$ to a check that the grand products of the $2k$ polynomials
+///
+/// 1) $T'_c(x) = \gamma + w_c(x) + \alpha \cdot id_c(x)$
+/// 2) $U'_c(x) = \gamma + w_c(x) + \alpha \cdot \sigma_c(x)$
+///
+/// satisfy $\prod_c \prod_x T'_c(x) = \prod_c \prod_x U'_c(x)$, where $id_c$ and $\sigma_c$ are
+/// the transparent polynomials encoding the cell indices and their $\sigma$ images, and $\gamma$
+/// and $\alpha$ are large field challenges sampled via Fiat-Shamir after the columns are
+/// committed.
+///
+/// The grand products in the returned proof must be observed by the challenger before the
+/// returned grand product claims are proven with [`gkr_gpa::batch_prove`].
+///
+/// ## Throws
+///
+/// * [`Error::WitnessNumColumnsMismatch`] if the witness columns do not match the claim
+/// * [`Error::WitnessNumVariablesMismatch`] if the witness columns do not match the claim
+/// * [`Error::Verification`] if the witness columns are not invariant under the wiring
+///
+/// [`gkr_gpa::batch_prove`]: crate::protocols::gkr_gpa::batch_prove
+#[instrument(skip_all, name = "permcheck::prove")]
+pub fn prove<'a, F, FW>(
+	oracles: &mut MultilinearOracleSet<F>,
+	witness_index: &mut MultilinearWitnessIndex<'a, FW>,
+	claim: &PermcheckClaim<F>,
+	witness: PermcheckWitness<'a, FW>,
+	gamma: F,
+	alpha: F,
+) -> Result<PermcheckProveOutput<F, FW>, Error>
+where
+	F: TowerField + From<FW>,
+	FW: TowerField + From<F>,
+{
+	let n_vars = claim.n_vars();
+	if witness.columns().len() != claim.columns().len() {
+		return Err(Error::WitnessNumColumnsMismatch);
+	}
+	if witness
+		.columns()
+		.iter()
+		.any(|column| column.n_vars() != n_vars)
+	{
+		return Err(Error::WitnessNumVariablesMismatch);
+	}
+
+	let lincom_oracles = reduce_permcheck_claim(oracles, claim, gamma, alpha)?;
+
+	let encoded_indices = |indices: &[usize]| -> Result<Vec<FW>, Error> {
+		indices
+			.par_iter()
+			.map(|&index| -> Result<_, Error> { Ok(FW::from(encode_index::<F>(index)?)) })
+			.collect()
+	};
+
+	let lincom_witness = |column: &MultilinearWitness<'a, FW>,
+	                      permuted_values: &[FW]|
+	 -> Result<MultilinearWitness<'a, FW>, Error> {
+		let fw_gamma = FW::from(gamma);
+		let fw_alpha = FW::from(alpha);
+		let values = permuted_values
+			.par_iter()
+			.enumerate()
+			.map(|(i, &permuted)| -> Result<_, PolynomialError> {
+				Ok(fw_gamma + column.evaluate_on_hypercube(i)? + fw_alpha * permuted)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(MultilinearExtension::from_values(values)?.specialize_arc_dyn())
+	};
+
+	let mut identity_witnesses = Vec::with_capacity(claim.columns().len());
+	let mut sigma_witnesses = Vec::with_capacity(claim.columns().len());
+	for (column_index, column) in witness.columns().iter().enumerate() {
+		let cells = column_index << n_vars..(column_index + 1) << n_vars;
+
+		let identity_values = encoded_indices(&cells.clone().collect::<Vec<_>>())?;
+		let sigma_values = encoded_indices(&claim.sigma()[cells])?;
+
+		let identity_lincom = lincom_witness(column, &identity_values)?;
+		let sigma_lincom = lincom_witness(column, &sigma_values)?;
+
+		witness_index.set(
+			claim.identity_oracles()[column_index].id(),
+			MultilinearExtension::from_values(identity_values)?.specialize_arc_dyn(),
+		);
+		witness_index.set(
+			claim.sigma_oracles()[column_index].id(),
+			MultilinearExtension::from_values(sigma_values)?.specialize_arc_dyn(),
+		);
+		witness_index.set(lincom_oracles[0][column_index].id(), identity_lincom.clone());
+		witness_index.set(lincom_oracles[1][column_index].id(), sigma_lincom.clone());
+
+		identity_witnesses.push(GrandProductWitness::new(identity_lincom)?);
+		sigma_witnesses.push(GrandProductWitness::new(sigma_lincom)?);
+	}
+
+	let products = |witnesses: &[GrandProductWitness<FW>]| {
+		witnesses
+			.iter()
+			.map(|witness| F::from(witness.grand_product_evaluation()))
+			.collect::<Vec<_>>()
+	};
+
+	let proof = PermcheckProof {
+		identity_products: products(&identity_witnesses),
+		sigma_products: products(&sigma_witnesses),
+	};
+
+	let grand_product_claims = make_grand_product_claims(lincom_oracles, &proof)?;
+	let grand_product_witnesses = identity_witnesses
+		.into_iter()
+		.chain(sigma_witnesses)
+		.collect();
+
+	Ok(PermcheckProveOutput {
+		proof,
+		grand_product_claims,
+		grand_product_witnesses,
+	})
+}
@@ -0,0 +1,231 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	prove, verify, CopyConstraints, Error, PermcheckClaim, PermcheckProveOutput, PermcheckWitness,
+	VerificationError,
+};
+use crate::{
+	challenger::{CanObserve, HashChallenger},
+	oracle::{CommittedBatchSpec, CommittedId, MultilinearOracleSet, MultilinearPolyOracle},
+	polynomial::{
+		IsomorphicEvaluationDomainFactory, MultilinearExtension, MultilinearQuery, MultivariatePoly,
+	},
+	protocols::gkr_gpa::{batch_prove, batch_verify, GrandProductBatchProveOutput},
+	witness::{MultilinearWitness, MultilinearWitnessIndex},
+};
+use assert_matches::assert_matches;
+use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b, Field, TowerField};
+use binius_hash::GroestlHasher;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::iter::repeat_with;
+
+type F = BinaryField128b;
+type FS = BinaryField32b;
+
+const N_COLUMNS: usize = 3;
+const N_VARS: usize = 4;
+
+/// Random copy constraints and column values satisfying them.
+fn wired_columns(rng: &mut StdRng) -> (CopyConstraints, Vec<Vec<FS>>) {
+	let n_rows = 1 << N_VARS;
+	let mut copy_constraints = CopyConstraints::new(N_COLUMNS, N_VARS);
+	let mut columns = (0..N_COLUMNS)
+		.map(|_| {
+			repeat_with(|| <FS as Field>::random(&mut *rng))
+				.take(n_rows)
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
+	for _ in 0..20 {
+		let a = (rng.gen_range(0..N_COLUMNS), rng.gen_range(0..n_rows));
+		let b = (rng.gen_range(0..N_COLUMNS), rng.gen_range(0..n_rows));
+		copy_constraints.connect(a, b).unwrap();
+	}
+
+	// Assign every cycle the value of its first cell
+	let sigma = copy_constraints.sigma();
+	let mut visited = vec![false; sigma.len()];
+	for start in 0..sigma.len() {
+		let value = columns[start / n_rows][start % n_rows];
+		let mut index = start;
+		while !visited[index] {
+			visited[index] = true;
+			columns[index / n_rows][index % n_rows] = value;
+			index = sigma[index];
+		}
+	}
+
+	(copy_constraints, columns)
+}
+
+fn column_witnesses(columns: &[Vec<FS>]) -> Vec<MultilinearWitness<'static, F>> {
+	columns
+		.iter()
+		.map(|column| {
+			MultilinearExtension::from_values(column.clone())
+				.unwrap()
+				.specialize_arc_dyn()
+		})
+		.collect()
+}
+
+fn committed_columns(oracles: &mut MultilinearOracleSet<F>) -> Vec<MultilinearPolyOracle<F>> {
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars: N_VARS,
+		n_polys: N_COLUMNS,
+		tower_level: FS::TOWER_LEVEL,
+	});
+	(0..N_COLUMNS)
+		.map(|index| oracles.committed_oracle(CommittedId { batch_id, index }))
+		.collect()
+}
+
+#[test]
+fn test_copy_constraints_cycles() {
+	let mut copy_constraints = CopyConstraints::new(2, 2);
+	copy_constraints.connect((0, 1), (1, 2)).unwrap();
+	copy_constraints.connect((1, 2), (0, 3)).unwrap();
+	// Connecting cells already in the same cycle leaves the wiring unchanged
+	copy_constraints.connect((0, 3), (0, 1)).unwrap();
+
+	let sigma = copy_constraints.sigma();
+	let mut cycle = vec![1];
+	while sigma[*cycle.last().unwrap()] != 1 {
+		cycle.push(sigma[*cycle.last().unwrap()]);
+	}
+	cycle.sort();
+	assert_eq!(cycle, vec![1, 3, 6]);
+
+	for index in [0, 2, 4, 5, 7] {
+		assert_eq!(sigma[index], index);
+	}
+
+	assert_matches!(
+		copy_constraints.connect((2, 0), (0, 0)),
+		Err(Error::CellOutOfRange { column: 2, row: 0 })
+	);
+	assert_matches!(
+		copy_constraints.connect((0, 0), (1, 4)),
+		Err(Error::CellOutOfRange { column: 1, row: 4 })
+	);
+}
+
+#[test]
+fn test_claim_rejects_invalid_permutation() {
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let columns = committed_columns(&mut oracles);
+
+	let mut sigma = (0..N_COLUMNS << N_VARS).collect::<Vec<_>>();
+	sigma[0] = 1;
+	assert_matches!(
+		PermcheckClaim::new_in(&mut oracles, columns.clone(), sigma),
+		Err(Error::InvalidPermutation)
+	);
+	assert_matches!(
+		PermcheckClaim::new_in(&mut oracles, columns, vec![0, 1]),
+		Err(Error::InvalidPermutation)
+	);
+}
+
+#[test]
+fn test_prove_verify_interaction() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (copy_constraints, columns) = wired_columns(&mut rng);
+
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let column_oracles = committed_columns(&mut oracles);
+	let claim = PermcheckClaim::new_in(&mut oracles, column_oracles, copy_constraints.into_sigma())
+		.unwrap();
+
+	let gamma = <F as Field>::random(&mut rng);
+	let alpha = <F as Field>::random(&mut rng);
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let witness = PermcheckWitness::new(column_witnesses(&columns)).unwrap();
+
+	let mut prover_oracles = oracles.clone();
+	let PermcheckProveOutput {
+		proof,
+		grand_product_claims,
+		grand_product_witnesses,
+	} = prove(&mut prover_oracles, &mut witness_index, &claim, witness, gamma, alpha).unwrap();
+	assert_eq!(grand_product_claims.len(), 2 * N_COLUMNS);
+
+	// Transparent oracle witnesses agree with the transparent polynomials
+	let point = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(N_VARS)
+		.collect::<Vec<_>>();
+	let query = MultilinearQuery::<F>::with_full_query(&point).unwrap();
+	for oracle in claim.identity_oracles().iter().chain(claim.sigma_oracles()) {
+		let MultilinearPolyOracle::Transparent(id, transparent) = oracle else {
+			panic!("identity and sigma oracles must be transparent");
+		};
+		let witness = witness_index.get(*id).unwrap();
+		assert_eq!(witness.evaluate(&query).unwrap(), transparent.poly().evaluate(&point).unwrap());
+	}
+
+	let verifier_claims = verify(&mut oracles, &claim, gamma, alpha, &proof).unwrap();
+	assert_eq!(verifier_claims.len(), grand_product_claims.len());
+	for (prover_claim, verifier_claim) in grand_product_claims.iter().zip(verifier_claims.iter()) {
+		assert_eq!(prover_claim.poly, verifier_claim.poly);
+		assert_eq!(prover_claim.product, verifier_claim.product);
+	}
+
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	prover_challenger.observe_slice(&proof.identity_products);
+	prover_challenger.observe_slice(&proof.sigma_products);
+	let verifier_challenger = prover_challenger.clone();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let GrandProductBatchProveOutput {
+		evalcheck_claims,
+		proof: gpa_proof,
+	} = batch_prove::<_, _, BinaryField8b, _>(
+		grand_product_witnesses,
+		grand_product_claims,
+		domain_factory,
+		|_| 1,
+		prover_challenger,
+	)
+	.unwrap();
+
+	let verified_evalcheck_claims =
+		batch_verify(verifier_claims, gpa_proof, verifier_challenger).unwrap();
+	for (prover_claim, verifier_claim) in evalcheck_claims.iter().zip(&verified_evalcheck_claims) {
+		assert_eq!(prover_claim.eval_point, verifier_claim.eval_point);
+		assert_eq!(prover_claim.eval, verifier_claim.eval);
+
+		let query = MultilinearQuery::with_full_query(&verifier_claim.eval_point).unwrap();
+		let witness = witness_index.get(verifier_claim.poly.id()).unwrap();
+		assert_eq!(witness.evaluate(&query).unwrap(), verifier_claim.eval);
+	}
+}
+
+#[test]
+fn test_prove_rejects_broken_copy_constraint() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (copy_constraints, mut columns) = wired_columns(&mut rng);
+
+	// Break the copy constraint of a cell which is connected to another
+	let sigma = copy_constraints.sigma();
+	let index = (0..sigma.len())
+		.find(|&index| sigma[index] != index)
+		.expect("random constraints connect some cells");
+	columns[index >> N_VARS][index % (1 << N_VARS)] += FS::ONE;
+
+	let mut oracles = MultilinearOracleSet::<F>::new();
+	let column_oracles = committed_columns(&mut oracles);
+	let claim = PermcheckClaim::new_in(&mut oracles, column_oracles, copy_constraints.into_sigma())
+		.unwrap();
+
+	let gamma = <F as Field>::random(&mut rng);
+	let alpha = <F as Field>::random(&mut rng);
+
+	let mut witness_index = MultilinearWitnessIndex::new();
+	let witness = PermcheckWitness::new(column_witnesses(&columns)).unwrap();
+	assert_matches!(
+		prove(&mut oracles, &mut witness_index, &claim, witness, gamma, alpha),
+		Err(Error::Verification(VerificationError::ProductMismatch))
+	);
+}
@@ -0,0 +1,31 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	error::Error,
+	permcheck::{
+		make_grand_product_claims, reduce_permcheck_claim, PermcheckClaim, PermcheckProof,
+	},
+};
+use crate::{oracle::MultilinearOracleSet, protocols::gkr_gpa::GrandProductClaim};
+use binius_field::TowerField;
+
+/// Verify a permutation check instance reduction.
+///
+/// The grand products in `proof` must be observed by the challenger before the returned grand
+/// product claims are verified with [`gkr_gpa::batch_verify`].
+///
+/// ## Throws
+///
+/// * [`Error::Verification`] if the grand products of the identity and sigma sides differ
+///
+/// [`gkr_gpa::batch_verify`]: crate::protocols::gkr_gpa::batch_verify
+pub fn verify<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	claim: &PermcheckClaim<F>,
+	gamma: F,
+	alpha: F,
+	proof: &PermcheckProof<F>,
+) -> Result<Vec<GrandProductClaim<F>>, Error> {
+	let lincom_oracles = reduce_permcheck_claim(oracles, claim, gamma, alpha)?;
+	make_grand_product_claims(lincom_oracles, proof)
+}
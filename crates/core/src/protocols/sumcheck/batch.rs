@@ -5,9 +5,18 @@
 //! The sumcheck protocol over can be batched over multiple instances by taking random linear
 //! combinations over the claimed sums and polynomials. When the sumcheck instances are not all
 //! over polynomials with the same number of variables, we can still batch them together, sharing
-//! later round challenges. Importantly, the verifier samples mixing challenges "just-in-time".
-//! That is, the verifier samples mixing challenges for new sumcheck claims over n variables only
-//! after the last sumcheck round message has been sent by the prover.
+//! round challenges, in either [`BatchBindingOrder`]:
+//!
+//! * Back-loaded: claims over fewer variables share the later round challenges. The verifier
+//!   samples mixing challenges "just-in-time". That is, the verifier samples mixing challenges for
+//!   new sumcheck claims over n variables only after the last sumcheck round message has been
+//!   sent by the prover.
+//! * Front-loaded: claims over fewer variables share the earlier round challenges. All mixing
+//!   challenges are sampled upfront, and once a claim over n variables has been reduced by the
+//!   first n rounds, the prover sends its evaluation, which the verifier removes from the batched
+//!   round claim.
+//!
+//! Neither order scales the claimed sums of the smaller instances.
 
 use super::{error::Error, prove::SumcheckProver, sumcheck::SumcheckReductor, SumcheckClaim};
 use crate::{
@@ -16,7 +25,7 @@ use crate::{
 	protocols::{
 		abstract_sumcheck::{
 			self, finalize_evalcheck_claim, AbstractSumcheckBatchProof,
-			AbstractSumcheckBatchProveOutput,
+			AbstractSumcheckBatchProveOutput, BatchBindingOrder,
 		},
 		evalcheck::EvalcheckClaim,
 	},
@@ -36,6 +45,7 @@ pub struct SumcheckBatchProveOutput<F: Field> {
 /// See module documentation for details.
 pub fn batch_prove<'a, F, PW, DomainField, CW, M, CH>(
 	provers: impl IntoIterator<Item = SumcheckProver<'a, F, PW, DomainField, CW, M>>,
+	binding_order: BatchBindingOrder,
 	challenger: CH,
 ) -> Result<SumcheckBatchProveOutput<F>, Error>
 where
@@ -56,7 +66,7 @@ where
 	let AbstractSumcheckBatchProveOutput {
 		proof,
 		reduced_claims,
-	} = abstract_sumcheck::batch_prove(provers_vec, challenger)?;
+	} = abstract_sumcheck::batch_prove(provers_vec, binding_order, challenger)?;
 
 	let evalcheck_claims = reduced_claims
 		.into_iter()
@@ -76,6 +86,7 @@ where
 pub fn batch_verify<F, CH>(
 	claims: impl IntoIterator<Item = SumcheckClaim<F>>,
 	proof: SumcheckBatchProof<F>,
+	binding_order: BatchBindingOrder,
 	challenger: CH,
 ) -> Result<Vec<EvalcheckClaim<F>>, Error>
 where
@@ -89,6 +100,7 @@ where
 	let reduced_claims = abstract_sumcheck::batch_verify(
 		claims_vec.clone().into_iter().map(|c| c.into()),
 		proof,
+		binding_order,
 		sumcheck_reductor,
 		challenger,
 	)?;
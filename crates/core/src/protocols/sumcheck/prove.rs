@@ -18,7 +18,7 @@ use crate::{
 		abstract_sumcheck::{
 			self, check_evaluation_domain, finalize_evalcheck_claim, validate_rd_challenge,
 			AbstractSumcheckEvaluator, AbstractSumcheckProver, AbstractSumcheckReductor,
-			ProverState, ReducedClaim,
+			BatchBindingOrder, ProverState, ReducedClaim,
 		},
 		sumcheck::SumcheckProof,
 	},
@@ -26,12 +26,44 @@ use crate::{
 use binius_field::{ExtensionField, Field, PackedField};
 use getset::Getters;
 use rayon::prelude::*;
-use std::{fmt::Debug, marker::PhantomData};
+use std::{cmp, fmt::Debug, marker::PhantomData};
 use tracing::instrument;
 
 #[cfg(feature = "debug_validate_sumcheck")]
 use super::sumcheck::validate_witness;
 
+/// Wraps a `switchover_fn` so that a sumcheck prover fits within a large field memory budget.
+///
+/// Post-switchover, each multilinear is stored as a large field MLE of $2^{n\_vars - switchover}$
+/// scalars, while pre-switchover rounds store a tensor of $2^{switchover}$ scalars of the
+/// received challenges. The returned closure delays the switchover until the former fits into
+/// $2^{log\_memory}$ scalars. As both sizes have to fit, budgets below $\lceil n\_vars / 2
+/// \rceil$ are raised to it, which corresponds to the square root of the trace size.
+///
+/// Each delayed round is computed by small field inner products over the whole witness, thus
+/// lower budgets trade memory for $O(n\_vars - log\_memory)$ additional passes over the witness.
+///
+/// The budget only holds for [`SumcheckProver`], whose large field memory is that of its
+/// [`ProverState`]. Zerocheck and GKR sumcheck provers hold equality indicator tables of
+/// $2^{n\_vars - 1}$ scalars regardless of the switchover.
+///
+/// ## Arguments
+///
+/// * `n_vars`: the number of variables of the sumchecked multilinears
+/// * `log_memory`: base-2 logarithm of the number of large field scalars allotted per multilinear
+/// * `switchover_fn`: the switchover round as a function of the extension degree, used whenever it
+///                    already fits the budget
+pub fn streaming_switchover_fn(
+	n_vars: usize,
+	log_memory: usize,
+	switchover_fn: impl Fn(usize) -> usize,
+) -> impl Fn(usize) -> usize {
+	let log_memory = cmp::max(log_memory, n_vars.div_ceil(2));
+	let min_switchover = n_vars.saturating_sub(log_memory);
+	// The tensor of pre-switchover challenges has to fit into the budget as well
+	move |extension_degree| switchover_fn(extension_degree).clamp(min_switchover, log_memory)
+}
+
 /// Prove a sumcheck to evalcheck reduction.
 #[instrument(skip_all, name = "sumcheck::prove")]
 pub fn prove<F, PW, DomainField, CW, M, CH>(
@@ -269,7 +301,7 @@ where
 		SumcheckProver::finalize(self, prev_rd_challenge)
 	}
 
-	fn batch_proving_consistent(&self, _other: &Self) -> bool {
+	fn batch_proving_consistent(&self, _other: &Self, _binding_order: BatchBindingOrder) -> bool {
 		true
 	}
 
@@ -358,9 +390,10 @@ where
 		// we can compute $r(0)$ using the identity $r(0) = s - r(1)$
 		round_evals.insert(0, current_round_sum - round_evals[0]);
 
-		let coeffs = self.evaluation_domain.interpolate(&round_evals)?;
+		let mut coeffs = self.evaluation_domain.interpolate(&round_evals)?;
 
-		// Trimming highest degree coefficient as it can be recovered by the verifier
-		Ok(coeffs[..coeffs.len() - 1].to_vec())
+		// Trimming the linear coefficient as it can be recovered by the verifier
+		coeffs.remove(1);
+		Ok(coeffs)
 	}
 }
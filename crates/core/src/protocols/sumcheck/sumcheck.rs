@@ -82,7 +82,7 @@ fn reduce_intermediate_round_claim_helper<F: Field>(
 
 	// The prover has sent coefficients for the purported ith round polynomial
 	// * $r_i(X) = \sum_{j=0}^d a_j * X^j$
-	// However, the prover has not sent the highest degree coefficient $a_d$.
+	// However, the prover has not sent the linear coefficient $a_1$.
 	// The verifier will need to recover this missing coefficient.
 	//
 	// Let $s$ denote the current round's claimed sum.
@@ -91,15 +91,19 @@ fn reduce_intermediate_round_claim_helper<F: Field>(
 	// Using
 	//     $r_i(0) = a_0$
 	//     $r_i(1) = \sum_{j=0}^d a_j$
-	// There is a unique $a_d$ that allows $r_i$ to satisfy the above identity.
+	// There is a unique $a_1$ that allows $r_i$ to satisfy the above identity.
 	// Specifically
-	//     $a_d = s - a_0 - \sum_{j=0}^{d-1} a_j$
+	//     $a_1 = s - 2 a_0 - \sum_{j=2}^d a_j$
+	//
+	// The linear coefficient is omitted rather than the leading one because its position does
+	// not depend on the degree of the round polynomial. This keeps the encoding linear, so that
+	// round polynomials of different degrees may be mixed when batching.
 	//
 	// Not sending the whole round polynomial is an optimization.
 	// In the unoptimized version of the protocol, the verifier will halt and reject
 	// if given a round polynomial that does not satisfy the above identity.
-	let last_coeff = current_round_sum - coeffs[0] - coeffs.iter().sum::<F>();
-	coeffs.push(last_coeff);
+	let linear_coeff = current_round_sum - coeffs[0] - coeffs.iter().sum::<F>();
+	coeffs.insert(1, linear_coeff);
 	let new_round_sum = evaluate_univariate(&coeffs, challenge);
 
 	partial_point.push(challenge);
@@ -4,15 +4,24 @@ use crate::{
 	challenger::HashChallenger,
 	oracle::{CommittedBatchSpec, CommittedId, CompositePolyOracle, MultilinearOracleSet},
 	polynomial::{
-		CompositionPoly, Error as PolynomialError, EvaluationDomain, MultilinearComposite,
-		MultilinearExtension, MultilinearExtensionSpecialized, MultilinearQuery,
+		CompositionPoly, ConstantMultilinear, Error as PolynomialError, EvaluationDomain,
+		MultilinearComposite, MultilinearExtension, MultilinearExtensionSpecialized,
+		MultilinearQuery, PeriodicMultilinear, SparseMultilinear,
 	},
 	protocols::{
-		sumcheck::{batch_prove, batch_verify, prove, verify, SumcheckClaim, SumcheckProver},
+		abstract_sumcheck::{
+			BatchBindingOrder, Error as AbstractSumcheckError, ProverState,
+			VerificationError as AbstractSumcheckVerificationError,
+		},
+		sumcheck::{
+			batch_prove, batch_verify, prove, streaming_switchover_fn, verify, Error,
+			SumcheckClaim, SumcheckProver,
+		},
 		test_utils::{transform_poly, TestProductComposition},
 	},
-	witness::MultilinearWitnessIndex,
+	witness::{MultilinearWitness, MultilinearWitnessIndex},
 };
+use assert_matches::assert_matches;
 use binius_field::{
 	BinaryField128b, BinaryField128bPolyval, BinaryField32b, ExtensionField, Field, PackedField,
 	TowerField,
@@ -21,7 +30,7 @@ use binius_hash::GroestlHasher;
 use p3_util::log2_ceil_usize;
 use rand::{rngs::StdRng, SeedableRng};
 use rayon::current_num_threads;
-use std::iter::repeat_with;
+use std::{iter::repeat_with, sync::Arc};
 
 fn generate_poly_and_sum_helper<F, FE>(
 	rng: &mut StdRng,
@@ -249,6 +258,134 @@ fn test_prove_verify_interaction_with_monomial_basis_conversion_pigeonhole_cores
 	}
 }
 
+#[test]
+fn test_streaming_switchover_fn() {
+	// Budgets below the square root of the trace size are raised to it
+	let switchover_fn = streaming_switchover_fn(28, 0, |_| 1);
+	assert_eq!(switchover_fn(1), 14);
+	let switchover_fn = streaming_switchover_fn(27, 0, |_| 1);
+	assert_eq!(switchover_fn(1), 13);
+
+	let switchover_fn = streaming_switchover_fn(28, 20, |extension_degree| extension_degree);
+	assert_eq!(switchover_fn(1), 8);
+	assert_eq!(switchover_fn(16), 16);
+	// The tensor of pre-switchover challenges is bound by the budget too
+	assert_eq!(switchover_fn(128), 20);
+
+	let switchover_fn = streaming_switchover_fn(28, 28, |_| 7);
+	assert_eq!(switchover_fn(1), 7);
+}
+
+#[test]
+fn test_streaming_switchover_fn_memory_bound() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let (n_vars, n_multilinears) = (12, 3);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let (poly, _) = generate_poly_and_sum_helper::<F, FE>(&mut rng, n_vars, n_multilinears);
+
+	// The largest large field size of the prover state over all rounds
+	let max_large_field_size = |switchover_fn: &dyn Fn(usize) -> usize| {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut state =
+			ProverState::new(n_vars, poly.multilinears.iter().cloned(), switchover_fn).unwrap();
+		let mut max_size = state.large_field_size();
+		for _ in 1..n_vars {
+			state.fold(<FE as Field>::random(&mut rng)).unwrap();
+			max_size = max_size.max(state.large_field_size());
+		}
+		max_size
+	};
+
+	// The tensor of pre-switchover challenges and every multilinear fit into the budget
+	for log_memory in [0, 6, 8, 10] {
+		let budget = (n_multilinears + 1) << log_memory.max(n_vars / 2);
+		let switchover_fn = streaming_switchover_fn(n_vars, log_memory, |_| 1);
+		assert!(max_large_field_size(&switchover_fn) <= budget);
+	}
+
+	// Without streaming, folding after the first round takes half the trace size per multilinear
+	assert!(max_large_field_size(&|_| 1) >= n_multilinears << (n_vars - 1));
+}
+
+#[test]
+fn test_prove_verify_streaming() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let n_multilinears = 3;
+
+	for n_vars in 2..9 {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (poly, sum) = generate_poly_and_sum_helper::<F, FE>(&mut rng, n_vars, n_multilinears);
+
+		let mut oracles = MultilinearOracleSet::new();
+		let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+			n_vars,
+			n_polys: n_multilinears,
+			tower_level: F::TOWER_LEVEL,
+		});
+		let h = (0..n_multilinears)
+			.map(|i| oracles.committed_oracle(CommittedId { batch_id, index: i }))
+			.collect();
+		let composite_poly =
+			CompositePolyOracle::new(n_vars, h, TestProductComposition::new(n_multilinears))
+				.unwrap();
+		let sumcheck_claim = SumcheckClaim {
+			sum: sum.into(),
+			poly: composite_poly,
+		};
+
+		let domain = EvaluationDomain::<FE>::new(n_multilinears + 1).unwrap();
+		let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+
+		let reference_output = prove::<_, _, FE, _, _, _>(
+			&sumcheck_claim,
+			poly.clone(),
+			&domain,
+			challenger.clone(),
+			|_| 1,
+		)
+		.unwrap();
+
+		for log_memory in 0..=n_vars {
+			let prove_output = prove::<_, _, FE, _, _, _>(
+				&sumcheck_claim,
+				poly.clone(),
+				&domain,
+				challenger.clone(),
+				streaming_switchover_fn(n_vars, log_memory, |_| 1),
+			)
+			.unwrap();
+
+			// Streaming only changes how the round polynomials are computed
+			assert_eq!(
+				prove_output
+					.sumcheck_proof
+					.rounds
+					.iter()
+					.map(|round| &round.coeffs)
+					.collect::<Vec<_>>(),
+				reference_output
+					.sumcheck_proof
+					.rounds
+					.iter()
+					.map(|round| &round.coeffs)
+					.collect::<Vec<_>>(),
+			);
+
+			let verify_output =
+				verify(&sumcheck_claim, prove_output.sumcheck_proof, challenger.clone()).unwrap();
+			assert_eq!(prove_output.evalcheck_claim.eval, verify_output.eval);
+			assert_eq!(prove_output.evalcheck_claim.eval_point, verify_output.eval_point);
+
+			let multilin_query =
+				MultilinearQuery::with_full_query(&verify_output.eval_point).unwrap();
+			assert_eq!(poly.evaluate(&multilin_query).unwrap(), verify_output.eval);
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 struct SquareComposition;
 
@@ -276,6 +413,69 @@ impl<P: PackedField> CompositionPoly<P> for SquareComposition {
 	}
 }
 
+#[test]
+fn test_prove_verify_structured_multilinears() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let n_vars = 8;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let period = repeat_with(|| <F as Field>::random(&mut rng))
+		.take(1 << 3)
+		.collect();
+	let sparse_entries = (0..1 << n_vars)
+		.step_by(7)
+		.map(|index| (index, <F as Field>::random(&mut rng)))
+		.collect::<Vec<_>>();
+	let multilinears: Vec<MultilinearWitness<FE>> = vec![
+		Arc::new(ConstantMultilinear::new(n_vars, <F as Field>::random(&mut rng))),
+		Arc::new(PeriodicMultilinear::new(n_vars, period).unwrap()),
+		Arc::new(SparseMultilinear::new(n_vars, sparse_entries).unwrap()),
+	];
+	let sum = (0..1 << n_vars)
+		.map(|i| {
+			multilinears
+				.iter()
+				.map(|multilin| multilin.evaluate_on_hypercube(i).unwrap())
+				.product::<FE>()
+		})
+		.sum::<FE>();
+	let witness =
+		MultilinearComposite::new(n_vars, TestProductComposition::new(3), multilinears).unwrap();
+
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: 3,
+		tower_level: F::TOWER_LEVEL,
+	});
+	let h = (0..3)
+		.map(|index| oracles.committed_oracle(CommittedId { batch_id, index }))
+		.collect();
+	let sumcheck_claim = SumcheckClaim {
+		sum,
+		poly: CompositePolyOracle::new(n_vars, h, TestProductComposition::new(3)).unwrap(),
+	};
+
+	let domain = EvaluationDomain::<FE>::new(4).unwrap();
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+
+	let prove_output = prove::<_, _, FE, _, _, _>(
+		&sumcheck_claim,
+		witness.clone(),
+		&domain,
+		challenger.clone(),
+		|_| 3,
+	)
+	.expect("failed to prove sumcheck");
+	let verify_output = verify(&sumcheck_claim, prove_output.sumcheck_proof, challenger)
+		.expect("failed to verify sumcheck proof");
+	assert_eq!(prove_output.evalcheck_claim.eval, verify_output.eval);
+
+	let query = MultilinearQuery::with_full_query(&verify_output.eval_point).unwrap();
+	assert_eq!(witness.evaluate(&query).unwrap(), verify_output.eval);
+}
+
 #[test]
 fn test_prove_verify_batch() {
 	type F = BinaryField32b;
@@ -380,10 +580,189 @@ fn test_prove_verify_batch() {
 	// Setup evaluation domain
 	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
 
-	let prove_output = batch_prove([prover0, prover1, prover2], challenger.clone()).unwrap();
+	let prove_output =
+		batch_prove([prover0, prover1, prover2], BatchBindingOrder::BackLoaded, challenger.clone())
+			.unwrap();
+	let proof = prove_output.proof;
+	assert_eq!(proof.rounds.len(), 8);
+
+	let _evalcheck_claims = batch_verify(
+		sumcheck_claims.iter().cloned(),
+		proof,
+		BatchBindingOrder::BackLoaded,
+		challenger.clone(),
+	)
+	.unwrap();
+}
+
+fn test_prove_verify_batch_mixed_degrees_helper(
+	binding_order: BatchBindingOrder,
+	expected_degree: impl Fn(usize) -> usize,
+) {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+
+	// Instances of degrees 3, 1 and 2 respectively, so that the batched round polynomials
+	// combine compressed round messages of different lengths.
+	let params = [(4, 3), (6, 1), (8, 2)];
+
+	let (witnesses, sumcheck_claims): (Vec<_>, Vec<_>) = params
+		.iter()
+		.map(|&(n_vars, n_multilinears)| {
+			let (witness, sum) =
+				generate_poly_and_sum_helper::<F, FE>(&mut rng, n_vars, n_multilinears);
+			let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+				n_vars,
+				n_polys: n_multilinears,
+				tower_level: F::TOWER_LEVEL,
+			});
+			let h = (0..n_multilinears)
+				.map(|index| oracles.committed_oracle(CommittedId { batch_id, index }))
+				.collect();
+			let poly =
+				CompositePolyOracle::new(n_vars, h, TestProductComposition::new(n_multilinears))
+					.unwrap();
+			let claim = SumcheckClaim {
+				poly,
+				sum: sum.into(),
+			};
+			(witness, claim)
+		})
+		.unzip();
+
+	let domains = params
+		.iter()
+		.map(|&(_, n_multilinears)| EvaluationDomain::<FE>::new(n_multilinears + 1).unwrap())
+		.collect::<Vec<_>>();
+
+	let provers = witnesses
+		.iter()
+		.zip(sumcheck_claims.iter())
+		.zip(domains.iter())
+		.map(|((witness, claim), domain)| {
+			SumcheckProver::<_, _, FE, _, _>::new(domain, claim.clone(), witness.clone(), |_| 3)
+				.unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+
+	let prove_output = batch_prove(provers, binding_order, challenger.clone()).unwrap();
 	let proof = prove_output.proof;
 	assert_eq!(proof.rounds.len(), 8);
 
-	let _evalcheck_claims =
-		batch_verify(sumcheck_claims.iter().cloned(), proof, challenger.clone()).unwrap();
+	// The linear coefficient is omitted from every round message
+	for (round_no, round) in proof.rounds.iter().enumerate() {
+		assert_eq!(round.coeffs.len(), expected_degree(round_no));
+	}
+
+	let evalcheck_claims =
+		batch_verify(sumcheck_claims.iter().cloned(), proof, binding_order, challenger.clone())
+			.unwrap();
+
+	for (claim, witness) in evalcheck_claims.iter().zip(witnesses.iter()) {
+		let query = MultilinearQuery::with_full_query(&claim.eval_point).unwrap();
+		assert_eq!(witness.evaluate(&query).unwrap(), claim.eval);
+	}
+
+	// Smaller instances are bound over the last or the first round challenges respectively
+	let largest_point = &evalcheck_claims[2].eval_point;
+	for claim in evalcheck_claims.iter() {
+		let n_vars = claim.eval_point.len();
+		let shared_challenges = match binding_order {
+			BatchBindingOrder::BackLoaded => &largest_point[largest_point.len() - n_vars..],
+			BatchBindingOrder::FrontLoaded => &largest_point[..n_vars],
+		};
+		assert_eq!(claim.eval_point, shared_challenges);
+	}
+}
+
+#[test]
+fn test_prove_verify_batch_mixed_degrees() {
+	// Instances with fewer variables join the batch in the later rounds
+	test_prove_verify_batch_mixed_degrees_helper(BatchBindingOrder::BackLoaded, |round_no| {
+		if round_no < 4 {
+			2
+		} else {
+			3
+		}
+	});
+}
+
+#[test]
+fn test_prove_verify_batch_front_loaded() {
+	// Instances with fewer variables leave the batch after the earlier rounds
+	test_prove_verify_batch_mixed_degrees_helper(BatchBindingOrder::FrontLoaded, |round_no| {
+		if round_no < 4 {
+			3
+		} else {
+			2
+		}
+	});
+}
+
+fn test_verify_batch_rejects_incorrect_evaluation_helper(binding_order: BatchBindingOrder) {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut oracles = MultilinearOracleSet::<FE>::new();
+
+	let (witnesses, sumcheck_claims): (Vec<_>, Vec<_>) = [4, 6]
+		.into_iter()
+		.map(|n_vars| {
+			let (witness, sum) = generate_poly_and_sum_helper::<F, FE>(&mut rng, n_vars, 2);
+			let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+				n_vars,
+				n_polys: 2,
+				tower_level: F::TOWER_LEVEL,
+			});
+			let h = (0..2)
+				.map(|index| oracles.committed_oracle(CommittedId { batch_id, index }))
+				.collect();
+			let poly = CompositePolyOracle::new(n_vars, h, TestProductComposition::new(2)).unwrap();
+			let claim = SumcheckClaim {
+				poly,
+				sum: sum.into(),
+			};
+			(witness, claim)
+		})
+		.unzip();
+
+	let domain = EvaluationDomain::<FE>::new(3).unwrap();
+	let provers = witnesses
+		.into_iter()
+		.zip(sumcheck_claims.iter())
+		.map(|(witness, claim)| {
+			SumcheckProver::<_, _, FE, _, _>::new(&domain, claim.clone(), witness, |_| 3).unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let mut proof = batch_prove(provers, binding_order, challenger.clone())
+		.unwrap()
+		.proof;
+
+	// Tamper with the evaluation of the smaller instance
+	proof.sorted_evals[1] += FE::ONE;
+	assert_matches!(
+		batch_verify(sumcheck_claims.iter().cloned(), proof, binding_order, challenger),
+		Err(Error::AbstractSumcheck(AbstractSumcheckError::Verification(
+			AbstractSumcheckVerificationError::IncorrectBatchEvaluation
+		)))
+	);
+}
+
+#[test]
+fn test_verify_back_loaded_rejects_incorrect_evaluation() {
+	test_verify_batch_rejects_incorrect_evaluation_helper(BatchBindingOrder::BackLoaded);
+}
+
+#[test]
+fn test_verify_front_loaded_rejects_incorrect_evaluation() {
+	// The evaluation of the smaller instance is sent mid-batch
+	test_verify_batch_rejects_incorrect_evaluation_helper(BatchBindingOrder::FrontLoaded);
 }
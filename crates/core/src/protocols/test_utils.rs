@@ -7,6 +7,7 @@ use crate::{
 		MultivariatePoly,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		evalcheck::{
 			subclaims::{
 				non_same_query_pcs_sumcheck_claim, non_same_query_pcs_sumcheck_metas,
@@ -184,7 +185,7 @@ where
 	let SumcheckBatchProveOutput {
 		proof,
 		evalcheck_claims,
-	} = batch_prove(prover_states, challenger)?;
+	} = batch_prove(prover_states, BatchBindingOrder::BackLoaded, challenger)?;
 
 	Ok((proof, evalcheck_claims))
 }
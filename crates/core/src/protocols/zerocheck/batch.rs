@@ -4,27 +4,65 @@
 //!
 //! The zerocheck protocol over can be batched over multiple instances by taking random linear
 //! combinations over the claimed sums and polynomials. When the zerocheck instances are not all
-//! over polynomials with the same number of variables, we can still batch them together, sharing
-//! later round challenges. Importantly, the verifier samples mixing challenges "just-in-time".
-//! That is, the verifier samples mixing challenges for new zerocheck claims over n variables only
-//! after the last zerocheck round message has been sent by the prover.
+//! over polynomials with the same number of variables, we can still batch them together in either
+//! [`BatchBindingOrder`], the same way as sumcheck instances (see
+//! [`sumcheck::batch_prove`](crate::protocols::sumcheck::batch_prove)).
+//!
+//! All instances share the zerocheck challenges of the rounds they take part in. With back-loaded
+//! binding an instance over n variables takes part in the last n rounds and its prover takes a
+//! suffix of the batch zerocheck challenges, whereas with front-loaded binding it takes part in
+//! the first n rounds and its prover takes a prefix. Provers are given the batch challenges along
+//! with the binding order.
+//!
+//! Zerocheck instances with a univariate skip are batched the same way after the univariate
+//! round. All instances must skip the same number of variables and share the univariate round
+//! challenge; the univariate round messages are sent individually, one per instance.
 
-use super::{error::Error, prove::ZerocheckProver, zerocheck::ZerocheckReductor, ZerocheckClaim};
+use super::{
+	error::{Error, VerificationError},
+	prove::ZerocheckProver,
+	univariate_skip::{
+		check_partial_evals, check_univariate_round, compute_partial_evals,
+		evaluate_univariate_round, make_evalcheck_claims, ZerocheckUnivariateSkipProver,
+		ZerocheckUnivariateSkipReductor,
+	},
+	zerocheck::{batch_round_degrees, ZerocheckReductor, ZerocheckRound},
+	ZerocheckClaim,
+};
 use crate::{
 	challenger::{CanObserve, CanSample},
-	polynomial::CompositionPoly,
+	polynomial::{CompositionPoly, EvaluationDomain},
 	protocols::{
 		abstract_sumcheck::{
 			self, finalize_evalcheck_claim, AbstractSumcheckBatchProof,
-			AbstractSumcheckBatchProveOutput,
+			AbstractSumcheckBatchProveOutput, AbstractSumcheckClaim, BatchBindingOrder,
+			Error as AbstractSumcheckError,
 		},
 		evalcheck::EvalcheckClaim,
 	},
 };
-use binius_field::{ExtensionField, Field, PackedField};
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use std::iter::Step;
 
 pub type ZerocheckBatchProof<F> = AbstractSumcheckBatchProof<F>;
 
+#[derive(Debug, Clone)]
+pub struct ZerocheckUnivariateSkipBatchProof<F> {
+	/// Univariate round messages, one per instance in the original order.
+	pub univariate_rounds: Vec<ZerocheckRound<F>>,
+	/// The batched proof of the rounds following the univariate round.
+	pub proof: ZerocheckBatchProof<F>,
+	/// Partial evaluations of the multilinears of each instance, in the original order.
+	pub partial_evals: Vec<Vec<Vec<F>>>,
+}
+
+#[derive(Debug)]
+pub struct ZerocheckUnivariateSkipBatchProveOutput<F: Field> {
+	/// Evalcheck claims on the multilinears of each instance, in the original order.
+	pub evalcheck_claims: Vec<Vec<EvalcheckClaim<F>>>,
+	pub proof: ZerocheckUnivariateSkipBatchProof<F>,
+}
+
 #[derive(Debug)]
 pub struct ZerocheckBatchProveOutput<F: Field> {
 	pub evalcheck_claims: Vec<EvalcheckClaim<F>>,
@@ -36,6 +74,7 @@ pub struct ZerocheckBatchProveOutput<F: Field> {
 /// See module documentation for details.
 pub fn batch_prove<'a, F, PW, DomainField, CW, CH>(
 	provers: impl IntoIterator<Item = ZerocheckProver<'a, F, PW, DomainField, CW>>,
+	binding_order: BatchBindingOrder,
 	challenger: CH,
 ) -> Result<ZerocheckBatchProveOutput<F>, Error>
 where
@@ -55,7 +94,7 @@ where
 	let AbstractSumcheckBatchProveOutput {
 		proof,
 		reduced_claims,
-	} = abstract_sumcheck::batch_prove(provers_vec, challenger)?;
+	} = abstract_sumcheck::batch_prove(provers_vec, binding_order, challenger)?;
 
 	let evalcheck_claims = reduced_claims
 		.into_iter()
@@ -75,6 +114,7 @@ where
 pub fn batch_verify<F, CH>(
 	claims: impl IntoIterator<Item = ZerocheckClaim<F>>,
 	proof: ZerocheckBatchProof<F>,
+	binding_order: BatchBindingOrder,
 	mut challenger: CH,
 ) -> Result<Vec<EvalcheckClaim<F>>, Error>
 where
@@ -94,6 +134,13 @@ where
 		.then_some(())
 		.ok_or(Error::ZeroVariableClaim)?;
 
+	if claims_vec
+		.iter()
+		.any(|claim| claim.poly.max_individual_degree() == 0)
+	{
+		return Err(Error::PolynomialDegreeIsZero);
+	}
+
 	// Find the maximum number of variables in any claim,
 	// while also ensuring there is at least one claim
 	let max_n_vars = claims_vec
@@ -104,10 +151,21 @@ where
 
 	let alphas = challenger.sample_vec(max_n_vars - 1);
 
-	let reductor = ZerocheckReductor { alphas: &alphas };
+	let round_degrees = batch_round_degrees(
+		claims_vec
+			.iter()
+			.map(|claim| (claim.n_vars(), claim.poly.max_individual_degree())),
+		max_n_vars,
+		binding_order,
+	);
+	let reductor = ZerocheckReductor {
+		alphas: &alphas,
+		round_degrees: &round_degrees,
+	};
 	let reduced_claims = abstract_sumcheck::batch_verify(
 		claims_vec.into_iter().map(|c| c.into()),
 		proof,
+		binding_order,
 		reductor,
 		challenger,
 	)?;
@@ -120,3 +178,208 @@ where
 
 	Ok(evalcheck_claims)
 }
+
+/// Prove a batched zerocheck instance with a univariate skip.
+///
+/// The provers must be freshly created, all with the same number of skipped variables. See
+/// module documentation for details.
+pub fn batch_prove_univariate_skip<'a, F, PW, DomainField, CW, CH>(
+	provers: impl IntoIterator<Item = ZerocheckUnivariateSkipProver<'a, F, PW, DomainField, CW>>,
+	binding_order: BatchBindingOrder,
+	mut challenger: CH,
+	switchover_fn: impl Fn(usize) -> usize,
+) -> Result<ZerocheckUnivariateSkipBatchProveOutput<F>, Error>
+where
+	F: TowerField + Step + From<PW::Scalar>,
+	PW: PackedExtension<DomainField>,
+	PW::Scalar: From<F> + ExtensionField<DomainField>,
+	DomainField: Field,
+	CW: CompositionPoly<PW> + 'static,
+	CH: CanObserve<F> + CanSample<F>,
+{
+	let mut provers_vec = provers.into_iter().collect::<Vec<_>>();
+	let skip_vars = provers_vec
+		.first()
+		.ok_or(Error::EmptyClaimsArray)?
+		.skip_vars();
+	if provers_vec
+		.iter()
+		.any(|prover| prover.skip_vars() != skip_vars)
+	{
+		return Err(AbstractSumcheckError::InelligibleBatch.into());
+	}
+
+	let univariate_rounds = provers_vec
+		.iter_mut()
+		.map(|prover| prover.execute_univariate_round())
+		.collect::<Result<Vec<_>, _>>()?;
+	for univariate_round in univariate_rounds.iter() {
+		challenger.observe_slice(&univariate_round.coeffs);
+	}
+	let univariate_challenge = challenger.sample();
+
+	let folded_provers = provers_vec
+		.into_iter()
+		.map(|prover| prover.fold_univariate_round(univariate_challenge, &switchover_fn))
+		.collect::<Result<Vec<_>, _>>()?;
+	let oracles_multilinears = folded_provers
+		.iter()
+		.map(|prover| (prover.oracle().clone(), prover.multilinears().clone()))
+		.collect::<Vec<_>>();
+
+	let AbstractSumcheckBatchProveOutput {
+		proof,
+		reduced_claims,
+	} = abstract_sumcheck::batch_prove(folded_provers, binding_order, &mut challenger)?;
+
+	let partial_evals = oracles_multilinears
+		.iter()
+		.zip(reduced_claims.iter())
+		.map(|((_, multilinears), reduced_claim)| {
+			compute_partial_evals(multilinears, skip_vars, &reduced_claim.eval_point)
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	for evals in partial_evals.iter().flatten() {
+		challenger.observe_slice(evals);
+	}
+	let skipped_challenges = challenger.sample_vec(skip_vars);
+
+	let evalcheck_claims = oracles_multilinears
+		.iter()
+		.zip(partial_evals.iter())
+		.zip(reduced_claims.iter())
+		.map(|(((oracle, _), partial_evals), reduced_claim)| {
+			make_evalcheck_claims(
+				oracle,
+				partial_evals,
+				&skipped_challenges,
+				&reduced_claim.eval_point,
+			)
+		})
+		.collect::<Result<_, _>>()?;
+
+	Ok(ZerocheckUnivariateSkipBatchProveOutput {
+		evalcheck_claims,
+		proof: ZerocheckUnivariateSkipBatchProof {
+			univariate_rounds,
+			proof,
+			partial_evals,
+		},
+	})
+}
+
+/// Verify a batched zerocheck instance with a univariate skip of `skip_vars` variables.
+///
+/// See module documentation for details.
+pub fn batch_verify_univariate_skip<F, CH>(
+	claims: impl IntoIterator<Item = ZerocheckClaim<F>>,
+	skip_vars: usize,
+	proof: ZerocheckUnivariateSkipBatchProof<F>,
+	binding_order: BatchBindingOrder,
+	mut challenger: CH,
+) -> Result<Vec<Vec<EvalcheckClaim<F>>>, Error>
+where
+	F: TowerField + Step,
+	CH: CanSample<F> + CanObserve<F>,
+{
+	let claims_vec = claims.into_iter().collect::<Vec<_>>();
+
+	if claims_vec
+		.iter()
+		.any(|claim| skip_vars == 0 || claim.n_vars() < skip_vars)
+	{
+		return Err(Error::InvalidSkipVars);
+	}
+	if claims_vec
+		.iter()
+		.any(|claim| claim.poly.max_individual_degree() == 0)
+	{
+		return Err(Error::PolynomialDegreeIsZero);
+	}
+
+	let max_n_vars = claims_vec
+		.iter()
+		.map(|claim| claim.n_vars())
+		.max()
+		.ok_or(Error::EmptyClaimsArray)?;
+
+	let ZerocheckUnivariateSkipBatchProof {
+		univariate_rounds,
+		proof,
+		partial_evals,
+	} = proof;
+
+	if univariate_rounds.len() != claims_vec.len() || partial_evals.len() != claims_vec.len() {
+		return Err(VerificationError::NumberOfRounds.into());
+	}
+
+	let alphas = challenger.sample_vec(max_n_vars - skip_vars);
+
+	for (claim, univariate_round) in claims_vec.iter().zip(univariate_rounds.iter()) {
+		check_univariate_round(claim.poly.max_individual_degree(), skip_vars, univariate_round)?;
+		challenger.observe_slice(&univariate_round.coeffs);
+	}
+	let univariate_challenge = challenger.sample();
+
+	let vertex_domain = EvaluationDomain::<F>::new(1 << skip_vars)?;
+	let sumcheck_claims = claims_vec
+		.iter()
+		.zip(univariate_rounds.iter())
+		.map(|(claim, univariate_round)| AbstractSumcheckClaim {
+			n_vars: claim.n_vars() - skip_vars,
+			sum: evaluate_univariate_round(
+				vertex_domain.points().iter().copied(),
+				&univariate_round.coeffs,
+				univariate_challenge,
+			),
+		})
+		.collect::<Vec<_>>();
+
+	let round_degrees = batch_round_degrees(
+		claims_vec
+			.iter()
+			.map(|claim| (claim.n_vars() - skip_vars, claim.poly.max_individual_degree())),
+		max_n_vars - skip_vars,
+		binding_order,
+	);
+	let reductor = ZerocheckUnivariateSkipReductor {
+		alphas: &alphas,
+		round_degrees: &round_degrees,
+	};
+	let reduced_claims = abstract_sumcheck::batch_verify(
+		sumcheck_claims,
+		proof,
+		binding_order,
+		reductor,
+		&mut challenger,
+	)?;
+
+	let lagrange_evals = vertex_domain.lagrange_evals(univariate_challenge);
+	for ((claim, partial_evals), reduced_claim) in claims_vec
+		.iter()
+		.zip(partial_evals.iter())
+		.zip(reduced_claims.iter())
+	{
+		check_partial_evals(&claim.poly, &lagrange_evals, partial_evals, reduced_claim.eval)?;
+	}
+
+	for evals in partial_evals.iter().flatten() {
+		challenger.observe_slice(evals);
+	}
+	let skipped_challenges = challenger.sample_vec(skip_vars);
+
+	claims_vec
+		.iter()
+		.zip(partial_evals.iter())
+		.zip(reduced_claims.iter())
+		.map(|((claim, partial_evals), reduced_claim)| {
+			make_evalcheck_claims(
+				&claim.poly,
+				partial_evals,
+				&skipped_challenges,
+				&reduced_claim.eval_point,
+			)
+		})
+		.collect()
+}
@@ -25,6 +25,12 @@ pub enum Error {
 	RoundArgumentRoundClaimMismatch,
 	#[error("the round polynomial is inconsistent with the round claim")]
 	RoundPolynomialCheckFailed,
+	#[error("the number of skipped variables must be positive and leave enough variables to pack")]
+	InvalidSkipVars,
+	#[error("the univariate skip domain must extend the canonical domain of the skipped vertices")]
+	UnivariateSkipDomainMismatch,
+	#[error("the partial evaluations are inconsistent with the reduced claim")]
+	PartialEvaluationCheckFailed,
 	#[error("IOPolynomial error: {0}")]
 	IOPolynomial(#[from] IOPolynomialError),
 	#[error("polynomial error: {0}")]
@@ -47,8 +53,12 @@ pub enum VerificationError {
 	ExpectedClaimedSumToBeZero,
 	#[error("round proof must have at least one coefficient")]
 	NumberOfCoefficients,
+	#[error("round proof must have exactly as many coefficients as the composition degree")]
+	RoundPolynomialDegree,
 	#[error("incorrect number of rounds")]
 	NumberOfRounds,
+	#[error("incorrect number of partial evaluations")]
+	NumberOfPartialEvaluations,
 	#[error("IOPolynomial error: {0}")]
 	IOPolynomial(#[from] IOPolynomialError),
 	#[error("polynomial error: {0}")]
@@ -9,6 +9,7 @@ mod error;
 mod prove;
 #[cfg(test)]
 mod tests;
+mod univariate_skip;
 mod verify;
 #[allow(clippy::module_inception)]
 mod zerocheck;
@@ -16,6 +17,11 @@ mod zerocheck;
 pub use batch::*;
 pub use error::*;
 pub use prove::{prove, ZerocheckProver};
+pub use univariate_skip::{
+	prove_univariate_skip, verify_univariate_skip, ZerocheckUnivariateSkipFoldedProver,
+	ZerocheckUnivariateSkipProof, ZerocheckUnivariateSkipProveOutput,
+	ZerocheckUnivariateSkipProver, ZerocheckUnivariateSkipReductor,
+};
 pub use verify::verify;
 pub use zerocheck::{
 	validate_witness, ZerocheckClaim, ZerocheckProof, ZerocheckProveOutput, ZerocheckWitness,
@@ -3,7 +3,8 @@
 use super::{
 	error::Error,
 	zerocheck::{
-		ZerocheckClaim, ZerocheckProof, ZerocheckProveOutput, ZerocheckReductor, ZerocheckRound,
+		instance_zerocheck_challenges, zerocheck_challenges_consistent, ZerocheckClaim,
+		ZerocheckProof, ZerocheckProveOutput, ZerocheckReductor, ZerocheckRound,
 		ZerocheckRoundClaim, ZerocheckWitness,
 	},
 };
@@ -16,8 +17,8 @@ use crate::{
 	},
 	protocols::abstract_sumcheck::{
 		self, check_evaluation_domain, finalize_evalcheck_claim, validate_rd_challenge,
-		AbstractSumcheckEvaluator, AbstractSumcheckProver, AbstractSumcheckReductor, ProverState,
-		ReducedClaim,
+		AbstractSumcheckEvaluator, AbstractSumcheckProver, AbstractSumcheckReductor,
+		BatchBindingOrder, ProverState, ReducedClaim,
 	},
 	witness::MultilinearWitness,
 };
@@ -51,8 +52,14 @@ where
 	let n_vars = witness.n_vars();
 	let zerocheck_challenges = challenger.sample_vec(n_vars - 1);
 
-	let zerocheck_prover: ZerocheckProver<F, PW, FS, _> =
-		ZerocheckProver::new(domain, claim.clone(), witness, &zerocheck_challenges, switchover_fn)?;
+	let zerocheck_prover: ZerocheckProver<F, PW, FS, _> = ZerocheckProver::new(
+		domain,
+		claim.clone(),
+		witness,
+		&zerocheck_challenges,
+		BatchBindingOrder::BackLoaded,
+		switchover_fn,
+	)?;
 
 	let (reduced_claim, rounds) =
 		abstract_sumcheck::prove(claim.poly.n_vars(), zerocheck_prover, challenger)?;
@@ -134,11 +141,15 @@ where
 	/// a different (but isomorphic) packed field PW. `switchover_fn` closure specifies
 	/// switchover round number per multilinear polynomial as a function of its
 	/// [`crate::polynomial::MultilinearPoly::extension_degree`] value.
+	///
+	/// `zerocheck_challenges` are the zerocheck challenges of the whole batch, of which the
+	/// instance takes the ones of the rounds it takes part in under `binding_order`.
 	pub fn new(
 		domain: &'a EvaluationDomain<FS>,
 		claim: ZerocheckClaim<F>,
 		witness: ZerocheckWitness<'a, PW, CW>,
 		zerocheck_challenges: &'a [F],
+		binding_order: BatchBindingOrder,
 		switchover_fn: impl Fn(usize) -> usize,
 	) -> Result<Self, Error> {
 		#[cfg(feature = "debug_validate_sumcheck")]
@@ -156,12 +167,8 @@ where
 			return Err(Error::ProverClaimWitnessMismatch);
 		}
 
-		let needed_challenges = n_vars - 1;
-		if zerocheck_challenges.len() < needed_challenges {
-			return Err(Error::NotEnoughZerocheckChallenges);
-		}
 		let zerocheck_challenges =
-			zerocheck_challenges[zerocheck_challenges.len() - needed_challenges..].as_ref();
+			instance_zerocheck_challenges(zerocheck_challenges, n_vars - 1, binding_order)?;
 
 		let state = ProverState::new(n_vars, witness.multilinears, switchover_fn)?;
 
@@ -242,22 +249,7 @@ where
 	// We obtain the ith hypercube evaluation of $eq_1(X, \alpha_2)$ by summing the $(2*i)$ and $(2*i+1)$
 	// hypercube evaluations of $eq_2(X, \alpha_1, \alpha_2)$.
 	fn update_round_eq_ind(&mut self) -> Result<(), Error> {
-		let current_evals = self.round_eq_ind.evals();
-		let new_evals = (0..current_evals.len() >> 1)
-			.into_par_iter()
-			.map(|i| {
-				PW::from_fn(|j| {
-					let index = i * PW::WIDTH + j;
-					let eval0 = get_packed_slice(current_evals, index << 1);
-					let eval1 = get_packed_slice(current_evals, (index << 1) + 1);
-
-					eval0 + eval1
-				})
-			})
-			.collect();
-		let new_multilin = MultilinearExtension::from_values(new_evals)?;
-		self.round_eq_ind = new_multilin;
-
+		self.round_eq_ind = fold_round_eq_ind(&self.round_eq_ind)?;
 		Ok(())
 	}
 
@@ -416,8 +408,10 @@ where
 	}
 
 	fn reduce_claim(&mut self, prev_rd_challenge: F) -> Result<(), Error> {
+		let round_degrees = vec![self.oracle.max_individual_degree(); self.round];
 		let reductor = ZerocheckReductor {
 			alphas: self.zerocheck_challenges,
+			round_degrees: &round_degrees,
 		};
 		let round_claim = self.round_claim.clone();
 		let round_proof = self
@@ -482,10 +476,12 @@ where
 		ZerocheckProver::finalize(self, prev_rd_challenge)
 	}
 
-	fn batch_proving_consistent(&self, other: &Self) -> bool {
-		let common = other.zerocheck_challenges.len();
-		self.zerocheck_challenges[self.zerocheck_challenges.len() - common..]
-			== *other.zerocheck_challenges
+	fn batch_proving_consistent(&self, other: &Self, binding_order: BatchBindingOrder) -> bool {
+		zerocheck_challenges_consistent(
+			self.zerocheck_challenges,
+			other.zerocheck_challenges,
+			binding_order,
+		)
 	}
 
 	fn n_vars(&self) -> usize {
@@ -686,26 +682,69 @@ where
 	fn round_evals_to_coeffs(
 		&self,
 		current_round_sum: P::Scalar,
-		mut round_evals: Vec<P::Scalar>,
+		round_evals: Vec<P::Scalar>,
 	) -> Result<Vec<P::Scalar>, PolynomialError> {
-		// This is a subsequent round of a sumcheck that came from zerocheck, given $r(1), \ldots, r(d)$
-		// Letting $s$ be the current round's claimed sum, and $\alpha_i$ the ith zerocheck challenge
-		// we have the identity $r(0) = \frac{1}{1 - \alpha_i} * (s - \alpha_i * r(1))$
-		// which allows us to compute the value of $r(0)$
+		later_round_evals_to_coeffs(
+			self.evaluation_domain,
+			self.round_zerocheck_challenge,
+			current_round_sum,
+			round_evals,
+		)
+	}
+}
+
+/// Sum consecutive hypercube evaluations of an equality indicator to drop its lowest variable.
+///
+/// See [`ZerocheckProver`] for how this maintains the round equality indicator invariant.
+pub(super) fn fold_round_eq_ind<PW: PackedField>(
+	eq_ind: &MultilinearExtension<PW>,
+) -> Result<MultilinearExtension<PW>, PolynomialError> {
+	let current_evals = eq_ind.evals();
+	let new_evals = (0..current_evals.len() >> 1)
+		.into_par_iter()
+		.map(|i| {
+			PW::from_fn(|j| {
+				let index = i * PW::WIDTH + j;
+				let eval0 = get_packed_slice(current_evals, index << 1);
+				let eval1 = get_packed_slice(current_evals, (index << 1) + 1);
+
+				eval0 + eval1
+			})
+		})
+		.collect();
+	MultilinearExtension::from_values(new_evals)
+}
 
-		let alpha = self.round_zerocheck_challenge;
-		let alpha_bar = P::Scalar::ONE - alpha;
-		let one_evaluation = round_evals[0];
-		let zero_evaluation_numerator = current_round_sum - one_evaluation * alpha;
-		let zero_evaluation_denominator_inv = alpha_bar.invert().unwrap();
-		let zero_evaluation = zero_evaluation_numerator * zero_evaluation_denominator_inv;
+/// Interpolate the round polynomial of a zerocheck round with a zerocheck challenge.
+///
+/// `round_evals` are the evaluations $r(1), \ldots, r(d)$ on the evaluation domain.
+pub(super) fn later_round_evals_to_coeffs<F, FS>(
+	evaluation_domain: &EvaluationDomain<FS>,
+	round_zerocheck_challenge: F,
+	current_round_sum: F,
+	mut round_evals: Vec<F>,
+) -> Result<Vec<F>, PolynomialError>
+where
+	F: ExtensionField<FS>,
+	FS: Field,
+{
+	// This is a subsequent round of a sumcheck that came from zerocheck, given $r(1), \ldots, r(d)$
+	// Letting $s$ be the current round's claimed sum, and $\alpha_i$ the ith zerocheck challenge
+	// we have the identity $r(0) = \frac{1}{1 - \alpha_i} * (s - \alpha_i * r(1))$
+	// which allows us to compute the value of $r(0)$
 
-		round_evals.insert(0, zero_evaluation);
+	let alpha = round_zerocheck_challenge;
+	let alpha_bar = F::ONE - alpha;
+	let one_evaluation = round_evals[0];
+	let zero_evaluation_numerator = current_round_sum - one_evaluation * alpha;
+	let zero_evaluation_denominator_inv = alpha_bar.invert().unwrap();
+	let zero_evaluation = zero_evaluation_numerator * zero_evaluation_denominator_inv;
 
-		let coeffs = self.evaluation_domain.interpolate(&round_evals)?;
-		// We can omit the constant term safely
-		let coeffs = coeffs[1..].to_vec();
+	round_evals.insert(0, zero_evaluation);
 
-		Ok(coeffs)
-	}
+	let coeffs = evaluation_domain.interpolate(&round_evals)?;
+	// We can omit the constant term safely
+	let coeffs = coeffs[1..].to_vec();
+
+	Ok(coeffs)
 }
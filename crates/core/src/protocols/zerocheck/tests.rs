@@ -8,17 +8,27 @@ use crate::{
 		CommittedBatchSpec, CommittedId, CompositePolyOracle, MultilinearOracleSet,
 		MultilinearPolyOracle,
 	},
-	polynomial::{EvaluationDomain, MultilinearComposite, MultilinearExtension, MultilinearQuery},
+	polynomial::{
+		EvaluationDomain, MultilinearComposite, MultilinearExtension, MultilinearPoly,
+		MultilinearQuery,
+	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		test_utils::TestProductComposition,
 		zerocheck::{
-			self, batch_prove, batch_verify, prove::ZerocheckProver, verify,
-			zerocheck::ZerocheckProveOutput, ZerocheckClaim,
+			self, batch_prove, batch_prove_univariate_skip, batch_verify,
+			batch_verify_univariate_skip, prove::ZerocheckProver, verify,
+			zerocheck::ZerocheckProveOutput, Error, VerificationError, ZerocheckClaim,
+			ZerocheckUnivariateSkipProveOutput, ZerocheckUnivariateSkipProver,
 		},
 	},
 	witness::MultilinearWitnessIndex,
 };
-use binius_field::{BinaryField128b, BinaryField32b, ExtensionField, Field, TowerField};
+use assert_matches::assert_matches;
+use binius_field::{
+	BinaryField128b, BinaryField32b, ExtensionField, Field, PackedBinaryField2x128b,
+	PackedExtension, TowerField,
+};
 use binius_hash::GroestlHasher;
 use p3_challenger::CanSample;
 use p3_util::log2_ceil_usize;
@@ -126,6 +136,19 @@ fn test_prove_verify_interaction_helper(
 	)
 	.expect("failed to prove zerocheck");
 
+	// Round polynomials exclude the current variable's eq factor, so they have degree d
+	// and the prover sends d coefficients per round
+	for round in zerocheck_proof.rounds.iter() {
+		assert_eq!(round.coeffs.len(), n_multilinears);
+	}
+
+	let mut padded_proof = zerocheck_proof.clone();
+	padded_proof.rounds[0].coeffs.push(FE::ZERO);
+	assert_matches!(
+		verify(&zc_claim, padded_proof, verifier_challenger.clone()),
+		Err(Error::Verification(VerificationError::RoundPolynomialDegree))
+	);
+
 	let verified_evalcheck_claim = verify(&zc_claim, zerocheck_proof, &mut verifier_challenger)
 		.expect("failed to verify zerocheck");
 
@@ -266,8 +289,7 @@ where
 // * different number of underlying multilinears
 // * different max individual degree multivariate polynomials
 // * different number of variables
-#[test]
-fn test_prove_verify_batch() {
+fn test_prove_verify_batch_helper(binding_order: BatchBindingOrder) {
 	type F = BinaryField32b;
 	type FE = BinaryField128b;
 	let rng = StdRng::seed_from_u64(0);
@@ -344,15 +366,360 @@ fn test_prove_verify_batch() {
 		.map(|(witness, claim)| {
 			let degree = claim.poly.inner_polys().len();
 			let domain = &domains[degree - 1];
-			ZerocheckProver::<_, FE, _, _>::new(domain, claim, witness, &zc_challenges, |_| 1)
-				.unwrap()
+			ZerocheckProver::<_, FE, _, _>::new(
+				domain,
+				claim,
+				witness,
+				&zc_challenges,
+				binding_order,
+				|_| 1,
+			)
+			.unwrap()
 		})
 		.collect::<Vec<_>>();
 
-	let prove_output = batch_prove(provers, prover_challenger).unwrap();
+	let prove_output = batch_prove(provers, binding_order, prover_challenger).unwrap();
 	let proof = prove_output.proof;
 	assert_eq!(proof.rounds.len(), max_n_vars);
+	for round in proof.rounds.iter() {
+		assert_eq!(round.coeffs.len(), max_degree);
+	}
+
+	let mut padded_proof = proof.clone();
+	padded_proof.rounds[max_n_vars - 1].coeffs.push(FE::ZERO);
+	assert_matches!(
+		batch_verify(
+			claims.iter().cloned(),
+			padded_proof,
+			binding_order,
+			verifier_challenger.clone()
+		),
+		Err(Error::Verification(VerificationError::RoundPolynomialDegree))
+	);
+
+	let evalcheck_claims =
+		batch_verify(claims.iter().cloned(), proof, binding_order, verifier_challenger).unwrap();
+	for (prover_claim, verifier_claim) in prove_output
+		.evalcheck_claims
+		.iter()
+		.zip(evalcheck_claims.iter())
+	{
+		assert_eq!(prover_claim.eval_point, verifier_claim.eval_point);
+		assert_eq!(prover_claim.eval, verifier_claim.eval);
+	}
+}
+
+#[test]
+fn test_prove_verify_batch() {
+	test_prove_verify_batch_helper(BatchBindingOrder::BackLoaded);
+}
+
+#[test]
+fn test_prove_verify_batch_front_loaded() {
+	test_prove_verify_batch_helper(BatchBindingOrder::FrontLoaded);
+}
+
+fn test_prove_verify_univariate_skip_helper<PW>(
+	n_vars: usize,
+	n_multilinears: usize,
+	skip_vars: usize,
+) where
+	PW: PackedExtension<BinaryField32b, Scalar = BinaryField128b>,
+{
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let multilins = generate_poly_helper::<F>(&mut rng, n_vars, n_multilinears);
+	let zc_multilins = multilins
+		.into_iter()
+		.map(|m| m.specialize_arc_dyn::<PW>())
+		.collect();
+	let zc_witness = MultilinearComposite::<PW, _, _>::new(
+		n_vars,
+		TestProductComposition::new(n_multilinears),
+		zc_multilins,
+	)
+	.unwrap();
 
-	let _evalcheck_claims =
-		batch_verify(claims.iter().cloned(), proof, verifier_challenger).unwrap();
+	let mut oracles = MultilinearOracleSet::new();
+	let batch_id = oracles.add_committed_batch(CommittedBatchSpec {
+		n_vars,
+		n_polys: n_multilinears,
+		tower_level: F::TOWER_LEVEL,
+	});
+	let h = (0..n_multilinears)
+		.map(|i| oracles.committed_oracle(CommittedId { batch_id, index: i }))
+		.collect();
+	let composite_poly =
+		CompositePolyOracle::new(n_vars, h, TestProductComposition::new(n_multilinears)).unwrap();
+	let zc_claim = ZerocheckClaim {
+		poly: composite_poly,
+	};
+
+	let skip_domain =
+		EvaluationDomain::<F>::new(n_multilinears * ((1 << skip_vars) - 1) + 1).unwrap();
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let mut verifier_challenger = prover_challenger.clone();
+
+	let ZerocheckUnivariateSkipProveOutput {
+		evalcheck_claims,
+		zerocheck_proof,
+	} = zerocheck::prove_univariate_skip::<FE, PW, F, _, _>(
+		&zc_claim,
+		zc_witness.clone(),
+		skip_vars,
+		&skip_domain,
+		&mut prover_challenger,
+		|_| 1,
+	)
+	.expect("failed to prove zerocheck");
+	assert_eq!(zerocheck_proof.rounds.len(), n_vars - skip_vars);
+
+	let verified_evalcheck_claims = zerocheck::verify_univariate_skip(
+		&zc_claim,
+		skip_vars,
+		zerocheck_proof,
+		&mut verifier_challenger,
+	)
+	.expect("failed to verify zerocheck");
+
+	assert_eq!(evalcheck_claims.len(), n_multilinears);
+	assert_eq!(verified_evalcheck_claims.len(), n_multilinears);
+	for ((evalcheck_claim, verified_evalcheck_claim), multilin) in evalcheck_claims
+		.iter()
+		.zip(verified_evalcheck_claims.iter())
+		.zip(zc_witness.multilinears.iter())
+	{
+		assert_eq!(evalcheck_claim.eval, verified_evalcheck_claim.eval);
+		assert_eq!(evalcheck_claim.eval_point, verified_evalcheck_claim.eval_point);
+		assert_eq!(verified_evalcheck_claim.eval_point.len(), n_vars);
+		assert!(verified_evalcheck_claim.is_random_point);
+
+		let query =
+			MultilinearQuery::with_full_query(&verified_evalcheck_claim.eval_point).unwrap();
+		assert_eq!(multilin.evaluate(&query).unwrap(), verified_evalcheck_claim.eval);
+	}
+}
+
+#[test]
+fn test_zerocheck_prove_verify_univariate_skip() {
+	for n_vars in 2..7 {
+		for n_multilinears in 1..5 {
+			for skip_vars in 1..=n_vars.min(3) {
+				test_prove_verify_univariate_skip_helper::<BinaryField128b>(
+					n_vars,
+					n_multilinears,
+					skip_vars,
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_zerocheck_prove_verify_univariate_skip_packed() {
+	for n_vars in 4..8 {
+		for n_multilinears in 1..5 {
+			for skip_vars in 1..=(n_vars - 3).min(3) {
+				test_prove_verify_univariate_skip_helper::<PackedBinaryField2x128b>(
+					n_vars,
+					n_multilinears,
+					skip_vars,
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn test_zerocheck_univariate_skip_rejects_bad_partial_evals() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let (n_vars, n_multilinears, skip_vars) = (5, 3, 2);
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let CreateClaimsWitnessesOutput {
+		new_claims,
+		new_witnesses,
+		..
+	} = create_claims_witnesses_helper::<F, FE>(
+		rng.clone(),
+		MultilinearOracleSet::new(),
+		MultilinearWitnessIndex::new(),
+		n_vars,
+		n_multilinears,
+		1,
+	);
+	let skip_domain =
+		EvaluationDomain::<F>::new(n_multilinears * ((1 << skip_vars) - 1) + 1).unwrap();
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let verifier_challenger = prover_challenger.clone();
+
+	let mut zerocheck_proof = zerocheck::prove_univariate_skip::<FE, FE, F, _, _>(
+		&new_claims[0],
+		new_witnesses[0].clone(),
+		skip_vars,
+		&skip_domain,
+		&mut prover_challenger,
+		|_| 1,
+	)
+	.unwrap()
+	.zerocheck_proof;
+	zerocheck_proof.partial_evals[1][2] += <FE as Field>::random(&mut rng);
+
+	assert_matches!(
+		zerocheck::verify_univariate_skip(
+			&new_claims[0],
+			skip_vars,
+			zerocheck_proof,
+			verifier_challenger
+		),
+		Err(Error::PartialEvaluationCheckFailed)
+	);
+}
+
+#[test]
+fn test_zerocheck_univariate_skip_rejects_long_rounds() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let (n_vars, n_multilinears, skip_vars) = (5, 3, 2);
+
+	let CreateClaimsWitnessesOutput {
+		new_claims,
+		new_witnesses,
+		..
+	} = create_claims_witnesses_helper::<F, FE>(
+		StdRng::seed_from_u64(0),
+		MultilinearOracleSet::new(),
+		MultilinearWitnessIndex::new(),
+		n_vars,
+		n_multilinears,
+		1,
+	);
+	let skip_domain =
+		EvaluationDomain::<F>::new(n_multilinears * ((1 << skip_vars) - 1) + 1).unwrap();
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let verifier_challenger = prover_challenger.clone();
+
+	let mut zerocheck_proof = zerocheck::prove_univariate_skip::<FE, FE, F, _, _>(
+		&new_claims[0],
+		new_witnesses[0].clone(),
+		skip_vars,
+		&skip_domain,
+		&mut prover_challenger,
+		|_| 1,
+	)
+	.unwrap()
+	.zerocheck_proof;
+	zerocheck_proof.rounds[1].coeffs.push(FE::ZERO);
+
+	assert_matches!(
+		zerocheck::verify_univariate_skip(
+			&new_claims[0],
+			skip_vars,
+			zerocheck_proof,
+			verifier_challenger
+		),
+		Err(Error::Verification(VerificationError::RoundPolynomialDegree))
+	);
+}
+
+#[test]
+fn test_prove_verify_batch_univariate_skip() {
+	type F = BinaryField32b;
+	type FE = BinaryField128b;
+	let skip_vars = 2;
+	let mut prover_challenger = <HashChallenger<_, GroestlHasher<_>>>::new();
+	let verifier_challenger = prover_challenger.clone();
+
+	// Two claims on 4 variables of degrees 2 and 3, and one claim on 6 variables of degree 4
+	let CreateClaimsWitnessesOutput {
+		mut new_claims,
+		mut new_witnesses,
+		oracle_set,
+		witness_index,
+		rng,
+	} = create_claims_witnesses_helper::<F, FE>(
+		StdRng::seed_from_u64(0),
+		MultilinearOracleSet::new(),
+		MultilinearWitnessIndex::new(),
+		4,
+		2,
+		2,
+	);
+	let CreateClaimsWitnessesOutput {
+		new_claims: more_claims,
+		new_witnesses: more_witnesses,
+		witness_index,
+		..
+	} = create_claims_witnesses_helper::<F, FE>(rng, oracle_set, witness_index, 6, 4, 1);
+	new_claims.extend(more_claims);
+	new_witnesses.extend(more_witnesses);
+
+	let max_n_vars = 6;
+	let skip_domains = (1..=4)
+		.map(|degree| EvaluationDomain::<FE>::new(degree * ((1 << skip_vars) - 1) + 1).unwrap())
+		.collect::<Vec<_>>();
+	let zc_challenges = prover_challenger.sample_vec(max_n_vars - skip_vars);
+
+	let provers = new_witnesses
+		.into_iter()
+		.zip(new_claims.clone())
+		.map(|(witness, claim)| {
+			let degree = claim.poly.max_individual_degree();
+			ZerocheckUnivariateSkipProver::<_, FE, _, _>::new(
+				&skip_domains[degree - 1],
+				claim,
+				witness,
+				skip_vars,
+				&zc_challenges,
+				BatchBindingOrder::BackLoaded,
+			)
+			.unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let prove_output = batch_prove_univariate_skip(
+		provers,
+		BatchBindingOrder::BackLoaded,
+		prover_challenger,
+		|_| 1,
+	)
+	.unwrap();
+	assert_eq!(prove_output.proof.proof.rounds.len(), max_n_vars - skip_vars);
+
+	let mut padded_proof = prove_output.proof.clone();
+	padded_proof.proof.rounds[0].coeffs.push(FE::ZERO);
+	assert_matches!(
+		batch_verify_univariate_skip(
+			new_claims.iter().cloned(),
+			skip_vars,
+			padded_proof,
+			BatchBindingOrder::BackLoaded,
+			verifier_challenger.clone(),
+		),
+		Err(Error::Verification(VerificationError::RoundPolynomialDegree))
+	);
+
+	let evalcheck_claims = batch_verify_univariate_skip(
+		new_claims.iter().cloned(),
+		skip_vars,
+		prove_output.proof,
+		BatchBindingOrder::BackLoaded,
+		verifier_challenger,
+	)
+	.unwrap();
+	assert_eq!(evalcheck_claims.len(), new_claims.len());
+
+	for (claim, claim_evalcheck_claims) in new_claims.iter().zip(evalcheck_claims.iter()) {
+		assert_eq!(claim_evalcheck_claims.len(), claim.poly.n_multilinears());
+		for (multilin_oracle, evalcheck_claim) in
+			claim.poly.inner_polys().iter().zip(claim_evalcheck_claims)
+		{
+			let multilin = witness_index.get(multilin_oracle.id()).unwrap();
+			let query = MultilinearQuery::with_full_query(&evalcheck_claim.eval_point).unwrap();
+			assert_eq!(multilin.evaluate(&query).unwrap(), evalcheck_claim.eval);
+		}
+	}
 }
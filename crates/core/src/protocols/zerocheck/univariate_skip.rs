@@ -0,0 +1,1065 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Univariate skip for the zerocheck protocol, following [Gruen24], Section 5.
+//!
+//! The first $k$ rounds of zerocheck are replaced with a single univariate round. The $2^k$
+//! hypercube vertices of the low $k$ variables are identified with the first $2^k$ points $D$ of
+//! a small-field evaluation domain, and each multilinear $f$ is replaced by the polynomial
+//! $\hat{f}(Z, x)$ of degree less than $2^k$ in $Z$ that agrees with $f$ on $D$. The univariate
+//! round polynomial
+//!
+//! $$r(Z) = \sum_{x \in \{0, 1\}^{n-k}} eq(\alpha, x) C(\hat{f}_0(Z, x), \ldots)$$
+//!
+//! vanishes on $D$, so the prover sends the quotient $q(Z) = r(Z) / Z_D(Z)$ by the vanishing
+//! polynomial of $D$. Computing $q$ only takes small-field linear combinations of the witness
+//! values, which is where the savings over $k$ ordinary rounds come from when the witness is over
+//! a small field.
+//!
+//! After the verifier samples a challenge $z$, the multilinears are folded into
+//! $\hat{f}(z, x)$ and the remaining $n - k$ variables are reduced by zerocheck rounds, all of
+//! which use the zerocheck challenge identity since $r(z)$ is in general not zero. Finally, the
+//! prover sends the $2^k$ values $f(y, \rho)$ for $y \in \{0, 1\}^k$ of every multilinear, from
+//! which the verifier recomputes $\hat{f}(z, \rho)$ to check the final sumcheck claim. After
+//! sampling $k$ more challenges $\rho'$ the verifier obtains evalcheck claims on every multilinear
+//! at $(\rho', \rho)$.
+//!
+//! [Gruen24]: https://eprint.iacr.org/2024/108
+
+use super::{
+	error::{Error, VerificationError},
+	prove::{fold_round_eq_ind, later_round_evals_to_coeffs},
+	zerocheck::{
+		instance_zerocheck_challenges, reduce_intermediate_round_claim_helper,
+		zerocheck_challenges_consistent, ZerocheckClaim, ZerocheckRound, ZerocheckRoundClaim,
+		ZerocheckWitness,
+	},
+};
+use crate::{
+	challenger::{CanObserve, CanSample},
+	oracle::CompositePolyOracle,
+	polynomial::{
+		evaluate_univariate, extrapolate_line, transparent::eq_ind::EqIndPartialEval,
+		CompositionPoly, Error as PolynomialError, EvaluationDomain, MultilinearExtension,
+		MultilinearExtensionSpecialized, MultilinearPoly, MultilinearQuery,
+	},
+	protocols::{
+		abstract_sumcheck::{
+			self, check_evaluation_domain, validate_rd_challenge, AbstractSumcheckClaim,
+			AbstractSumcheckEvaluator, AbstractSumcheckProof, AbstractSumcheckProver,
+			AbstractSumcheckReductor, AbstractSumcheckRoundClaim, BatchBindingOrder, ProverState,
+			ReducedClaim,
+		},
+		evalcheck::EvalcheckClaim,
+	},
+	witness::MultilinearWitness,
+};
+use binius_field::{
+	packed::get_packed_slice, ExtensionField, Field, PackedExtension, PackedField, TowerField,
+};
+use getset::Getters;
+use rayon::prelude::*;
+use std::iter::Step;
+use tracing::instrument;
+
+#[derive(Debug, Clone)]
+pub struct ZerocheckUnivariateSkipProof<F> {
+	/// Monomial coefficients of the univariate round polynomial divided by the vanishing
+	/// polynomial of the skipped vertices.
+	pub univariate_round: ZerocheckRound<F>,
+	/// Round messages for the variables that were not skipped.
+	pub rounds: Vec<ZerocheckRound<F>>,
+	/// For every multilinear, its evaluations at the skipped hypercube vertices in the low
+	/// variables and the sumcheck challenges in the high variables.
+	pub partial_evals: Vec<Vec<F>>,
+}
+
+#[derive(Debug)]
+pub struct ZerocheckUnivariateSkipProveOutput<F: Field> {
+	/// Evalcheck claims on each multilinear of the composite, in order.
+	pub evalcheck_claims: Vec<EvalcheckClaim<F>>,
+	pub zerocheck_proof: ZerocheckUnivariateSkipProof<F>,
+}
+
+/// Prove a zerocheck to evalcheck reduction, skipping the first `skip_vars` variables with a
+/// univariate round.
+///
+/// `skip_domain` is the evaluation domain of the univariate round. It must have
+/// $d (2^k - 1) + 1$ points, where the first $2^k$ are the canonical domain points mapped into
+/// `FS`, as produced by [`crate::polynomial::IsomorphicEvaluationDomainFactory`].
+#[instrument(skip_all, name = "zerocheck::prove_univariate_skip")]
+pub fn prove_univariate_skip<'a, F, PW, FS, CW, CH>(
+	claim: &ZerocheckClaim<F>,
+	witness: ZerocheckWitness<'a, PW, CW>,
+	skip_vars: usize,
+	skip_domain: &EvaluationDomain<FS>,
+	mut challenger: CH,
+	switchover_fn: impl Fn(usize) -> usize,
+) -> Result<ZerocheckUnivariateSkipProveOutput<F>, Error>
+where
+	F: TowerField + Step + From<PW::Scalar>,
+	PW: PackedExtension<FS>,
+	PW::Scalar: TowerField + From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+	CH: CanSample<F> + CanObserve<F>,
+{
+	let n_vars = witness.n_vars();
+	let zerocheck_challenges = challenger.sample_vec(n_vars.saturating_sub(skip_vars));
+
+	let mut prover = ZerocheckUnivariateSkipProver::<F, PW, FS, _>::new(
+		skip_domain,
+		claim.clone(),
+		witness,
+		skip_vars,
+		&zerocheck_challenges,
+		BatchBindingOrder::BackLoaded,
+	)?;
+
+	let univariate_round = prover.execute_univariate_round()?;
+	challenger.observe_slice(&univariate_round.coeffs);
+	let prover = prover.fold_univariate_round(challenger.sample(), switchover_fn)?;
+	let multilinears = prover.multilinears().clone();
+
+	let (reduced_claim, rounds) =
+		abstract_sumcheck::prove(n_vars - skip_vars, prover, &mut challenger)?;
+
+	let partial_evals = compute_partial_evals(&multilinears, skip_vars, &reduced_claim.eval_point)?;
+
+	for evals in partial_evals.iter() {
+		challenger.observe_slice(evals);
+	}
+	let skipped_challenges = challenger.sample_vec(skip_vars);
+	let evalcheck_claims = make_evalcheck_claims(
+		&claim.poly,
+		&partial_evals,
+		&skipped_challenges,
+		&reduced_claim.eval_point,
+	)?;
+
+	Ok(ZerocheckUnivariateSkipProveOutput {
+		evalcheck_claims,
+		zerocheck_proof: ZerocheckUnivariateSkipProof {
+			univariate_round,
+			rounds,
+			partial_evals,
+		},
+	})
+}
+
+/// Verify a zerocheck to evalcheck reduction with a univariate skip of `skip_vars` variables.
+#[instrument(skip_all, name = "zerocheck::verify_univariate_skip")]
+pub fn verify_univariate_skip<F, CH>(
+	claim: &ZerocheckClaim<F>,
+	skip_vars: usize,
+	proof: ZerocheckUnivariateSkipProof<F>,
+	mut challenger: CH,
+) -> Result<Vec<EvalcheckClaim<F>>, Error>
+where
+	F: TowerField + Step,
+	CH: CanSample<F> + CanObserve<F>,
+{
+	let degree = claim.poly.max_individual_degree();
+	if degree == 0 {
+		return Err(Error::PolynomialDegreeIsZero);
+	}
+
+	let n_vars = claim.n_vars();
+	if skip_vars == 0 || skip_vars > n_vars {
+		return Err(Error::InvalidSkipVars);
+	}
+
+	let ZerocheckUnivariateSkipProof {
+		univariate_round,
+		rounds,
+		partial_evals,
+	} = proof;
+
+	if rounds.len() != n_vars - skip_vars {
+		return Err(VerificationError::NumberOfRounds.into());
+	}
+
+	let zerocheck_challenges = challenger.sample_vec(n_vars - skip_vars);
+
+	let vertex_domain = EvaluationDomain::<F>::new(1 << skip_vars)?;
+	check_univariate_round(degree, skip_vars, &univariate_round)?;
+	challenger.observe_slice(&univariate_round.coeffs);
+	let univariate_challenge = challenger.sample();
+
+	let sum = evaluate_univariate_round(
+		vertex_domain.points().iter().copied(),
+		&univariate_round.coeffs,
+		univariate_challenge,
+	);
+	let sumcheck_claim = AbstractSumcheckClaim {
+		n_vars: n_vars - skip_vars,
+		sum,
+	};
+	let round_degrees = vec![degree; n_vars - skip_vars];
+	let reductor = ZerocheckUnivariateSkipReductor {
+		alphas: &zerocheck_challenges,
+		round_degrees: &round_degrees,
+	};
+	let reduced_claim = abstract_sumcheck::verify(
+		sumcheck_claim,
+		AbstractSumcheckProof { rounds },
+		reductor,
+		&mut challenger,
+	)?;
+
+	let lagrange_evals = vertex_domain.lagrange_evals(univariate_challenge);
+	check_partial_evals(&claim.poly, &lagrange_evals, &partial_evals, reduced_claim.eval)?;
+
+	for evals in partial_evals.iter() {
+		challenger.observe_slice(evals);
+	}
+	let skipped_challenges = challenger.sample_vec(skip_vars);
+	make_evalcheck_claims(
+		&claim.poly,
+		&partial_evals,
+		&skipped_challenges,
+		&reduced_claim.eval_point,
+	)
+}
+
+/// Reductor for the zerocheck rounds that follow a univariate skip.
+///
+/// Unlike the regular zerocheck reductor, every round has a zerocheck challenge, as the
+/// claimed sum after the univariate round is in general not zero.
+pub struct ZerocheckUnivariateSkipReductor<'a, F> {
+	pub alphas: &'a [F],
+	/// The degree of the round polynomial of each round, which is the largest degree among the
+	/// claims taking part in the round.
+	pub round_degrees: &'a [usize],
+}
+
+impl<'a, F: Field> AbstractSumcheckReductor<F> for ZerocheckUnivariateSkipReductor<'a, F> {
+	type Error = Error;
+
+	fn reduce_round_claim(
+		&self,
+		round: usize,
+		claim: AbstractSumcheckRoundClaim<F>,
+		challenge: F,
+		round_proof: ZerocheckRound<F>,
+	) -> Result<AbstractSumcheckRoundClaim<F>, Self::Error> {
+		if round != claim.partial_point.len() {
+			return Err(Error::RoundArgumentRoundClaimMismatch);
+		}
+		let alpha_i = self
+			.alphas
+			.get(round)
+			.copied()
+			.ok_or(VerificationError::ExpectedZerocheckChallengeNotFound)?;
+		let degree = *self
+			.round_degrees
+			.get(round)
+			.ok_or(VerificationError::NumberOfRounds)?;
+
+		reduce_intermediate_round_claim_helper(claim, challenge, round_proof, Some(alpha_i), degree)
+	}
+}
+
+/// A zerocheck prover for the univariate round of the univariate skip.
+///
+/// The prover is instantiated via `new`, followed by one `execute_univariate_round` call. After
+/// the verifier challenge is sampled, `fold_univariate_round` yields the
+/// [`ZerocheckUnivariateSkipFoldedProver`] for the remaining rounds.
+#[derive(Debug, Getters)]
+pub struct ZerocheckUnivariateSkipProver<'a, F, PW, FS, CW>
+where
+	F: Field + From<PW::Scalar>,
+	PW: PackedField,
+	PW::Scalar: From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+{
+	#[getset(get = "pub")]
+	oracle: CompositePolyOracle<F>,
+	composition: CW,
+	multilinears: Vec<MultilinearWitness<'a, PW>>,
+	#[getset(get_copy = "pub")]
+	skip_vars: usize,
+	skip_domain: &'a EvaluationDomain<FS>,
+	// The first 2^skip_vars points of the skip domain, identified with the skipped vertices
+	vertex_domain: EvaluationDomain<FS>,
+	zerocheck_challenges: &'a [F],
+	univariate_round_proof: Option<ZerocheckRound<F>>,
+}
+
+impl<'a, F, PW, FS, CW> ZerocheckUnivariateSkipProver<'a, F, PW, FS, CW>
+where
+	F: TowerField + Step + From<PW::Scalar>,
+	PW: PackedExtension<FS>,
+	PW::Scalar: From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+{
+	/// Start a new zerocheck instance with a univariate skip of the low `skip_vars` variables.
+	///
+	/// `zerocheck_challenges` are the zerocheck challenges of the whole batch, of which the
+	/// instance takes the ones of the rounds it takes part in under `binding_order`.
+	///
+	/// ## Throws
+	///
+	/// * `InvalidSkipVars` if `skip_vars` is zero, or leaves fewer high variables than the
+	///   packing width of `PW`
+	/// * `UnivariateSkipDomainMismatch` if `skip_domain` does not have the required size or does
+	///   not start with the canonical points
+	pub fn new(
+		skip_domain: &'a EvaluationDomain<FS>,
+		claim: ZerocheckClaim<F>,
+		witness: ZerocheckWitness<'a, PW, CW>,
+		skip_vars: usize,
+		zerocheck_challenges: &'a [F],
+		binding_order: BatchBindingOrder,
+	) -> Result<Self, Error> {
+		let n_vars = claim.n_vars();
+		let degree = claim.poly.max_individual_degree();
+
+		if degree == 0 {
+			return Err(Error::PolynomialDegreeIsZero);
+		}
+		if witness.n_vars() != n_vars {
+			return Err(Error::ProverClaimWitnessMismatch);
+		}
+		if skip_vars == 0 || skip_vars > n_vars || n_vars - skip_vars < PW::LOG_WIDTH {
+			return Err(Error::InvalidSkipVars);
+		}
+
+		let n_vertices = 1 << skip_vars;
+		if skip_domain.size() != degree * (n_vertices - 1) + 1 {
+			return Err(Error::UnivariateSkipDomainMismatch);
+		}
+		let canonical_domain = EvaluationDomain::<F>::new(n_vertices)?;
+		let is_canonical = skip_domain.points()[..n_vertices]
+			.iter()
+			.zip(canonical_domain.points())
+			.all(|(&point, &canonical_point)| F::from(PW::Scalar::from(point)) == canonical_point);
+		if !is_canonical {
+			return Err(Error::UnivariateSkipDomainMismatch);
+		}
+		let vertex_domain =
+			EvaluationDomain::from_points(skip_domain.points()[..n_vertices].to_vec())?;
+
+		let zerocheck_challenges =
+			instance_zerocheck_challenges(zerocheck_challenges, n_vars - skip_vars, binding_order)?;
+
+		Ok(Self {
+			oracle: claim.poly,
+			composition: witness.composition,
+			multilinears: witness.multilinears,
+			skip_vars,
+			skip_domain,
+			vertex_domain,
+			zerocheck_challenges,
+			univariate_round_proof: None,
+		})
+	}
+
+	/// Compute the univariate round message.
+	///
+	/// The univariate round polynomial $r(Z)$ has degree $d (2^k - 1)$ and vanishes on the
+	/// skipped vertices, so it is determined by its values on the remaining points of the skip
+	/// domain. At each of those points, the univariatized multilinears are small-field linear
+	/// combinations of the witness values on the skipped vertices.
+	#[instrument(skip_all, name = "zerocheck::execute_univariate_round")]
+	pub fn execute_univariate_round(&mut self) -> Result<ZerocheckRound<F>, Error> {
+		if self.univariate_round_proof.is_some() {
+			return Err(Error::TooManyExecuteRoundCalls);
+		}
+
+		let n_vertices = 1 << self.skip_vars;
+		let n_vars_high = self.oracle.n_vars() - self.skip_vars;
+		let extra_points = &self.skip_domain.points()[n_vertices..];
+
+		let coeffs = if extra_points.is_empty() {
+			// The multilinear case, where r(Z) has degree less than the number of vertices
+			Vec::new()
+		} else {
+			let lagrange_evals = extra_points
+				.iter()
+				.map(|&point| self.vertex_domain.lagrange_evals(point))
+				.collect::<Vec<_>>();
+
+			// eq(alpha, x) factors into a packed table over the low chunk variables of x and a
+			// scalar table over the chunk index
+			let chunk_vars = univariate_skip_chunk_vars::<PW>(n_vars_high);
+			let chunk_len = (1 << chunk_vars) / PW::WIDTH;
+			let pw_challenges = self
+				.zerocheck_challenges
+				.iter()
+				.map(|&f| f.into())
+				.collect::<Vec<PW::Scalar>>();
+			let (chunk_challenges, chunk_index_challenges) = pw_challenges.split_at(chunk_vars);
+			let eq_chunk =
+				MultilinearQuery::<PW>::with_full_query(chunk_challenges)?.into_expansion();
+			let eq_chunk_index =
+				MultilinearQuery::<PW::Scalar>::with_full_query(chunk_index_challenges)?
+					.into_expansion();
+
+			let n_multilinears = self.multilinears.len();
+			let round_evals = (0..1 << (n_vars_high - chunk_vars))
+				.into_par_iter()
+				.try_fold(
+					|| {
+						UnivariateRoundState::<PW>::new(
+							n_multilinears,
+							n_vertices,
+							chunk_len,
+							extra_points.len(),
+						)
+					},
+					|mut state, chunk_index| -> Result<_, Error> {
+						for (multilin, columns) in self.multilinears.iter().zip(
+							state
+								.vertex_columns
+								.chunks_exact_mut(n_vertices * chunk_len),
+						) {
+							read_vertex_columns(
+								multilin,
+								self.skip_vars,
+								chunk_vars,
+								chunk_index,
+								&mut state.subcube,
+								columns,
+							)?;
+						}
+
+						for (round_eval, lagrange_evals) in
+							state.round_evals.iter_mut().zip(lagrange_evals.iter())
+						{
+							// The univariatized multilinears at the extra point are small-field
+							// linear combinations of the vertex columns
+							for (evals_z, columns) in state
+								.evals_z
+								.chunks_exact_mut(chunk_len)
+								.zip(state.vertex_columns.chunks_exact(n_vertices * chunk_len))
+							{
+								extrapolate_vertex_columns::<PW, FS>(
+									columns,
+									lagrange_evals,
+									evals_z,
+								);
+							}
+
+							let mut chunk_eval = PW::zero();
+							for (i, &eq_chunk_factor) in eq_chunk.iter().enumerate() {
+								for (query, evals_z) in state
+									.query
+									.iter_mut()
+									.zip(state.evals_z.chunks_exact(chunk_len))
+								{
+									*query = evals_z[i];
+								}
+								chunk_eval +=
+									self.composition.evaluate(&state.query)? * eq_chunk_factor;
+							}
+							*round_eval +=
+								chunk_eval.iter().sum::<PW::Scalar>() * eq_chunk_index[chunk_index];
+						}
+
+						Ok(state)
+					},
+				)
+				.map(|state| state.map(|state| state.round_evals))
+				.try_reduce(
+					|| vec![PW::Scalar::ZERO; extra_points.len()],
+					|mut overall_round_evals, partial_round_evals| {
+						overall_round_evals
+							.iter_mut()
+							.zip(partial_round_evals.iter())
+							.for_each(|(f, s)| *f += s);
+						Ok(overall_round_evals)
+					},
+				)?;
+
+			// Divide out the vanishing polynomial of the vertices, which is non-zero outside them
+			let quotient_evals = round_evals
+				.into_iter()
+				.zip(extra_points.iter())
+				.map(|(round_eval, &point)| {
+					let vanishing_eval = self
+						.vertex_domain
+						.points()
+						.iter()
+						.map(|&vertex| point - vertex)
+						.product::<FS>();
+					round_eval * vanishing_eval.invert().expect("domain points are distinct")
+				})
+				.collect::<Vec<_>>();
+
+			let quotient_domain = EvaluationDomain::from_points(extra_points.to_vec())?;
+			quotient_domain.interpolate(&quotient_evals)?
+		};
+
+		let proof_round = ZerocheckRound {
+			coeffs: coeffs.into_iter().map(Into::into).collect(),
+		};
+		self.univariate_round_proof = Some(proof_round.clone());
+
+		Ok(proof_round)
+	}
+
+	/// Fold the skipped variables with the univariate round challenge.
+	///
+	/// `switchover_fn` is applied to the folded multilinears, which are in the large field.
+	#[instrument(skip_all, name = "zerocheck::fold_univariate_round")]
+	pub fn fold_univariate_round(
+		self,
+		challenge: F,
+		switchover_fn: impl Fn(usize) -> usize,
+	) -> Result<ZerocheckUnivariateSkipFoldedProver<'a, F, PW, FS, CW>, Error> {
+		let univariate_round_proof = self
+			.univariate_round_proof
+			.ok_or(Error::PrematureFinalizeCall)?;
+
+		let n_vertices = 1 << self.skip_vars;
+		let n_vars_high = self.oracle.n_vars() - self.skip_vars;
+		let lagrange_evals = self
+			.vertex_domain
+			.lagrange_evals(PW::Scalar::from(challenge));
+
+		let chunk_vars = univariate_skip_chunk_vars::<PW>(n_vars_high);
+		let chunk_len = (1 << chunk_vars) / PW::WIDTH;
+		let folded_multilinears = self
+			.multilinears
+			.iter()
+			.map(|multilin| {
+				let mut packed_evals = vec![PW::zero(); (1 << n_vars_high) / PW::WIDTH];
+				packed_evals
+					.par_chunks_exact_mut(chunk_len)
+					.enumerate()
+					.try_for_each_init(
+						|| {
+							(
+								vec![PW::zero(); n_vertices * chunk_len],
+								vec![PW::zero(); n_vertices * chunk_len],
+							)
+						},
+						|(subcube, columns), (chunk_index, folded)| {
+							read_vertex_columns(
+								multilin,
+								self.skip_vars,
+								chunk_vars,
+								chunk_index,
+								subcube,
+								columns,
+							)?;
+							for (column, &lagrange_eval) in
+								columns.chunks_exact(chunk_len).zip(lagrange_evals.iter())
+							{
+								for (folded, &eval) in folded.iter_mut().zip(column.iter()) {
+									*folded += eval * lagrange_eval;
+								}
+							}
+							Ok::<_, PolynomialError>(())
+						},
+					)?;
+				Ok(MultilinearExtension::from_values(packed_evals)?.specialize::<PW>())
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let vertex_points = self
+			.vertex_domain
+			.points()
+			.iter()
+			.map(|&point| F::from(PW::Scalar::from(point)));
+		let round_claim = ZerocheckRoundClaim {
+			partial_point: Vec::new(),
+			current_round_sum: evaluate_univariate_round(
+				vertex_points,
+				&univariate_round_proof.coeffs,
+				challenge,
+			),
+		};
+
+		let degree = self.oracle.max_individual_degree();
+		let round_domain =
+			EvaluationDomain::from_points(self.skip_domain.points()[..degree + 1].to_vec())?;
+		check_evaluation_domain(degree, &round_domain)?;
+
+		let pw_challenges = self
+			.zerocheck_challenges
+			.iter()
+			.skip(1)
+			.map(|&f| f.into())
+			.collect::<Vec<PW::Scalar>>();
+		let round_eq_ind = EqIndPartialEval::new(n_vars_high.saturating_sub(1), pw_challenges)?
+			.multilinear_extension()?;
+
+		let state = ProverState::new(n_vars_high, folded_multilinears, switchover_fn)?;
+
+		Ok(ZerocheckUnivariateSkipFoldedProver {
+			oracle: self.oracle,
+			composition: self.composition,
+			multilinears: self.multilinears,
+			skip_vars: self.skip_vars,
+			round_domain,
+			round_claim,
+			round: 0,
+			last_round_proof: None,
+			state,
+			zerocheck_challenges: self.zerocheck_challenges,
+			round_eq_ind,
+		})
+	}
+}
+
+/// A zerocheck prover for the rounds following the univariate round of the univariate skip.
+///
+/// The folded prover runs $n\\_vars - skip\\_vars$ rounds via the [`AbstractSumcheckProver`]
+/// interface, and may be batched with other folded provers through
+/// [`abstract_sumcheck::batch_prove`].
+#[derive(Debug, Getters)]
+pub struct ZerocheckUnivariateSkipFoldedProver<'a, F, PW, FS, CW>
+where
+	F: Field + From<PW::Scalar>,
+	PW: PackedField,
+	PW::Scalar: From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+{
+	#[getset(get = "pub")]
+	oracle: CompositePolyOracle<F>,
+	composition: CW,
+	#[getset(get = "pub")]
+	multilinears: Vec<MultilinearWitness<'a, PW>>,
+	#[getset(get_copy = "pub")]
+	skip_vars: usize,
+	round_domain: EvaluationDomain<FS>,
+	#[getset(get = "pub")]
+	round_claim: ZerocheckRoundClaim<F>,
+
+	round: usize,
+	last_round_proof: Option<ZerocheckRound<F>>,
+	state: ProverState<PW, MultilinearExtensionSpecialized<PW, PW>>,
+
+	zerocheck_challenges: &'a [F],
+	round_eq_ind: MultilinearExtension<PW>,
+}
+
+impl<'a, F, PW, FS, CW> ZerocheckUnivariateSkipFoldedProver<'a, F, PW, FS, CW>
+where
+	F: Field + From<PW::Scalar>,
+	PW: PackedField,
+	PW::Scalar: From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+{
+	#[instrument(skip_all, name = "zerocheck::finalize_univariate_skip")]
+	fn finalize(mut self, prev_rd_challenge: Option<F>) -> Result<ReducedClaim<F>, Error> {
+		validate_rd_challenge(prev_rd_challenge, self.round)?;
+
+		if self.round != self.n_vars() {
+			return Err(Error::PrematureFinalizeCall);
+		}
+
+		if let Some(prev_rd_challenge) = prev_rd_challenge {
+			self.reduce_claim(prev_rd_challenge)?;
+		}
+
+		Ok(self.round_claim.into())
+	}
+
+	#[instrument(skip_all, name = "zerocheck::execute_univariate_skip_round")]
+	fn execute_round(&mut self, prev_rd_challenge: Option<F>) -> Result<ZerocheckRound<F>, Error> {
+		validate_rd_challenge(prev_rd_challenge, self.round)?;
+
+		if self.round >= self.n_vars() {
+			return Err(Error::TooManyExecuteRoundCalls);
+		}
+
+		if let Some(prev_rd_challenge) = prev_rd_challenge {
+			self.state.fold(prev_rd_challenge.into())?;
+			self.round_eq_ind = fold_round_eq_ind(&self.round_eq_ind)?;
+			self.reduce_claim(prev_rd_challenge)?;
+		}
+
+		let rd_vars = self.n_vars() - self.round;
+		let vertex_state_iterator = (0..1 << (rd_vars - 1)).into_par_iter().map(|_i| ());
+
+		let evaluator = ZerocheckUnivariateSkipEvaluator {
+			composition: &self.composition,
+			domain_points: self.round_domain.points(),
+			evaluation_domain: &self.round_domain,
+			degree: self.oracle.max_individual_degree(),
+			eq_ind: self.round_eq_ind.to_ref(),
+			round_zerocheck_challenge: self.zerocheck_challenges[self.round].into(),
+		};
+		let round_coeffs = self.state.calculate_round_coeffs(
+			evaluator,
+			self.round_claim.current_round_sum.into(),
+			vertex_state_iterator,
+		)?;
+
+		let proof_round = ZerocheckRound {
+			coeffs: round_coeffs.into_iter().map(Into::into).collect(),
+		};
+		self.last_round_proof = Some(proof_round.clone());
+
+		self.round += 1;
+
+		Ok(proof_round)
+	}
+
+	fn reduce_claim(&mut self, prev_rd_challenge: F) -> Result<(), Error> {
+		let round_degrees = vec![self.oracle.max_individual_degree(); self.round];
+		let reductor = ZerocheckUnivariateSkipReductor {
+			alphas: self.zerocheck_challenges,
+			round_degrees: &round_degrees,
+		};
+		let round_claim = self.round_claim.clone();
+		let round_proof = self
+			.last_round_proof
+			.as_ref()
+			.expect("round is at least 1 by invariant")
+			.clone();
+
+		self.round_claim = reductor.reduce_round_claim(
+			self.round - 1,
+			round_claim,
+			prev_rd_challenge,
+			round_proof,
+		)?;
+
+		Ok(())
+	}
+}
+
+impl<'a, F, PW, FS, CW> AbstractSumcheckProver<F>
+	for ZerocheckUnivariateSkipFoldedProver<'a, F, PW, FS, CW>
+where
+	F: Field + From<PW::Scalar>,
+	PW: PackedField,
+	PW::Scalar: From<F> + ExtensionField<FS>,
+	FS: Field,
+	CW: CompositionPoly<PW>,
+{
+	type Error = Error;
+
+	fn execute_round(
+		&mut self,
+		prev_rd_challenge: Option<F>,
+	) -> Result<ZerocheckRound<F>, Self::Error> {
+		ZerocheckUnivariateSkipFoldedProver::execute_round(self, prev_rd_challenge)
+	}
+
+	fn finalize(self, prev_rd_challenge: Option<F>) -> Result<ReducedClaim<F>, Self::Error> {
+		ZerocheckUnivariateSkipFoldedProver::finalize(self, prev_rd_challenge)
+	}
+
+	fn batch_proving_consistent(&self, other: &Self, binding_order: BatchBindingOrder) -> bool {
+		self.skip_vars == other.skip_vars
+			&& zerocheck_challenges_consistent(
+				self.zerocheck_challenges,
+				other.zerocheck_challenges,
+				binding_order,
+			)
+	}
+
+	fn n_vars(&self) -> usize {
+		self.oracle.n_vars() - self.skip_vars
+	}
+}
+
+/// Evaluator for the zerocheck rounds following a univariate skip.
+///
+/// The round polynomial is evaluated at $X = 1, \ldots, d$, with $r(0)$ recovered from the
+/// zerocheck challenge identity as in the later rounds of the regular zerocheck prover.
+#[derive(Debug)]
+pub struct ZerocheckUnivariateSkipEvaluator<'a, P, FS, C>
+where
+	P: PackedField<Scalar: ExtensionField<FS>>,
+	FS: Field,
+	C: CompositionPoly<P>,
+{
+	pub composition: &'a C,
+	pub domain_points: &'a [FS],
+	pub evaluation_domain: &'a EvaluationDomain<FS>,
+	pub degree: usize,
+	pub eq_ind: MultilinearExtension<P, &'a [P]>,
+	pub round_zerocheck_challenge: P::Scalar,
+}
+
+impl<'a, P, FS, C> AbstractSumcheckEvaluator<P> for ZerocheckUnivariateSkipEvaluator<'a, P, FS, C>
+where
+	P: PackedField<Scalar: ExtensionField<FS>>,
+	FS: Field,
+	C: CompositionPoly<P>,
+{
+	type VertexState = ();
+
+	fn n_round_evals(&self) -> usize {
+		self.degree
+	}
+
+	fn process_vertex(
+		&self,
+		i: usize,
+		_vertex_state: Self::VertexState,
+		evals_0: &[P::Scalar],
+		evals_1: &[P::Scalar],
+		evals_z: &mut [P::Scalar],
+		round_evals: &mut [P::Scalar],
+	) {
+		debug_assert!(i < self.eq_ind.size());
+
+		let eq_ind_factor = self
+			.eq_ind
+			.evaluate_on_hypercube(i)
+			.unwrap_or(P::Scalar::ZERO);
+
+		round_evals[0] += self
+			.composition
+			.evaluate_scalar(evals_1)
+			.expect("evals_1 is initialized with a length of poly.composition.n_vars()")
+			* eq_ind_factor;
+
+		for d in 2..self.domain_points.len() {
+			evals_0
+				.iter()
+				.zip(evals_1.iter())
+				.zip(evals_z.iter_mut())
+				.for_each(|((&evals_0_j, &evals_1_j), evals_z_j)| {
+					*evals_z_j = extrapolate_line::<P::Scalar, FS>(
+						evals_0_j,
+						evals_1_j,
+						self.domain_points[d],
+					);
+				});
+
+			let composite_value = self
+				.composition
+				.evaluate_scalar(evals_z)
+				.expect("evals_z is initialized with a length of poly.composition.n_vars()");
+
+			round_evals[d - 1] += composite_value * eq_ind_factor;
+		}
+	}
+
+	fn round_evals_to_coeffs(
+		&self,
+		current_round_sum: P::Scalar,
+		round_evals: Vec<P::Scalar>,
+	) -> Result<Vec<P::Scalar>, PolynomialError> {
+		later_round_evals_to_coeffs(
+			self.evaluation_domain,
+			self.round_zerocheck_challenge,
+			current_round_sum,
+			round_evals,
+		)
+	}
+}
+
+/// The number of high variables covered by the packed chunks of the univariate skip prover.
+///
+/// Chunks span about half of the high variables, so that both factors of the eq indicator have
+/// about $2^{(n - k) / 2}$ entries, and at least one packed element.
+fn univariate_skip_chunk_vars<P: PackedField>(n_vars_high: usize) -> usize {
+	n_vars_high.div_ceil(2).max(P::LOG_WIDTH).min(n_vars_high)
+}
+
+/// Per-thread buffers of the univariate round computation.
+struct UnivariateRoundState<P: PackedField> {
+	subcube: Vec<P>,
+	/// For each multilinear and skipped vertex, its packed values on the chunk
+	vertex_columns: Vec<P>,
+	/// For each multilinear, its packed univariatized values on the chunk at the current point
+	evals_z: Vec<P>,
+	query: Vec<P>,
+	round_evals: Vec<P::Scalar>,
+}
+
+impl<P: PackedField> UnivariateRoundState<P> {
+	fn new(
+		n_multilinears: usize,
+		n_vertices: usize,
+		chunk_len: usize,
+		n_round_evals: usize,
+	) -> Self {
+		Self {
+			subcube: vec![P::zero(); n_vertices * chunk_len],
+			vertex_columns: vec![P::zero(); n_multilinears * n_vertices * chunk_len],
+			evals_z: vec![P::zero(); n_multilinears * chunk_len],
+			query: vec![P::zero(); n_multilinears],
+			round_evals: vec![P::Scalar::ZERO; n_round_evals],
+		}
+	}
+}
+
+/// Read the packed subcube of a multilinear at `chunk_index` in the high variables, and transpose
+/// it into one packed column per skipped vertex.
+///
+/// The subcube covers the `skip_vars` skipped variables and the low `chunk_vars` high variables,
+/// so `subcube` and `columns` both hold $2^{skip\_vars + chunk\_vars}$ values.
+fn read_vertex_columns<P, M>(
+	multilin: &M,
+	skip_vars: usize,
+	chunk_vars: usize,
+	chunk_index: usize,
+	subcube: &mut [P],
+	columns: &mut [P],
+) -> Result<(), PolynomialError>
+where
+	P: PackedField,
+	M: MultilinearPoly<P> + ?Sized,
+{
+	multilin.subcube_evals(skip_vars + chunk_vars, chunk_index, subcube)?;
+
+	let chunk_len = columns.len() >> skip_vars;
+	for (vertex, column) in columns.chunks_exact_mut(chunk_len).enumerate() {
+		for (i, packed) in column.iter_mut().enumerate() {
+			*packed = P::from_fn(|j| {
+				get_packed_slice(subcube, vertex | ((i * P::WIDTH + j) << skip_vars))
+			});
+		}
+	}
+	Ok(())
+}
+
+/// Set `dst` to the linear combination of the vertex columns with small-field coefficients.
+///
+/// The combination is computed on the `FS` coordinates of the packed values, which is where the
+/// univariate skip saves over ordinary rounds.
+fn extrapolate_vertex_columns<PW, FS>(columns: &[PW], coeffs: &[FS], dst: &mut [PW])
+where
+	PW: PackedExtension<FS>,
+	PW::Scalar: ExtensionField<FS>,
+	FS: Field,
+{
+	let chunk_len = dst.len();
+	let dst = PW::cast_bases_mut(dst);
+	dst.fill(PW::PackedSubfield::zero());
+	for (column, &coeff) in columns.chunks_exact(chunk_len).zip(coeffs.iter()) {
+		for (dst, &value) in dst.iter_mut().zip(PW::cast_bases(column).iter()) {
+			*dst += value * coeff;
+		}
+	}
+}
+
+/// Check the number of coefficients of a univariate round message.
+pub(super) fn check_univariate_round<F: Field>(
+	degree: usize,
+	skip_vars: usize,
+	univariate_round: &ZerocheckRound<F>,
+) -> Result<(), Error> {
+	// deg(r) = d (2^k - 1) and deg(Z_D) = 2^k, so the quotient has (d - 1) (2^k - 1) coefficients
+	if univariate_round.coeffs.len() != (degree - 1) * ((1 << skip_vars) - 1) {
+		return Err(VerificationError::NumberOfCoefficients.into());
+	}
+	Ok(())
+}
+
+/// Evaluate the univariate round polynomial $r(z) = Z_D(z) q(z)$ from the quotient coefficients.
+pub(super) fn evaluate_univariate_round<F: Field>(
+	vertex_points: impl IntoIterator<Item = F>,
+	quotient_coeffs: &[F],
+	challenge: F,
+) -> F {
+	let vanishing_eval = vertex_points
+		.into_iter()
+		.map(|point| challenge - point)
+		.product::<F>();
+	vanishing_eval * evaluate_univariate(quotient_coeffs, challenge)
+}
+
+/// Compute, for every multilinear, its evaluations at the skipped vertices in the low variables
+/// and `eval_point` in the high variables.
+pub(super) fn compute_partial_evals<F, PW>(
+	multilinears: &[MultilinearWitness<PW>],
+	skip_vars: usize,
+	eval_point: &[F],
+) -> Result<Vec<Vec<F>>, Error>
+where
+	F: Field + From<PW::Scalar>,
+	PW: PackedField<Scalar: From<F>>,
+{
+	let pw_eval_point = eval_point
+		.iter()
+		.map(|&f| f.into())
+		.collect::<Vec<PW::Scalar>>();
+	let query = MultilinearQuery::<PW>::with_full_query(&pw_eval_point)?;
+	let expansion = query.expansion();
+
+	multilinears
+		.iter()
+		.map(|multilin| {
+			(0..1 << skip_vars)
+				.map(|j| {
+					let eval = (0..1 << eval_point.len())
+						.into_par_iter()
+						.map(|i| {
+							multilin.evaluate_on_hypercube_and_scale(
+								j | (i << skip_vars),
+								get_packed_slice(expansion, i),
+							)
+						})
+						.try_reduce(|| PW::Scalar::ZERO, |lhs, rhs| Ok(lhs + rhs))?;
+					Ok(eval.into())
+				})
+				.collect::<Result<Vec<F>, Error>>()
+		})
+		.collect()
+}
+
+/// Check the partial evaluations against the final claim of the rounds after the univariate one.
+///
+/// `lagrange_evals` are the Lagrange basis polynomials of the vertex domain evaluated at the
+/// univariate round challenge.
+pub(super) fn check_partial_evals<F: Field>(
+	poly: &CompositePolyOracle<F>,
+	lagrange_evals: &[F],
+	partial_evals: &[Vec<F>],
+	eval: F,
+) -> Result<(), Error> {
+	if partial_evals.len() != poly.n_multilinears()
+		|| partial_evals
+			.iter()
+			.any(|evals| evals.len() != lagrange_evals.len())
+	{
+		return Err(VerificationError::NumberOfPartialEvaluations.into());
+	}
+
+	let univariatized_evals = partial_evals
+		.iter()
+		.map(|evals| {
+			evals
+				.iter()
+				.zip(lagrange_evals.iter())
+				.map(|(&eval, &lagrange_eval)| eval * lagrange_eval)
+				.sum()
+		})
+		.collect::<Vec<F>>();
+	if poly.composition().evaluate_scalar(&univariatized_evals)? != eval {
+		return Err(Error::PartialEvaluationCheckFailed);
+	}
+	Ok(())
+}
+
+/// Make evalcheck claims on each multilinear at the point `skipped_challenges || eval_point`.
+pub(super) fn make_evalcheck_claims<F: Field>(
+	poly: &CompositePolyOracle<F>,
+	partial_evals: &[Vec<F>],
+	skipped_challenges: &[F],
+	eval_point: &[F],
+) -> Result<Vec<EvalcheckClaim<F>>, Error> {
+	let query = MultilinearQuery::<F>::with_full_query(skipped_challenges)?;
+	let full_eval_point = [skipped_challenges, eval_point].concat();
+
+	let evalcheck_claims = poly
+		.inner_polys()
+		.into_iter()
+		.zip(partial_evals.iter())
+		.map(|(multilin, evals)| EvalcheckClaim {
+			poly: multilin.into_composite(),
+			eval_point: full_eval_point.clone(),
+			eval: evals
+				.iter()
+				.zip(query.expansion().iter())
+				.map(|(&eval, &eq_eval)| eval * eq_eval)
+				.sum(),
+			is_random_point: true,
+		})
+		.collect();
+	Ok(evalcheck_claims)
+}
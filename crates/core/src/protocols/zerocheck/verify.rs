@@ -25,7 +25,8 @@ where
 	F: TowerField,
 	CH: CanSample<F> + CanObserve<F>,
 {
-	if claim.poly.max_individual_degree() == 0 {
+	let degree = claim.poly.max_individual_degree();
+	if degree == 0 {
 		return Err(Error::PolynomialDegreeIsZero);
 	}
 
@@ -37,8 +38,10 @@ where
 	}
 
 	let zerocheck_challenges = challenger.sample_vec(n_vars - 1);
+	let round_degrees = vec![degree; n_vars];
 	let reductor = ZerocheckReductor {
 		alphas: &zerocheck_challenges,
+		round_degrees: &round_degrees,
 	};
 	let reduced_claim =
 		abstract_sumcheck::verify(claim.clone().into(), proof, reductor, challenger)?;
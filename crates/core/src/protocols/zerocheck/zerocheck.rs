@@ -6,7 +6,7 @@ use crate::{
 	protocols::{
 		abstract_sumcheck::{
 			AbstractSumcheckClaim, AbstractSumcheckProof, AbstractSumcheckReductor,
-			AbstractSumcheckRound, AbstractSumcheckRoundClaim,
+			AbstractSumcheckRound, AbstractSumcheckRoundClaim, BatchBindingOrder,
 		},
 		evalcheck::EvalcheckClaim,
 	},
@@ -56,8 +56,19 @@ pub struct ZerocheckProveOutput<F: Field> {
 	pub zerocheck_proof: ZerocheckProof<F>,
 }
 
+/// Round claim reductor for the zerocheck protocol.
+///
+/// Round polynomials are computed without the equality indicator factor of the current variable,
+/// so each round polynomial has the degree of the composition rather than one more. The factor
+/// $eq(X, \alpha_i) = (1 - \alpha_i)(1 - X) + \alpha_i X$ is reintroduced symbolically through
+/// the identity $s = (1 - \alpha_i) r_i(0) + \alpha_i r_i(1)$, see Section 3 of [Gruen24].
+///
+/// [Gruen24]: https://eprint.iacr.org/2024/108
 pub struct ZerocheckReductor<'a, F> {
 	pub alphas: &'a [F],
+	/// The degree of the round polynomial of each round, which is the largest degree among the
+	/// claims taking part in the round.
+	pub round_degrees: &'a [usize],
 }
 
 impl<'a, F: Field> AbstractSumcheckReductor<F> for ZerocheckReductor<'a, F> {
@@ -78,9 +89,76 @@ impl<'a, F: Field> AbstractSumcheckReductor<F> for ZerocheckReductor<'a, F> {
 		} else {
 			Some(self.alphas[round - 1])
 		};
+		let degree = *self
+			.round_degrees
+			.get(round)
+			.ok_or(VerificationError::NumberOfRounds)?;
+
+		reduce_intermediate_round_claim_helper(claim, challenge, round_proof, alpha_i, degree)
+	}
+}
+
+/// The degree of the round polynomial of each round of a batch of zerocheck claims, given the
+/// number of variables and the degree of every claim.
+///
+/// A batched round polynomial is a random linear combination of the round polynomials of the
+/// claims taking part in the round under `binding_order`, hence its degree is the largest degree
+/// among these claims.
+pub(super) fn batch_round_degrees(
+	claims: impl IntoIterator<Item = (usize, usize)>,
+	n_rounds: usize,
+	binding_order: BatchBindingOrder,
+) -> Vec<usize> {
+	let mut round_degrees = vec![0; n_rounds];
+	for (n_vars, degree) in claims {
+		let rounds = match binding_order {
+			BatchBindingOrder::BackLoaded => n_rounds - n_vars..n_rounds,
+			BatchBindingOrder::FrontLoaded => 0..n_vars,
+		};
+		for round_degree in round_degrees[rounds].iter_mut() {
+			*round_degree = (*round_degree).max(degree);
+		}
+	}
+	round_degrees
+}
+
+/// Whether a batched instance over fewer variables shares the zerocheck challenges of a larger
+/// instance in the rounds where both are in the batch.
+///
+/// The rounds of both instances coincide at the end of the batch with back-loaded binding and at
+/// the start with front-loaded binding, so the challenges must be a suffix or a prefix
+/// respectively.
+pub(super) fn zerocheck_challenges_consistent<F: PartialEq>(
+	larger_challenges: &[F],
+	smaller_challenges: &[F],
+	binding_order: BatchBindingOrder,
+) -> bool {
+	let common = smaller_challenges.len();
+	let shared_challenges = match binding_order {
+		BatchBindingOrder::BackLoaded => &larger_challenges[larger_challenges.len() - common..],
+		BatchBindingOrder::FrontLoaded => &larger_challenges[..common],
+	};
+	shared_challenges == smaller_challenges
+}
 
-		reduce_intermediate_round_claim_helper(claim, challenge, round_proof, alpha_i)
+/// The zerocheck challenges of a batched instance needing `n_challenges` of the batch challenges.
+///
+/// The instance takes part in the last rounds of the batch with back-loaded binding and in the
+/// first ones with front-loaded binding, so it takes a suffix or a prefix respectively.
+pub(super) fn instance_zerocheck_challenges<F>(
+	batch_challenges: &[F],
+	n_challenges: usize,
+	binding_order: BatchBindingOrder,
+) -> Result<&[F], Error> {
+	if batch_challenges.len() < n_challenges {
+		return Err(Error::NotEnoughZerocheckChallenges);
 	}
+
+	let challenges = match binding_order {
+		BatchBindingOrder::BackLoaded => &batch_challenges[batch_challenges.len() - n_challenges..],
+		BatchBindingOrder::FrontLoaded => &batch_challenges[..n_challenges],
+	};
+	Ok(challenges)
 }
 
 /// Reduce a zerocheck round claim to a claim for the next round
@@ -88,11 +166,13 @@ impl<'a, F: Field> AbstractSumcheckReductor<F> for ZerocheckReductor<'a, F> {
 /// Arguments:
 /// * `challenge`: The random challenge sampled by the verifier at the beginning of the round.
 /// * `alpha_i`: The zerocheck challenge for round i.
-fn reduce_intermediate_round_claim_helper<F: Field>(
+/// * `degree`: The degree of the round polynomial.
+pub(super) fn reduce_intermediate_round_claim_helper<F: Field>(
 	claim: ZerocheckRoundClaim<F>,
 	challenge: F,
 	proof: ZerocheckRound<F>,
 	alpha_i: Option<F>,
+	degree: usize,
 ) -> Result<ZerocheckRoundClaim<F>, Error> {
 	let ZerocheckRoundClaim {
 		mut partial_point,
@@ -102,15 +182,21 @@ fn reduce_intermediate_round_claim_helper<F: Field>(
 	let ZerocheckRound { mut coeffs } = proof;
 	let round = partial_point.len();
 
+	// Round polynomials exclude the current variable's equality indicator factor, hence have
+	// degree at most d, and the prover omits their constant term.
+	if coeffs.len() != degree {
+		return Err(VerificationError::RoundPolynomialDegree.into());
+	}
+
 	// The prover has sent some coefficients for the purported ith round polynomial
 	// * $r_i(X) = \sum_{j=0}^d a_j * X^j$
 	// The verifier will need to recover the missing coefficient(s).
 	//
 	// Let $s$ denote the current round's claimed sum. There are two cases
-	// Case 1: This is the first round
+	// Case 1: This is the first round, and no zerocheck challenge is given for it
 	// The verifier expects two identities of $r$ to hold
 	// * $r_i(0) = 0$ and $r(1) = 0$.
-	// Case 2: This is a subsequent round
+	// Case 2: This is a subsequent round, or any round after a univariate skip
 	// The verifier expects one identity of $r$ to hold
 	// * $s = (1 - \alpha_i) r(0) + \alpha_i r(1)$.
 	//
@@ -122,12 +208,12 @@ fn reduce_intermediate_round_claim_helper<F: Field>(
 	// In the unoptimized version of the protocol, the verifier will halt and reject
 	// if given a round polynomial that does not satisfy the required identities.
 	// For more information, see Section 3 of https://eprint.iacr.org/2024/108
-	if round == 0 {
+	if alpha_i.is_none() {
 		if coeffs.is_empty() {
 			return Err(VerificationError::NumberOfCoefficients.into());
 		}
-		if alpha_i.is_some() {
-			return Err(VerificationError::UnexpectedZerocheckChallengeFound.into());
+		if round != 0 {
+			return Err(VerificationError::ExpectedZerocheckChallengeNotFound.into());
 		}
 		// In case 1, the verifier has not been given $a_0$
 		// However, the verifier knows that $f(0) = f(1) = 0$
@@ -0,0 +1,988 @@
+// Copyright 2024 Ulvetanna Inc.
+
+use super::{
+	ArcOrRef, Error, MultilinearExtensionIndex, MultilinearExtensionIndexEntry, MultilinearWitness,
+};
+use crate::{
+	oracle::{
+		MultilinearOracleSet, MultilinearPolyOracle, OracleId, ProjectionVariant, ShiftVariant,
+	},
+	polynomial::{util::PackingDeref, MultilinearExtension, MultilinearPoly, MultilinearQuery},
+};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	BinaryField128b, BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b,
+	BinaryField64b, BinaryField8b, ExtensionField, Field, PackedField, TowerField,
+};
+use rayon::prelude::*;
+use tracing::{debug_span, instrument};
+
+/// An underlier type that can pack the fields of every level of the canonical binary tower.
+pub trait TowerUnderlier:
+	UnderlierType
+	+ PackScalar<BinaryField1b>
+	+ PackScalar<BinaryField2b>
+	+ PackScalar<BinaryField4b>
+	+ PackScalar<BinaryField8b>
+	+ PackScalar<BinaryField16b>
+	+ PackScalar<BinaryField32b>
+	+ PackScalar<BinaryField64b>
+	+ PackScalar<BinaryField128b>
+{
+}
+
+impl<U> TowerUnderlier for U where
+	U: UnderlierType
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField2b>
+		+ PackScalar<BinaryField4b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField16b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>
+		+ PackScalar<BinaryField128b>
+{
+}
+
+/// A field that extends every level of the canonical binary tower.
+///
+/// In practice this is [`BinaryField128b`]. Fields in other tower bases, like
+/// `BinaryField128bPolyval`, do not implement it.
+pub trait CanonicalTowerExtension:
+	TowerField
+	+ ExtensionField<BinaryField1b>
+	+ ExtensionField<BinaryField2b>
+	+ ExtensionField<BinaryField4b>
+	+ ExtensionField<BinaryField8b>
+	+ ExtensionField<BinaryField16b>
+	+ ExtensionField<BinaryField32b>
+	+ ExtensionField<BinaryField64b>
+	+ ExtensionField<BinaryField128b>
+{
+}
+
+impl<F> CanonicalTowerExtension for F where
+	F: TowerField
+		+ ExtensionField<BinaryField1b>
+		+ ExtensionField<BinaryField2b>
+		+ ExtensionField<BinaryField4b>
+		+ ExtensionField<BinaryField8b>
+		+ ExtensionField<BinaryField16b>
+		+ ExtensionField<BinaryField32b>
+		+ ExtensionField<BinaryField64b>
+		+ ExtensionField<BinaryField128b>
+{
+}
+
+/// Evaluates `$body` with `$fs` bound to the canonical tower field at the given level.
+macro_rules! with_tower_field {
+	($tower_level:expr, $fs:ident => $body:expr) => {
+		match $tower_level {
+			0 => {
+				type $fs = BinaryField1b;
+				$body
+			}
+			1 => {
+				type $fs = BinaryField2b;
+				$body
+			}
+			2 => {
+				type $fs = BinaryField4b;
+				$body
+			}
+			3 => {
+				type $fs = BinaryField8b;
+				$body
+			}
+			4 => {
+				type $fs = BinaryField16b;
+				$body
+			}
+			5 => {
+				type $fs = BinaryField32b;
+				$body
+			}
+			6 => {
+				type $fs = BinaryField64b;
+				$body
+			}
+			7 => {
+				type $fs = BinaryField128b;
+				$body
+			}
+			tower_level => Err(Error::UnsupportedTowerLevel { tower_level }),
+		}
+	};
+}
+
+/// The definition of a derived oracle in terms of the IDs of its inner oracles.
+///
+/// Unlike [`MultilinearPolyOracle`], this holds no transparent polynomials, so it can be shared
+/// across threads.
+#[derive(Debug, Clone)]
+enum DerivedOracle<F: Field> {
+	Repeating {
+		inner_id: OracleId,
+		log_count: usize,
+	},
+	Interleaved(OracleId, OracleId),
+	Merged(OracleId, OracleId),
+	Projected {
+		inner_id: OracleId,
+		values: Vec<F>,
+		variant: ProjectionVariant,
+	},
+	Shifted {
+		inner_id: OracleId,
+		offset: usize,
+		block_bits: usize,
+		variant: ShiftVariant,
+		fill: F,
+	},
+	Shifted2D {
+		inner_id: OracleId,
+		col_bits: usize,
+		row_bits: usize,
+		col_offset: usize,
+		row_offset: usize,
+		variant: ShiftVariant,
+	},
+	Packed {
+		inner_id: OracleId,
+		log_degree: usize,
+	},
+	ZeroPadded {
+		inner_id: OracleId,
+		block_index: usize,
+	},
+	PermutedVars {
+		inner_id: OracleId,
+		permutation: Vec<usize>,
+	},
+	Embedded(OracleId),
+	LinearCombination {
+		offset: F,
+		inner: Vec<(OracleId, F)>,
+	},
+}
+
+impl<F: Field> DerivedOracle<F> {
+	/// Returns `None` for transparent and committed oracles.
+	fn new(oracle: &MultilinearPolyOracle<F>) -> Option<Self> {
+		let derived = match oracle {
+			MultilinearPolyOracle::Transparent(..) | MultilinearPolyOracle::Committed { .. } => {
+				return None
+			}
+			MultilinearPolyOracle::Repeating {
+				inner, log_count, ..
+			} => Self::Repeating {
+				inner_id: inner.id(),
+				log_count: *log_count,
+			},
+			MultilinearPolyOracle::Interleaved(_, poly0, poly1) => {
+				Self::Interleaved(poly0.id(), poly1.id())
+			}
+			MultilinearPolyOracle::Merged(_, poly0, poly1) => Self::Merged(poly0.id(), poly1.id()),
+			MultilinearPolyOracle::Projected(_, projected) => Self::Projected {
+				inner_id: projected.inner().id(),
+				values: projected.values().clone(),
+				variant: projected.projection_variant(),
+			},
+			MultilinearPolyOracle::Shifted(_, shifted) => Self::Shifted {
+				inner_id: shifted.inner().id(),
+				offset: shifted.shift_offset(),
+				block_bits: shifted.block_size(),
+				variant: shifted.shift_variant(),
+				fill: shifted.fill(),
+			},
+			MultilinearPolyOracle::Shifted2D(_, shifted) => Self::Shifted2D {
+				inner_id: shifted.inner().id(),
+				col_bits: shifted.col_bits(),
+				row_bits: shifted.row_bits(),
+				col_offset: shifted.col_offset(),
+				row_offset: shifted.row_offset(),
+				variant: shifted.shift_variant(),
+			},
+			MultilinearPolyOracle::Packed(_, packed) => Self::Packed {
+				inner_id: packed.inner().id(),
+				log_degree: packed.log_degree(),
+			},
+			MultilinearPolyOracle::ZeroPadded(_, padded) => Self::ZeroPadded {
+				inner_id: padded.inner().id(),
+				block_index: padded.block_index(),
+			},
+			MultilinearPolyOracle::PermutedVars(_, permuted) => Self::PermutedVars {
+				inner_id: permuted.inner().id(),
+				permutation: permuted.permutation().clone(),
+			},
+			MultilinearPolyOracle::Embedded(_, embedded) => Self::Embedded(embedded.inner().id()),
+			MultilinearPolyOracle::LinearCombination(_, lin_com) => Self::LinearCombination {
+				offset: lin_com.offset(),
+				inner: lin_com
+					.polys()
+					.map(|poly| poly.id())
+					.zip(lin_com.coefficients())
+					.collect(),
+			},
+		};
+		Some(derived)
+	}
+
+	fn inner_ids(&self) -> Vec<OracleId> {
+		match self {
+			Self::Repeating { inner_id, .. }
+			| Self::Projected { inner_id, .. }
+			| Self::Shifted { inner_id, .. }
+			| Self::Shifted2D { inner_id, .. }
+			| Self::Packed { inner_id, .. }
+			| Self::ZeroPadded { inner_id, .. }
+			| Self::PermutedVars { inner_id, .. }
+			| Self::Embedded(inner_id) => vec![*inner_id],
+			Self::Interleaved(id0, id1) | Self::Merged(id0, id1) => vec![*id0, *id1],
+			Self::LinearCombination { inner, .. } => inner.iter().map(|(id, _)| *id).collect(),
+		}
+	}
+}
+
+impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
+where
+	U: TowerUnderlier + PackScalar<FW>,
+	FW: CanonicalTowerExtension,
+{
+	/// Computes the witnesses of all derived oracles that do not have one yet.
+	///
+	/// Derived oracles are the repeating, interleaved, merged, projected, shifted, 2D shifted,
+	/// packed, zero-padded, permuted-variable, embedded, and linear combination oracles. Their
+	/// witnesses are computed from the witnesses of their inner oracles, so the witnesses of all
+	/// committed and transparent oracles they depend on must be present in the index. Witnesses
+	/// that are already present are never recomputed.
+	///
+	/// The oracles are filled in topological order. All oracles at the same depth of the oracle
+	/// graph are independent of each other and are filled in parallel. Each witness is stored in
+	/// the tower field given by [`MultilinearOracleSet::tower_level`]. Where the layout allows it,
+	/// witnesses are built directly from the underlier data of the inner oracles; in particular,
+	/// packed oracles share the storage of their inner oracle without copying.
+	#[instrument(skip_all, name = "witness::fill_derived_witnesses")]
+	pub fn fill_derived_witnesses<F>(
+		mut self,
+		oracles: &MultilinearOracleSet<F>,
+	) -> Result<Self, Error>
+	where
+		F: TowerField,
+		FW: From<F>,
+	{
+		let mut depths = vec![0; oracles.size()];
+		let mut layers = Vec::<Vec<(OracleId, DerivedOracle<F>)>>::new();
+		for id in 0..oracles.size() {
+			let Some(derived) = DerivedOracle::new(&oracles.oracle(id)) else {
+				continue;
+			};
+
+			// Inner oracle IDs are always smaller than the ID of the derived oracle
+			let depth = 1 + derived
+				.inner_ids()
+				.into_iter()
+				.map(|inner_id| depths[inner_id])
+				.max()
+				.unwrap_or(0);
+			depths[id] = depth;
+
+			if self.entry(id).is_err() {
+				if layers.len() < depth {
+					layers.resize_with(depth, Vec::new);
+				}
+				layers[depth - 1].push((id, derived));
+			}
+		}
+
+		for layer in layers {
+			let filled = layer
+				.into_par_iter()
+				.map(|(id, derived)| {
+					let _span =
+						debug_span!("fill_derived_witness", id, name = oracles.name(id)).entered();
+					let entry = self.fill_derived(
+						id,
+						&derived,
+						oracles.n_vars(id),
+						oracles.tower_level(id),
+					)?;
+					Ok((id, entry))
+				})
+				.collect::<Result<Vec<_>, Error>>()
+				.map_err(|err| err.with_oracle_names(oracles))?;
+
+			for (id, entry) in filled {
+				if id >= self.entries.len() {
+					self.entries.resize_with(id + 1, || None);
+				}
+				self.entries[id] = Some(entry);
+			}
+		}
+
+		Ok(self)
+	}
+
+	/// Returns the witness of an oracle as a multilinear over the unpacked field `FW`.
+	///
+	/// This is the representation taken by the provers of the multiset relation protocols. The
+	/// returned polynomial shares the storage of the witness in its tower field.
+	///
+	/// ## Throws
+	///
+	/// * `Error::MissingWitness` if the oracle has no witness
+	/// * `Error::StructuredWitness` if the witness is not stored densely
+	pub fn get_scalar_multilin_poly(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearWitness<'a, FW>, Error> {
+		let entry = self.entry(id)?;
+		let underliers = entry
+			.underliers
+			.clone()
+			.ok_or(Error::StructuredWitness { id })?;
+		with_tower_field!(entry.tower_level, FS => scalar_multilin_poly::<U, FW, FS>(underliers))
+	}
+
+	fn entry(&self, id: OracleId) -> Result<&MultilinearExtensionIndexEntry<'a, U, FW>, Error> {
+		self.entries
+			.get(id)
+			.and_then(|entry| entry.as_ref())
+			.ok_or(Error::MissingWitness { id, name: None })
+	}
+
+	fn fill_derived<F>(
+		&self,
+		id: OracleId,
+		derived: &DerivedOracle<F>,
+		n_vars: usize,
+		tower_level: usize,
+	) -> Result<MultilinearExtensionIndexEntry<'a, U, FW>, Error>
+	where
+		F: TowerField,
+		FW: From<F>,
+	{
+		with_tower_field!(tower_level, FS => self.fill_derived_in::<F, FS>(id, derived, n_vars))
+	}
+
+	fn fill_derived_in<F, FS>(
+		&self,
+		id: OracleId,
+		derived: &DerivedOracle<F>,
+		n_vars: usize,
+	) -> Result<MultilinearExtensionIndexEntry<'a, U, FW>, Error>
+	where
+		F: TowerField,
+		FS: TowerField,
+		FW: From<F> + ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let log_width = PackedType::<U, FS>::LOG_WIDTH;
+		if n_vars < log_width {
+			return Err(Error::NotEnoughVarsForStorage {
+				id,
+				n_vars,
+				min_n_vars: log_width,
+			});
+		}
+
+		// Returns the underliers of an inner witness if it is stored densely in the output field
+		let native = |inner_id: OracleId| -> Result<Option<&[U]>, Error> {
+			let entry = self.entry(inner_id)?;
+			Ok(entry
+				.underliers
+				.as_ref()
+				.filter(|_| entry.tower_level == FS::TOWER_LEVEL)
+				.map(|underliers| underliers.as_ref()))
+		};
+
+		let underliers: ArcOrRef<'a, [U]> = match derived {
+			DerivedOracle::Repeating {
+				inner_id,
+				log_count,
+			} => match native(*inner_id)? {
+				Some(inner) => ArcOrRef::Arc(inner.repeat(1 << *log_count).into()),
+				None => {
+					let inner = self.entry(*inner_id)?.type_erased.clone();
+					let inner_size = 1 << inner.n_vars();
+					collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+						inner.evaluate_on_hypercube(i % inner_size)
+					})?
+				}
+			},
+			DerivedOracle::Interleaved(id0, id1) => {
+				let polys = [
+					self.entry(*id0)?.type_erased.clone(),
+					self.entry(*id1)?.type_erased.clone(),
+				];
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+					polys[i & 1].evaluate_on_hypercube(i >> 1)
+				})?
+			}
+			DerivedOracle::Merged(id0, id1) => match (native(*id0)?, native(*id1)?) {
+				(Some(inner0), Some(inner1)) => ArcOrRef::Arc([inner0, inner1].concat().into()),
+				_ => {
+					let polys = [
+						self.entry(*id0)?.type_erased.clone(),
+						self.entry(*id1)?.type_erased.clone(),
+					];
+					let half_size = 1 << (n_vars - 1);
+					collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+						polys[i / half_size].evaluate_on_hypercube(i % half_size)
+					})?
+				}
+			},
+			DerivedOracle::Projected {
+				inner_id,
+				values,
+				variant,
+			} => {
+				let inner = &self.entry(*inner_id)?.type_erased;
+				let values = values
+					.iter()
+					.map(|&value| value.into())
+					.collect::<Vec<FW>>();
+				let query = MultilinearQuery::<PackedType<U, FW>>::with_full_query(&values)?;
+				let projected = match variant {
+					ProjectionVariant::FirstVars => inner.evaluate_partial_low(&query)?,
+					ProjectionVariant::LastVars => inner.evaluate_partial_high(&query)?,
+				};
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| projected.evaluate_on_hypercube(i))?
+			}
+			DerivedOracle::Shifted {
+				inner_id,
+				offset,
+				block_bits,
+				variant,
+				fill,
+			} => {
+				let inner = self.entry(*inner_id)?.type_erased.clone();
+				let (offset, block_size) = (*offset, 1 << *block_bits);
+				let variant = *variant;
+				let fill = FW::from(*fill);
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+					let x = i % block_size;
+					let block_start = i - x;
+					match variant {
+						ShiftVariant::CircularLeft => {
+							inner.evaluate_on_hypercube(block_start + (x + offset) % block_size)
+						}
+						ShiftVariant::CircularRight => inner.evaluate_on_hypercube(
+							block_start + (x + block_size - offset) % block_size,
+						),
+						ShiftVariant::LogicalLeft if x + offset < block_size => {
+							inner.evaluate_on_hypercube(block_start + x + offset)
+						}
+						ShiftVariant::LogicalRight if x >= offset => {
+							inner.evaluate_on_hypercube(block_start + x - offset)
+						}
+						_ => Ok(fill),
+					}
+				})?
+			}
+			DerivedOracle::Shifted2D {
+				inner_id,
+				col_bits,
+				row_bits,
+				col_offset,
+				row_offset,
+				variant,
+			} => {
+				let inner = self.entry(*inner_id)?.type_erased.clone();
+				let (n_cols, n_rows) = (1 << *col_bits, 1 << *row_bits);
+				let (col_offset, row_offset) = (*col_offset, *row_offset);
+				let variant = *variant;
+				// The index read by a one-dimensional shift at index x of a dimension of the
+				// given size, or None if a logical shift moves it out of range
+				let source = move |x: usize, offset: usize, size: usize| match variant {
+					ShiftVariant::CircularLeft => Some((x + offset) % size),
+					ShiftVariant::CircularRight => Some((x + size - offset) % size),
+					ShiftVariant::LogicalLeft => (x + offset < size).then_some(x + offset),
+					ShiftVariant::LogicalRight => x.checked_sub(offset),
+				};
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+					let (col, row) = (i % n_cols, (i / n_cols) % n_rows);
+					let matrix_start = i - i % (n_cols * n_rows);
+					match (source(col, col_offset, n_cols), source(row, row_offset, n_rows)) {
+						(Some(col), Some(row)) => {
+							inner.evaluate_on_hypercube(matrix_start + row * n_cols + col)
+						}
+						_ => Ok(FW::ZERO),
+					}
+				})?
+			}
+			DerivedOracle::Packed {
+				inner_id,
+				log_degree,
+			} => {
+				// Packing 2^κ consecutive values into one extension field element is exactly the
+				// underlier layout of the inner witness, reinterpreted at a tower level κ higher
+				let entry = self.entry(*inner_id)?;
+				if entry.tower_level + log_degree != FS::TOWER_LEVEL {
+					return Err(Error::OracleTowerHeightMismatch {
+						oracle_id: *inner_id,
+						oracle_level: entry.tower_level,
+						field_level: FS::TOWER_LEVEL.saturating_sub(*log_degree),
+					});
+				}
+				match &entry.underliers {
+					Some(underliers) => underliers.clone(),
+					None => {
+						// Structured inner witnesses are combined with the tower basis instead
+						let inner = entry.type_erased.clone();
+						let (inner_level, log_degree) = (entry.tower_level, *log_degree);
+						collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+							(0..1 << log_degree)
+								.map(|j| -> Result<FW, crate::polynomial::Error> {
+									let basis = <FW as TowerField>::basis(inner_level, j)?;
+									inner.evaluate_on_hypercube_and_scale(
+										(i << log_degree) | j,
+										basis,
+									)
+								})
+								.sum()
+						})?
+					}
+				}
+			}
+			DerivedOracle::ZeroPadded {
+				inner_id,
+				block_index,
+			} => match native(*inner_id)? {
+				Some(inner) => {
+					let mut underliers = vec![U::default(); 1 << (n_vars - log_width)];
+					let block_start = block_index * inner.len();
+					underliers[block_start..block_start + inner.len()].copy_from_slice(inner);
+					ArcOrRef::Arc(underliers.into())
+				}
+				None => {
+					let inner = self.entry(*inner_id)?.type_erased.clone();
+					let inner_n_vars = inner.n_vars();
+					let block_index = *block_index;
+					collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+						if i >> inner_n_vars == block_index {
+							inner.evaluate_on_hypercube(i % (1 << inner_n_vars))
+						} else {
+							Ok(FW::ZERO)
+						}
+					})?
+				}
+			},
+			DerivedOracle::PermutedVars {
+				inner_id,
+				permutation,
+			} => {
+				let inner = self.entry(*inner_id)?.type_erased.clone();
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+					let inner_index = permutation
+						.iter()
+						.enumerate()
+						.map(|(var, &inner_var)| ((i >> var) & 1) << inner_var)
+						.sum();
+					inner.evaluate_on_hypercube(inner_index)
+				})?
+			}
+			DerivedOracle::Embedded(inner_id) => {
+				let inner = self.entry(*inner_id)?.type_erased.clone();
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| inner.evaluate_on_hypercube(i))?
+			}
+			DerivedOracle::LinearCombination { offset, inner } => {
+				let inner = inner
+					.iter()
+					.map(|(inner_id, coeff)| {
+						Ok((self.entry(*inner_id)?.type_erased.clone(), FW::from(*coeff)))
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+				let offset = FW::from(*offset);
+				collect_underliers::<U, FW, FS>(id, n_vars, |i| {
+					inner.iter().try_fold(offset, |sum, (poly, coeff)| {
+						Ok(sum + poly.evaluate_on_hypercube_and_scale(i, *coeff)?)
+					})
+				})?
+			}
+		};
+
+		MultilinearExtensionIndexEntry::new::<FS>(underliers)
+	}
+}
+
+/// Packs the hypercube evaluations of an `n_vars`-variate witness into underliers of `FS`.
+fn collect_underliers<'a, U, FW, FS>(
+	id: OracleId,
+	n_vars: usize,
+	eval: impl Fn(usize) -> Result<FW, crate::polynomial::Error> + Sync,
+) -> Result<ArcOrRef<'a, [U]>, Error>
+where
+	U: UnderlierType + PackScalar<FS>,
+	FW: ExtensionField<FS>,
+	FS: TowerField,
+{
+	let log_width = PackedType::<U, FS>::LOG_WIDTH;
+	let underliers = (0..1 << (n_vars - log_width))
+		.into_par_iter()
+		.map(|packed_index| {
+			let mut packed = PackedType::<U, FS>::default();
+			for j in 0..1 << log_width {
+				let value = eval((packed_index << log_width) | j)?;
+				let value: FS = value
+					.try_into()
+					.map_err(|_| Error::OracleTowerHeightMismatch {
+						oracle_id: id,
+						oracle_level: FW::TOWER_LEVEL,
+						field_level: FS::TOWER_LEVEL,
+					})?;
+				packed.set(j, value);
+			}
+			Ok(packed.to_underlier())
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+	Ok(ArcOrRef::Arc(underliers.into()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		polynomial::{PeriodicMultilinear, SparseMultilinear},
+		witness::MultilinearWitness,
+	};
+	use binius_field::arch::OptimalUnderlier128b;
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::{iter::repeat_with, sync::Arc};
+
+	type U = OptimalUnderlier128b;
+	type F = BinaryField128b;
+	type P1b = PackedType<U, BinaryField1b>;
+	type P8b = PackedType<U, BinaryField8b>;
+
+	const N_VARS: usize = 8;
+
+	fn random_packed<P: PackedField>(rng: &mut StdRng) -> Vec<P> {
+		repeat_with(|| P::random(&mut *rng))
+			.take((1 << N_VARS) / P::WIDTH)
+			.collect()
+	}
+
+	fn to_underliers<P: PackedField + WithUnderlier<Underlier = U>>(values: &[P]) -> Vec<U> {
+		values.iter().map(|&value| value.to_underlier()).collect()
+	}
+
+	fn to_scalars<P: PackedField>(values: &[P]) -> Vec<F>
+	where
+		F: From<P::Scalar>,
+	{
+		values
+			.iter()
+			.flat_map(|packed| packed.iter())
+			.map(F::from)
+			.collect()
+	}
+
+	fn hypercube_evals(witness: &MultilinearExtensionIndex<U, F>, id: OracleId) -> Vec<F> {
+		let poly = witness.get_multilin_poly(id).unwrap();
+		(0..1 << poly.n_vars())
+			.map(|i| poly.evaluate_on_hypercube(i).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn test_fill_derived_witnesses() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField8b::TOWER_LEVEL);
+		let [a, b] = batch_scope.add_multiple();
+		batch_scope.build();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField1b::TOWER_LEVEL);
+		let bits = batch_scope.add_one();
+		batch_scope.build();
+
+		let repeating = oracles.add_repeating(a, 2).unwrap();
+		let interleaved = oracles.add_interleaved(a, b).unwrap();
+		let interleaved_mixed = oracles.add_interleaved(bits, b).unwrap();
+		let merged = oracles.add_merged(a, b).unwrap();
+		let merged_mixed = oracles.add_merged(a, bits).unwrap();
+		let shifted = [
+			ShiftVariant::CircularLeft,
+			ShiftVariant::CircularRight,
+			ShiftVariant::LogicalLeft,
+			ShiftVariant::LogicalRight,
+		]
+		.map(|variant| (variant, oracles.add_shifted(a, 3, 4, variant).unwrap()));
+		let shifted_interleaved = oracles
+			.add_shifted(interleaved, 5, 3, ShiftVariant::CircularLeft)
+			.unwrap();
+		let shift_fill = F::random(&mut rng);
+		let shifted_filled = oracles
+			.add_shifted_with_fill(a, 3, 4, ShiftVariant::LogicalLeft, shift_fill)
+			.unwrap();
+		let shifted_2d = [ShiftVariant::CircularRight, ShiftVariant::LogicalRight]
+			.map(|variant| (variant, oracles.add_shifted_2d(a, 2, 3, 1, 2, variant).unwrap()));
+		let packed = oracles.add_packed(bits, 3).unwrap();
+		let zero_padded = oracles.add_zero_padded(a, N_VARS + 2, 2).unwrap();
+		let embedded = oracles
+			.add_embedded(bits, BinaryField8b::TOWER_LEVEL)
+			.unwrap();
+		let transposed = oracles
+			.add_permuted_vars(a, (0..N_VARS).map(|var| (var + 3) % N_VARS).collect())
+			.unwrap();
+		let zero_padded_mixed = oracles
+			.add_zero_padded(interleaved_mixed, N_VARS + 2, 1)
+			.unwrap();
+		let projection_values = repeat_with(|| F::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
+		let projected =
+			[ProjectionVariant::FirstVars, ProjectionVariant::LastVars].map(|variant| {
+				oracles
+					.add_projected(a, projection_values.clone(), variant)
+					.unwrap()
+			});
+		let (offset, coeffs) = (F::random(&mut rng), [F::random(&mut rng), F::random(&mut rng)]);
+		let lin_com = oracles
+			.add_linear_combination_with_offset(N_VARS, offset, [(a, coeffs[0]), (b, coeffs[1])])
+			.unwrap();
+
+		let a_values = random_packed::<P8b>(&mut rng);
+		let b_values = random_packed::<P8b>(&mut rng);
+		let bits_values = random_packed::<P1b>(&mut rng);
+
+		let witness = MultilinearExtensionIndex::<U, F>::new()
+			.update_owned::<BinaryField8b, _>([
+				(a, to_underliers(&a_values)),
+				(b, to_underliers(&b_values)),
+			])
+			.unwrap()
+			.update_owned::<BinaryField1b, _>([(bits, to_underliers(&bits_values))])
+			.unwrap()
+			.fill_derived_witnesses(&oracles)
+			.unwrap();
+
+		let a_evals = to_scalars(&a_values);
+		let b_evals = to_scalars(&b_values);
+		let bits_evals = to_scalars(&bits_values);
+		let size = 1 << N_VARS;
+
+		assert_eq!(hypercube_evals(&witness, repeating), a_evals.repeat(4));
+		assert_eq!(
+			hypercube_evals(&witness, interleaved),
+			(0..2 * size)
+				.map(|i| [&a_evals, &b_evals][i % 2][i / 2])
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			hypercube_evals(&witness, interleaved_mixed),
+			(0..2 * size)
+				.map(|i| [&bits_evals, &b_evals][i % 2][i / 2])
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(hypercube_evals(&witness, merged), [a_evals.clone(), b_evals.clone()].concat());
+		assert_eq!(
+			hypercube_evals(&witness, merged_mixed),
+			[a_evals.clone(), bits_evals.clone()].concat()
+		);
+		assert!(witness.get::<BinaryField8b>(merged_mixed).is_ok());
+
+		let shift = |values: &[F], offset: usize, block_size: usize, variant, fill| {
+			(0..values.len())
+				.map(|i| {
+					let (block_start, x) = (i - i % block_size, i % block_size);
+					match variant {
+						ShiftVariant::CircularLeft => {
+							values[block_start + (x + offset) % block_size]
+						}
+						ShiftVariant::CircularRight => {
+							values[block_start + (x + block_size - offset) % block_size]
+						}
+						ShiftVariant::LogicalLeft if x + offset < block_size => {
+							values[block_start + x + offset]
+						}
+						ShiftVariant::LogicalRight if x >= offset => {
+							values[block_start + x - offset]
+						}
+						_ => fill,
+					}
+				})
+				.collect::<Vec<_>>()
+		};
+		for (variant, id) in shifted {
+			assert_eq!(hypercube_evals(&witness, id), shift(&a_evals, 3, 16, variant, F::ZERO));
+		}
+		assert_eq!(
+			hypercube_evals(&witness, shifted_interleaved),
+			shift(
+				&hypercube_evals(&witness, interleaved),
+				5,
+				8,
+				ShiftVariant::CircularLeft,
+				F::ZERO
+			)
+		);
+		assert_eq!(
+			hypercube_evals(&witness, shifted_filled),
+			shift(&a_evals, 3, 16, ShiftVariant::LogicalLeft, shift_fill)
+		);
+
+		// The 2D shifts view each block of 32 values as an 8x4 matrix. Shifting the columns by 1
+		// and the rows by 2 is a shift by 1 within rows of 4, applied after a shift by 8 within
+		// blocks of 32.
+		for (variant, id) in shifted_2d {
+			let rows_shifted = shift(&a_evals, 8, 32, variant, F::ZERO);
+			assert_eq!(hypercube_evals(&witness, id), shift(&rows_shifted, 1, 4, variant, F::ZERO));
+		}
+
+		// Packed witnesses reuse the storage of the inner witness
+		assert!(witness.get::<BinaryField8b>(packed).is_ok());
+		assert_eq!(
+			hypercube_evals(&witness, packed),
+			bits_values
+				.iter()
+				.flat_map(|packed| packed.iter())
+				.collect::<Vec<_>>()
+				.chunks(8)
+				.map(|chunk| F::from(BinaryField8b::from_bases(chunk).unwrap()))
+				.collect::<Vec<_>>()
+		);
+
+		let zero_pad = |values: &[F], block_index: usize| {
+			let mut padded = vec![F::ZERO; 4 * size];
+			padded[block_index * values.len()..(block_index + 1) * values.len()]
+				.copy_from_slice(values);
+			padded
+		};
+		assert_eq!(hypercube_evals(&witness, zero_padded), zero_pad(&a_evals, 2));
+		assert_eq!(
+			hypercube_evals(&witness, zero_padded_mixed),
+			zero_pad(&hypercube_evals(&witness, interleaved_mixed), 1)
+		);
+
+		assert!(witness.get::<BinaryField8b>(embedded).is_ok());
+		assert_eq!(hypercube_evals(&witness, embedded), bits_evals);
+
+		// Variable i of the transposed oracle is variable (i + 3) % N_VARS of the inner oracle, so
+		// it reads the inner values in column-major order of a 32x8 table
+		assert_eq!(
+			hypercube_evals(&witness, transposed),
+			(0..size)
+				.map(|i| a_evals[(i % 32) * 8 + i / 32])
+				.collect::<Vec<_>>()
+		);
+
+		// Check the projections by evaluating at a random point
+		let a_poly = witness.get_multilin_poly(a).unwrap();
+		let point = repeat_with(|| F::random(&mut rng))
+			.take(N_VARS - 2)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<PackedType<U, F>>::with_full_query(&point).unwrap();
+		for (id, full_point) in [
+			(projected[0], [projection_values.clone(), point.clone()].concat()),
+			(projected[1], [point.clone(), projection_values.clone()].concat()),
+		] {
+			let full_query =
+				MultilinearQuery::<PackedType<U, F>>::with_full_query(&full_point).unwrap();
+			assert_eq!(
+				witness
+					.get_multilin_poly(id)
+					.unwrap()
+					.evaluate(&query)
+					.unwrap(),
+				a_poly.evaluate(&full_query).unwrap()
+			);
+		}
+
+		assert_eq!(
+			hypercube_evals(&witness, lin_com),
+			(0..size)
+				.map(|i| offset + coeffs[0] * a_evals[i] + coeffs[1] * b_evals[i])
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_fill_derived_witnesses_from_structured_witnesses() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField1b::TOWER_LEVEL);
+		let [bits, sparse_bits] = batch_scope.add_multiple();
+		batch_scope.build();
+		let packed = oracles.add_packed(bits, 3).unwrap();
+		let repeating = oracles.add_repeating(sparse_bits, 1).unwrap();
+
+		let period = repeat_with(|| BinaryField1b::random(&mut rng))
+			.take(16)
+			.collect();
+		let bits_poly = PeriodicMultilinear::new(N_VARS, period).unwrap();
+		let sparse_poly =
+			SparseMultilinear::new(N_VARS, [(3, BinaryField1b::ONE), (200, BinaryField1b::ONE)])
+				.unwrap();
+
+		let witness = MultilinearExtensionIndex::<U, F>::new()
+			.update_structured::<BinaryField1b>([
+				(bits, Arc::new(bits_poly) as MultilinearWitness<_>),
+				(sparse_bits, Arc::new(sparse_poly) as _),
+			])
+			.fill_derived_witnesses(&oracles)
+			.unwrap();
+
+		// Structured witnesses have no dense evaluations, but the derived witnesses do
+		assert!(matches!(
+			witness.get::<BinaryField1b>(bits),
+			Err(Error::StructuredWitness { id }) if id == bits
+		));
+		assert!(witness.get::<BinaryField8b>(packed).is_ok());
+		assert!(witness.get::<BinaryField1b>(repeating).is_ok());
+
+		assert_eq!(
+			hypercube_evals(&witness, packed),
+			hypercube_evals(&witness, bits)
+				.chunks(8)
+				.map(|chunk| {
+					let bases = chunk
+						.iter()
+						.map(|&bit| bit.try_into().unwrap())
+						.collect::<Vec<BinaryField1b>>();
+					F::from(BinaryField8b::from_bases(&bases).unwrap())
+				})
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			hypercube_evals(&witness, repeating),
+			hypercube_evals(&witness, sparse_bits).repeat(2)
+		);
+	}
+
+	#[test]
+	fn test_fill_derived_witnesses_missing_inner_witness() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let mut batch_scope = oracles.build_committed_batch(N_VARS, BinaryField8b::TOWER_LEVEL);
+		let a = batch_scope.add_one();
+		batch_scope.build();
+		oracles.add_repeating(a, 1).unwrap();
+
+		let result = MultilinearExtensionIndex::<U, F>::new().fill_derived_witnesses(&oracles);
+		assert!(matches!(result, Err(Error::MissingWitness { id, name: None }) if id == a));
+	}
+}
+
+fn scalar_multilin_poly<'a, U, FW, FS>(
+	underliers: ArcOrRef<'a, [U]>,
+) -> Result<MultilinearWitness<'a, FW>, Error>
+where
+	U: UnderlierType + PackScalar<FS>,
+	FW: ExtensionField<FS>,
+	FS: TowerField,
+{
+	let poly = match underliers {
+		ArcOrRef::Arc(owned) => {
+			MultilinearExtension::<_, PackingDeref<U, FS, _>>::from_underliers(owned)?
+				.specialize_arc_dyn()
+		}
+		ArcOrRef::Ref(borrowed) => MultilinearExtension::from_values_slice(
+			PackedType::<U, FS>::from_underliers_ref(borrowed),
+		)?
+		.specialize_arc_dyn(),
+	};
+	Ok(poly)
+}
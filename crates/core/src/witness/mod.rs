@@ -1,7 +1,11 @@
 // Copyright 2024 Ulvetanna Inc.
 
+mod fill;
+
+pub use fill::*;
+
 use crate::{
-	oracle::OracleId,
+	oracle::{MultilinearOracleSet, OracleDisplay, OracleId},
 	polynomial::{
 		util::PackingDeref, Error as PolynomialError, MultilinearExtension,
 		MultilinearExtensionBorrowed, MultilinearPoly,
@@ -65,10 +69,52 @@ where
 	F: Field,
 {
 	type_erased: MultilinearWitness<'a, PackedType<U, F>>,
-	underliers: ArcOrRef<'a, [U]>,
+	/// The hypercube evaluations, or `None` for structured witnesses that are not stored densely.
+	underliers: Option<ArcOrRef<'a, [U]>>,
 	tower_level: usize,
 }
 
+impl<'a, U, F> MultilinearExtensionIndexEntry<'a, U, F>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: Field,
+{
+	fn new<FS>(underliers: ArcOrRef<'a, [U]>) -> Result<Self, Error>
+	where
+		FS: TowerField,
+		F: ExtensionField<FS>,
+		U: PackScalar<FS>,
+	{
+		let type_erased = match &underliers {
+			ArcOrRef::Arc(owned) => {
+				MultilinearExtension::<_, PackingDeref<U, FS, _>>::from_underliers(owned.clone())?
+					.specialize_arc_dyn()
+			}
+			ArcOrRef::Ref(borrowed) => MultilinearExtension::from_values_slice(
+				PackedType::<U, FS>::from_underliers_ref(borrowed),
+			)?
+			.specialize_arc_dyn(),
+		};
+		Ok(Self {
+			type_erased,
+			underliers: Some(underliers),
+			tower_level: FS::TOWER_LEVEL,
+		})
+	}
+
+	fn new_structured<FS>(type_erased: MultilinearWitness<'a, PackedType<U, F>>) -> Self
+	where
+		FS: TowerField,
+		F: ExtensionField<FS>,
+	{
+		Self {
+			type_erased,
+			underliers: None,
+			tower_level: FS::TOWER_LEVEL,
+		}
+	}
+}
+
 /// Data structure that indexes multilinear extensions by oracle ID.
 ///
 /// A [`crate::oracle::MultilinearOracleSet`] indexes multilinear polynomial oracles by assigning
@@ -93,18 +139,47 @@ where
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-	#[error("witness not found for oracle {id}")]
-	MissingWitness { id: OracleId },
+	#[error("witness not found for oracle {}", OracleDisplay { id: *id, name: name.as_deref() })]
+	MissingWitness {
+		id: OracleId,
+		/// Name of the oracle, filled in by [`Error::with_oracle_names`].
+		name: Option<String>,
+	},
 	#[error("oracle tower height does not match field parameter")]
 	OracleTowerHeightMismatch {
 		oracle_id: OracleId,
 		oracle_level: usize,
 		field_level: usize,
 	},
+	#[error(
+		"oracle {id} has {n_vars} variables, but at least {min_n_vars} are required to store it"
+	)]
+	NotEnoughVarsForStorage {
+		id: OracleId,
+		n_vars: usize,
+		min_n_vars: usize,
+	},
+	#[error("witness for oracle {id} is structured and has no dense evaluations")]
+	StructuredWitness { id: OracleId },
+	#[error("tower level {tower_level} is not supported")]
+	UnsupportedTowerLevel { tower_level: usize },
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 }
 
+impl Error {
+	/// Attach the names of the oracles referred to by the error, as labeled in the oracle set.
+	pub fn with_oracle_names<F: TowerField>(self, oracles: &MultilinearOracleSet<F>) -> Self {
+		match self {
+			Self::MissingWitness { id, name: None } => Self::MissingWitness {
+				id,
+				name: oracles.name(id).map(String::from),
+			},
+			err => err,
+		}
+	}
+}
+
 impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
 where
 	U: UnderlierType + PackScalar<FW>,
@@ -126,9 +201,9 @@ where
 		let entry = self
 			.entries
 			.get(id)
-			.ok_or(Error::MissingWitness { id })?
+			.ok_or(Error::MissingWitness { id, name: None })?
 			.as_ref()
-			.ok_or(Error::MissingWitness { id })?;
+			.ok_or(Error::MissingWitness { id, name: None })?;
 		if entry.tower_level != FS::TOWER_LEVEL {
 			return Err(Error::OracleTowerHeightMismatch {
 				oracle_id: id,
@@ -136,9 +211,13 @@ where
 				field_level: FS::TOWER_LEVEL,
 			});
 		}
+		let underliers = entry
+			.underliers
+			.as_ref()
+			.ok_or(Error::StructuredWitness { id })?;
 
 		let mle = MultilinearExtension::from_values_slice(
-			PackedType::<U, FS>::from_underliers_ref(entry.underliers.as_ref()),
+			PackedType::<U, FS>::from_underliers_ref(underliers.as_ref()),
 		)?;
 		Ok(mle)
 	}
@@ -150,9 +229,9 @@ where
 		let entry = self
 			.entries
 			.get(id)
-			.ok_or(Error::MissingWitness { id })?
+			.ok_or(Error::MissingWitness { id, name: None })?
 			.as_ref()
-			.ok_or(Error::MissingWitness { id })?;
+			.ok_or(Error::MissingWitness { id, name: None })?;
 		Ok(entry.type_erased.clone())
 	}
 
@@ -172,15 +251,8 @@ where
 				entries.resize_with(id + 1, || None);
 			}
 
-			let witness = witness.into();
-			let mle = MultilinearExtension::<_, PackingDeref<U, FS, _>>::from_underliers(
-				witness.clone(),
-			)?;
-			entries[id] = Some(MultilinearExtensionIndexEntry {
-				type_erased: mle.specialize_arc_dyn(),
-				underliers: ArcOrRef::Arc(witness),
-				tower_level: FS::TOWER_LEVEL,
-			});
+			entries[id] =
+				Some(MultilinearExtensionIndexEntry::new::<FS>(ArcOrRef::Arc(witness.into()))?);
 		}
 		Ok(MultilinearExtensionIndex { entries })
 	}
@@ -201,14 +273,7 @@ where
 				entries.resize_with(id + 1, || None);
 			}
 
-			let mle = MultilinearExtension::from_values_slice(
-				PackedType::<U, FS>::from_underliers_ref(witness),
-			)?;
-			entries[id] = Some(MultilinearExtensionIndexEntry {
-				type_erased: mle.specialize_arc_dyn(),
-				underliers: ArcOrRef::Ref(witness),
-				tower_level: FS::TOWER_LEVEL,
-			});
+			entries[id] = Some(MultilinearExtensionIndexEntry::new::<FS>(ArcOrRef::Ref(witness))?);
 		}
 		Ok(MultilinearExtensionIndex { entries })
 	}
@@ -230,6 +295,36 @@ where
 		)
 	}
 
+	/// Stores witnesses that are not backed by dense hypercube evaluations.
+	///
+	/// This is meant for constant, periodic, and sparse columns, represented by
+	/// [`ConstantMultilinear`], [`PeriodicMultilinear`], and [`SparseMultilinear`], which are
+	/// passed to the provers without being expanded. The witnesses must take values in `FS`. They
+	/// are returned by [`Self::get_multilin_poly`] and [`Self::witness_index`], while
+	/// [`Self::get`] fails with [`Error::StructuredWitness`] for them.
+	///
+	/// [`ConstantMultilinear`]: crate::polynomial::ConstantMultilinear
+	/// [`PeriodicMultilinear`]: crate::polynomial::PeriodicMultilinear
+	/// [`SparseMultilinear`]: crate::polynomial::SparseMultilinear
+	pub fn update_structured<FS>(
+		self,
+		witnesses: impl IntoIterator<Item = (OracleId, MultilinearWitness<'a, PackedType<U, FW>>)>,
+	) -> MultilinearExtensionIndex<'a, U, FW>
+	where
+		FS: TowerField,
+		FW: ExtensionField<FS>,
+	{
+		let MultilinearExtensionIndex { mut entries } = self;
+		for (id, witness) in witnesses {
+			if id >= entries.len() {
+				entries.resize_with(id + 1, || None);
+			}
+
+			entries[id] = Some(MultilinearExtensionIndexEntry::new_structured::<FS>(witness));
+		}
+		MultilinearExtensionIndex { entries }
+	}
+
 	pub fn witness_index(&self) -> MultilinearWitnessIndex<'a, PackedType<U, FW>> {
 		let mut index = MultilinearWitnessIndex::new();
 		index.set_many(self.entries.iter().enumerate().flat_map(|(id, entry)| {
@@ -246,6 +341,15 @@ enum ArcOrRef<'a, T: ?Sized> {
 	Ref(&'a T),
 }
 
+impl<'a, T: ?Sized> Clone for ArcOrRef<'a, T> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Arc(owned) => Self::Arc(owned.clone()),
+			Self::Ref(borrowed) => Self::Ref(borrowed),
+		}
+	}
+}
+
 impl<'a, T: ?Sized> AsRef<T> for ArcOrRef<'a, T> {
 	fn as_ref(&self) -> &T {
 		match self {
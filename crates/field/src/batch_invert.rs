@@ -0,0 +1,167 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Batch inversion of packed field elements with Montgomery's trick.
+//!
+//! Montgomery's trick replaces $n$ inversions with a single inversion and $3n$ multiplications:
+//! one per element to accumulate the running product, and two per element to peel the inverses off
+//! the inverted product. Here the trick is applied lane-wise to packed elements: every lane keeps its
+//! own running product, so all the multiplications are SIMD operations and a whole chunk of packed
+//! elements costs only one packed inversion. Chunks are processed in parallel.
+
+use crate::{Field, PackedField};
+use rayon::prelude::*;
+
+/// Number of packed elements that share a single packed inversion.
+const CHUNK_SIZE: usize = 1 << 10;
+
+/// Computes the inverses of `values` into `out`, mapping zero scalars to zero.
+///
+/// ## Preconditions
+///
+/// * `values` and `out` must have the same length
+pub fn batch_invert_or_zero<P: PackedField>(values: &[P], out: &mut [P]) {
+	assert_eq!(values.len(), out.len(), "Both arguments must contain the same number of elements");
+
+	values
+		.par_chunks(CHUNK_SIZE)
+		.zip(out.par_chunks_mut(CHUNK_SIZE))
+		.for_each(|(values, out)| batch_invert_or_zero_chunk(values, out));
+}
+
+/// Replaces every element of `values` with its inverse, mapping zero scalars to zero.
+///
+/// The backward pass needs both the inputs and the running products, so each parallel task copies
+/// the chunk it inverts into a scratch buffer of up to `CHUNK_SIZE` packed elements, which it
+/// reuses across chunks.
+pub fn batch_invert_or_zero_in_place<P: PackedField>(values: &mut [P]) {
+	values.par_chunks_mut(CHUNK_SIZE).for_each_init(
+		|| Vec::with_capacity(CHUNK_SIZE),
+		|inputs, chunk| {
+			inputs.clear();
+			inputs.extend_from_slice(chunk);
+			batch_invert_or_zero_chunk(inputs, chunk);
+		},
+	);
+}
+
+fn batch_invert_or_zero_chunk<P: PackedField>(values: &[P], out: &mut [P]) {
+	debug_assert_eq!(values.len(), out.len());
+
+	// Forward pass: out[i] holds the lane-wise product of the non-zero scalars of values[..i].
+	let mut acc = P::one();
+	for (&value, prefix) in values.iter().zip(out.iter_mut()) {
+		*prefix = acc;
+		acc *= replace_zeros_with_one(value);
+	}
+
+	// Zero lanes were skipped in the running product, so `acc` has no zero lanes.
+	let mut acc_inv = acc.invert_or_zero();
+
+	// Backward pass: peel off the inputs one by one from the inverted product.
+	for (&value, res) in values.iter().zip(out.iter_mut()).rev() {
+		let inv = acc_inv * *res;
+		acc_inv *= replace_zeros_with_one(value);
+
+		// `inv` is the inverse of `value` in the non-zero lanes and one in the zero lanes.
+		*res = zero_out_lanes(inv, value);
+	}
+}
+
+/// Sets the lanes of `inv` where `value` is zero to zero.
+#[inline]
+fn zero_out_lanes<P: PackedField>(inv: P, value: P) -> P {
+	if value.iter().any(|scalar| scalar.is_zero()) {
+		P::from_fn(|i| {
+			// Safety: `i` is always less than `WIDTH`
+			if unsafe { value.get_unchecked(i) }.is_zero() {
+				P::Scalar::ZERO
+			} else {
+				unsafe { inv.get_unchecked(i) }
+			}
+		})
+	} else {
+		inv
+	}
+}
+
+#[inline]
+fn replace_zeros_with_one<P: PackedField>(value: P) -> P {
+	if value.iter().any(|scalar| scalar.is_zero()) {
+		P::from_fn(|i| {
+			// Safety: `i` is always less than `WIDTH`
+			let scalar = unsafe { value.get_unchecked(i) };
+			if scalar.is_zero() {
+				P::Scalar::ONE
+			} else {
+				scalar
+			}
+		})
+	} else {
+		value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		BinaryField128b, BinaryField128bPolyval, BinaryField8b, PackedBinaryField16x8b,
+		PackedBinaryField4x32b,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	fn random_with_zeros<P: PackedField>(rng: &mut StdRng, len: usize) -> Vec<P> {
+		(0..len)
+			.map(|_| {
+				P::from_fn(|_| {
+					if rng.gen_bool(0.1) {
+						P::Scalar::ZERO
+					} else {
+						<P::Scalar as Field>::random(&mut *rng)
+					}
+				})
+			})
+			.collect()
+	}
+
+	fn check_batch_invert<P: PackedField>(len: usize) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = random_with_zeros::<P>(&mut rng, len);
+		let expected = values
+			.iter()
+			.map(|&value| value.invert_or_zero())
+			.collect::<Vec<_>>();
+
+		let mut out = vec![P::default(); len];
+		batch_invert_or_zero(&values, &mut out);
+		assert_eq!(out, expected);
+
+		let mut in_place = values.clone();
+		batch_invert_or_zero_in_place(&mut in_place);
+		assert_eq!(in_place, expected);
+	}
+
+	#[test]
+	fn test_batch_invert_scalars() {
+		check_batch_invert::<BinaryField8b>(100);
+		check_batch_invert::<BinaryField128b>(3 * CHUNK_SIZE + 5);
+		check_batch_invert::<BinaryField128bPolyval>(17);
+	}
+
+	#[test]
+	fn test_batch_invert_packed() {
+		check_batch_invert::<PackedBinaryField16x8b>(CHUNK_SIZE + 1);
+		check_batch_invert::<PackedBinaryField4x32b>(2 * CHUNK_SIZE);
+	}
+
+	#[test]
+	fn test_batch_invert_empty_and_all_zeros() {
+		check_batch_invert::<PackedBinaryField4x32b>(0);
+
+		let mut values = vec![PackedBinaryField4x32b::zero(); 10];
+		batch_invert_or_zero_in_place(&mut values);
+		assert!(values
+			.iter()
+			.all(|&value| value == PackedBinaryField4x32b::zero()));
+	}
+}
@@ -60,6 +60,16 @@ pub trait TowerField: BinaryField {
 	fn mul_primitive(self, iota: usize) -> Result<Self, Error> {
 		Ok(self * <Self as ExtensionField<BinaryField1b>>::basis(1 << iota)?)
 	}
+
+	/// Returns the smallest tower level $\iota$ such that the element lies in $T_{\iota}$.
+	///
+	/// $T_{\iota}$ is the subfield fixed by the Frobenius power $x \mapsto x^{2^{2^\iota}}$, so
+	/// this does not depend on the representation of the tower.
+	fn min_tower_level(self) -> usize {
+		(0..Self::TOWER_LEVEL)
+			.find(|&iota| (0..1 << iota).fold(self, |x, _| x.square()) == self)
+			.unwrap_or(Self::TOWER_LEVEL)
+	}
 }
 
 pub(super) trait TowerExtensionField:
@@ -913,6 +923,17 @@ pub(crate) mod tests {
 		assert!(is_binary_field_valid_generator::<BinaryField128b>());
 	}
 
+	#[test]
+	fn test_min_tower_level() {
+		assert_eq!(BinaryField128b::ZERO.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::ONE.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::from(BF4::new(U4::new(0x2))).min_tower_level(), 1);
+		assert_eq!(BinaryField128b::from(BF8::new(0x2D)).min_tower_level(), 3);
+		assert_eq!(BinaryField128b::from(BF64::new(1 << 40)).min_tower_level(), 6);
+		assert_eq!(BinaryField128b::new(1 << 100).min_tower_level(), 7);
+		assert_eq!(BF16::new(0x100).min_tower_level(), 4);
+	}
+
 	proptest! {
 		#[test]
 		fn test_add_sub_subfields_is_commutative(a_val in any::<u8>(), b_val in any::<u64>()) {
@@ -7,6 +7,8 @@ pub enum Error {
 	ExtensionDegreeMismatch,
 	#[error("the argument has too large a field extension degree")]
 	ExtensionDegreeTooHigh,
+	#[error("failed to find an isomorphism between the fields")]
+	IsomorphismNotFound,
 	#[error("index {index} is out of range 0..{max}")]
 	IndexOutOfRange { index: usize, max: usize },
 	/// Thrown when trying to initialize a binary field element with a value bigger than what fits
@@ -0,0 +1,264 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Runtime construction of isomorphisms between binary field representations.
+//!
+//! Any two binary fields with the same degree are isomorphic. Given a generator $\alpha$ of the
+//! source field, an isomorphism is determined by the image $\beta$ of $\alpha$, which can be any
+//! root of the minimal polynomial of $\alpha$ in the target field. The root is found with
+//! deterministic trace-based equal-degree factorization, and the $\mathbb{F}_2$-linear matrix of
+//! the isomorphism is then recovered by Gaussian elimination from the images of
+//! $1, \alpha, \ldots, \alpha^{n-1}$.
+
+use crate::{
+	affine_transformation::FieldAffineTransformation, BinaryField, BinaryField1b, Error,
+	ExtensionField, Field,
+};
+
+/// Computes an isomorphism from the binary field `IF` to the binary field `OF`.
+///
+/// The result maps the $\mathbb{F}_2$-basis of `IF`, as enumerated by
+/// [`ExtensionField::iter_bases`], to `OF` elements, so it can be used both as a scalar
+/// [`crate::affine_transformation::Transformation`] and to build packed transformations with
+/// [`crate::affine_transformation::PackedTransformationFactory`]. The isomorphism is not unique:
+/// composing it with any Frobenius automorphism of `OF` gives another valid one.
+///
+/// Note that this operation performs up to $O(n^4)$ field multiplications, where $n$ is the field
+/// degree: finding a root of the minimal polynomial takes up to $n$ trace polynomial evaluations,
+/// each of $O(n^3)$ multiplications. It is better to compute the transformation once and reuse it.
+///
+/// ## Throws
+///
+/// * `Error::ExtensionDegreeMismatch` if the fields have different degrees
+/// * `Error::ExtensionDegreeTooHigh` if the fields have more than 128 bits
+/// * `Error::IsomorphismNotFound` if `IF::MULTIPLICATIVE_GENERATOR` does not generate `IF`
+pub fn make_field_isomorphism<IF, OF>() -> Result<FieldAffineTransformation<OF, Vec<OF>>, Error>
+where
+	IF: BinaryField,
+	OF: BinaryField,
+{
+	let n = IF::N_BITS;
+	if n != OF::N_BITS {
+		return Err(Error::ExtensionDegreeMismatch);
+	}
+	if n > u128::BITS as usize {
+		return Err(Error::ExtensionDegreeTooHigh);
+	}
+
+	let alpha = IF::MULTIPLICATIVE_GENERATOR;
+	let min_poly = minimal_polynomial(alpha)?
+		.into_iter()
+		.map(|coeff| if coeff { OF::ONE } else { OF::ZERO })
+		.collect();
+	let beta = find_root(min_poly).ok_or(Error::IsomorphismNotFound)?;
+
+	// Each row states that the image of alpha^k equals beta^k.
+	let mut rows = Vec::with_capacity(n);
+	let mut alpha_pow = IF::ONE;
+	let mut beta_pow = OF::ONE;
+	for _ in 0..n {
+		rows.push((to_bits(alpha_pow), beta_pow));
+		alpha_pow *= alpha;
+		beta_pow *= beta;
+	}
+
+	let bases = solve_linear_system(rows).ok_or(Error::IsomorphismNotFound)?;
+	Ok(FieldAffineTransformation::new(bases))
+}
+
+/// Returns the coefficients of the minimal polynomial of `alpha` over $\mathbb{F}_2$, in order of
+/// increasing degree.
+///
+/// The minimal polynomial is computed as the product of $(X - \alpha^{2^i})$ over all conjugates.
+fn minimal_polynomial<F: BinaryField>(alpha: F) -> Result<Vec<bool>, Error> {
+	let mut poly = vec![F::ONE];
+	let mut conjugate = alpha;
+	for i in 0..F::N_BITS {
+		if i != 0 && conjugate == alpha {
+			// The element lies in a proper subfield.
+			return Err(Error::IsomorphismNotFound);
+		}
+
+		poly.push(F::ZERO);
+		for j in (1..poly.len()).rev() {
+			poly[j] = poly[j - 1] + poly[j] * conjugate;
+		}
+		poly[0] *= conjugate;
+
+		conjugate = conjugate.square();
+	}
+
+	poly.into_iter()
+		.map(|coeff| match coeff {
+			coeff if coeff == F::ZERO => Ok(false),
+			coeff if coeff == F::ONE => Ok(true),
+			_ => Err(Error::IsomorphismNotFound),
+		})
+		.collect()
+}
+
+/// Finds a root of a monic polynomial which splits into distinct linear factors over `F`.
+///
+/// For every $\delta$, the roots of the polynomial are separated by the value of
+/// $\mathrm{Tr}(\delta X)$, so $\gcd(p(X), \mathrm{Tr}(\delta X))$ is a proper factor unless all
+/// roots have equal traces. Since the trace form is non-degenerate, a basis element $\delta$
+/// separating any two distinct roots always exists.
+fn find_root<F: BinaryField>(mut poly: Vec<F>) -> Option<F> {
+	while poly.len() > 2 {
+		poly = (0..F::N_BITS).find_map(|i| {
+			let delta =
+				<F as ExtensionField<BinaryField1b>>::basis(i).expect("i is less than N_BITS");
+			let factor = poly_gcd(poly.clone(), trace_polynomial(delta, &poly));
+			(factor.len() > 1 && factor.len() < poly.len()).then_some(factor)
+		})?;
+	}
+
+	// A monic linear polynomial X + c has the root -c.
+	(poly.len() == 2).then(|| -poly[0])
+}
+
+/// Computes $\mathrm{Tr}(\delta X) = \sum_{i=0}^{n-1} (\delta X)^{2^i}$ modulo `modulus`.
+fn trace_polynomial<F: BinaryField>(delta: F, modulus: &[F]) -> Vec<F> {
+	let mut term = vec![F::ZERO, delta];
+	poly_rem(&mut term, modulus);
+
+	let mut trace = term.clone();
+	for _ in 1..F::N_BITS {
+		// Squaring is linear in characteristic 2, so only the coefficients need squaring.
+		let mut squared = vec![F::ZERO; (2 * term.len()).saturating_sub(1)];
+		for (i, coeff) in term.iter().enumerate() {
+			squared[2 * i] = coeff.square();
+		}
+		poly_rem(&mut squared, modulus);
+		term = squared;
+
+		if trace.len() < term.len() {
+			trace.resize(term.len(), F::ZERO);
+		}
+		for (acc, &coeff) in trace.iter_mut().zip(term.iter()) {
+			*acc += coeff;
+		}
+	}
+
+	trim(&mut trace);
+	trace
+}
+
+/// Reduces `poly` modulo the monic polynomial `modulus` in place.
+fn poly_rem<F: Field>(poly: &mut Vec<F>, modulus: &[F]) {
+	let degree = modulus.len() - 1;
+	while poly.len() > degree {
+		let lead = poly.pop().expect("poly.len() > degree >= 0");
+		let offset = poly.len() - degree;
+		for (coeff, &modulus_coeff) in poly[offset..].iter_mut().zip(&modulus[..degree]) {
+			*coeff -= lead * modulus_coeff;
+		}
+	}
+	trim(poly);
+}
+
+/// Computes the monic greatest common divisor of two polynomials.
+fn poly_gcd<F: Field>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+	trim(&mut a);
+	trim(&mut b);
+	while !b.is_empty() {
+		make_monic(&mut b);
+		poly_rem(&mut a, &b);
+		std::mem::swap(&mut a, &mut b);
+	}
+	make_monic(&mut a);
+	a
+}
+
+fn make_monic<F: Field>(poly: &mut [F]) {
+	if let Some(&lead) = poly.last() {
+		let lead_inv = lead.invert().expect("leading coefficient is non-zero");
+		for coeff in poly.iter_mut() {
+			*coeff *= lead_inv;
+		}
+	}
+}
+
+fn trim<F: Field>(poly: &mut Vec<F>) {
+	while poly.last().is_some_and(|coeff| coeff.is_zero()) {
+		poly.pop();
+	}
+}
+
+fn to_bits<F: BinaryField>(elem: F) -> u128 {
+	<F as ExtensionField<BinaryField1b>>::iter_bases(&elem)
+		.enumerate()
+		.filter(|(_, bit)| *bit == BinaryField1b::ONE)
+		.fold(0, |acc, (i, _)| acc | (1u128 << i))
+}
+
+/// Solves the linear system where each row is a pair of the $\mathbb{F}_2$-coordinates of an
+/// input and the corresponding output, returning the outputs for the unit coordinate vectors.
+fn solve_linear_system<F: Field>(mut rows: Vec<(u128, F)>) -> Option<Vec<F>> {
+	let n = rows.len();
+	for i in 0..n {
+		let pivot = (i..n).find(|&j| (rows[j].0 >> i) & 1 == 1)?;
+		rows.swap(i, pivot);
+
+		let (pivot_bits, pivot_value) = rows[i];
+		for (j, (bits, value)) in rows.iter_mut().enumerate() {
+			if j != i && (*bits >> i) & 1 == 1 {
+				*bits ^= pivot_bits;
+				*value += pivot_value;
+			}
+		}
+	}
+
+	Some(rows.into_iter().map(|(_, value)| value).collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		affine_transformation::Transformation, AESTowerField32b, AESTowerField8b, BinaryField128b,
+		BinaryField128bPolyval, BinaryField16b, BinaryField32b, BinaryField8b,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	fn check_isomorphism<IF: BinaryField, OF: BinaryField>() {
+		let transformation = make_field_isomorphism::<IF, OF>().unwrap();
+		let mut rng = StdRng::seed_from_u64(0);
+
+		assert_eq!(transformation.transform(&IF::ZERO), OF::ZERO);
+		assert_eq!(transformation.transform(&IF::ONE), OF::ONE);
+		for _ in 0..100 {
+			let a = <IF as Field>::random(&mut rng);
+			let b = <IF as Field>::random(&mut rng);
+			let a_image: OF = transformation.transform(&a);
+			let b_image: OF = transformation.transform(&b);
+
+			assert_eq!(transformation.transform(&(a + b)), a_image + b_image);
+			assert_eq!(transformation.transform(&(a * b)), a_image * b_image);
+		}
+	}
+
+	#[test]
+	fn test_isomorphism_same_field() {
+		check_isomorphism::<BinaryField8b, BinaryField8b>();
+	}
+
+	#[test]
+	fn test_isomorphism_aes_tower() {
+		check_isomorphism::<AESTowerField8b, BinaryField8b>();
+		check_isomorphism::<BinaryField8b, AESTowerField8b>();
+		check_isomorphism::<BinaryField32b, AESTowerField32b>();
+	}
+
+	#[test]
+	fn test_isomorphism_polyval() {
+		check_isomorphism::<BinaryField128b, BinaryField128bPolyval>();
+	}
+
+	#[test]
+	fn test_degree_mismatch() {
+		assert!(matches!(
+			make_field_isomorphism::<BinaryField8b, BinaryField16b>(),
+			Err(Error::ExtensionDegreeMismatch)
+		));
+	}
+}
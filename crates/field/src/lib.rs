@@ -17,11 +17,13 @@ pub mod affine_transformation;
 pub mod arch;
 pub mod arithmetic_traits;
 pub mod as_packed_field;
+pub mod batch_invert;
 pub mod binary_field;
 mod binary_field_arithmetic;
 pub mod error;
 pub mod extension;
 pub mod field;
+pub mod isomorphism;
 mod macros;
 pub mod packed;
 pub mod packed_aes_field;
@@ -34,6 +36,7 @@ pub mod underlier;
 pub mod util;
 
 pub use aes_field::*;
+pub use batch_invert::{batch_invert_or_zero, batch_invert_or_zero_in_place};
 pub use binary_field::*;
 pub use error::*;
 pub use extension::*;
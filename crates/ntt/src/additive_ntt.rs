@@ -85,12 +85,38 @@ impl<F: BinaryField> AdditiveNTTWithOTFCompute<F> {
 	pub fn with_domain_field<DomainField: BinaryField + Into<F>>(
 		log_domain_size: usize,
 	) -> Result<Self, Error> {
-		let s_evals = precompute_subspace_evals::<F, DomainField>(log_domain_size)?
+		let basis = canonical_domain_basis::<F, DomainField>(log_domain_size)?;
+		Self::with_affine_domain(&basis, F::ZERO)
+	}
+
+	/// Creates an instance of `AdditiveNTTWithOTFCompute` over the affine subspace
+	/// `shift + span(basis)`.
+	///
+	/// The basis and the shift are converted to `F` before the twiddle factors are computed, so
+	/// the twiddles may lie in an extension of `DomainField`. The `coset`-th coset of a transform
+	/// of size $2^n$ is evaluated on the points `shift + span(basis)` with indices in
+	/// `coset * 2^n..(coset + 1) * 2^n`.
+	///
+	/// ## Throws
+	///
+	/// * `Error::LinearlyDependentBasis` if the basis elements are not linearly independent
+	pub fn with_affine_domain<DomainField: BinaryField + Into<F>>(
+		basis: &[DomainField],
+		shift: DomainField,
+	) -> Result<Self, Error> {
+		let log_domain_size = basis.len();
+		let (s_evals, shift_evals) = precompute_subspace_evals(
+			&basis.iter().map(|&elem| elem.into()).collect::<Vec<F>>(),
+			shift.into(),
+		)?;
+
+		let s_evals = s_evals
 			.into_iter()
+			.zip(shift_evals)
 			.enumerate()
-			.map(|(i, s_evals_i)| OnTheFlyTwiddleAccess {
+			.map(|(i, (s_evals_i, shift_eval_i))| OnTheFlyTwiddleAccess {
 				log_n: log_domain_size - 1 - i,
-				offset: F::ZERO,
+				offset: shift_eval_i,
 				s_evals: s_evals_i,
 			})
 			.collect();
@@ -295,13 +321,35 @@ impl<F: BinaryField> AdditiveNTTWithPrecompute<F> {
 	pub fn with_domain_field<DomainField: BinaryField + Into<F>>(
 		log_domain_size: usize,
 	) -> Result<Self, Error> {
-		let s_evals = precompute_subspace_evals::<F, DomainField>(log_domain_size)?;
+		let basis = canonical_domain_basis::<F, DomainField>(log_domain_size)?;
+		Self::with_affine_domain(&basis, F::ZERO)
+	}
+
+	/// Creates an instance of `AdditiveNTTWithPrecompute` over the affine subspace
+	/// `shift + span(basis)`.
+	///
+	/// See [`AdditiveNTTWithOTFCompute::with_affine_domain`] for the description of the domain.
+	///
+	/// ## Throws
+	///
+	/// * `Error::LinearlyDependentBasis` if the basis elements are not linearly independent
+	pub fn with_affine_domain<DomainField: BinaryField + Into<F>>(
+		basis: &[DomainField],
+		shift: DomainField,
+	) -> Result<Self, Error> {
+		let log_domain_size = basis.len();
+		let (s_evals, shift_evals) = precompute_subspace_evals(
+			&basis.iter().map(|&elem| elem.into()).collect::<Vec<F>>(),
+			shift.into(),
+		)?;
+
 		let s_evals_expanded = s_evals
 			.iter()
+			.zip(shift_evals)
 			.enumerate()
-			.map(|(i, s_evals_i)| {
+			.map(|(i, (s_evals_i, shift_eval_i))| {
 				let mut expanded = Vec::with_capacity(1 << s_evals_i.len());
-				expanded.push(F::ZERO);
+				expanded.push(shift_eval_i);
 				for &eval in s_evals_i.iter() {
 					for i in 0..expanded.len() {
 						expanded.push(expanded[i] + eval);
@@ -355,60 +403,79 @@ where
 	}
 }
 
-fn precompute_subspace_evals<F: BinaryField, DomainField: BinaryField + Into<F>>(
+/// Returns the first `log_domain_size` canonical basis elements of `DomainField`, converted to `F`.
+fn canonical_domain_basis<F: BinaryField, DomainField: BinaryField + Into<F>>(
 	log_domain_size: usize,
-) -> Result<Vec<Vec<F>>, Error> {
+) -> Result<Vec<F>, Error> {
 	if DomainField::N_BITS < log_domain_size {
 		return Err(Error::FieldTooSmall { log_domain_size });
 	}
 
-	let mut s_evals = Vec::with_capacity(log_domain_size);
-
-	// normalization_consts[i] = W_i(2^i)
-	let mut normalization_consts = Vec::with_capacity(log_domain_size);
-	normalization_consts.push(F::ONE);
-
-	let s0_evals = (1..log_domain_size)
+	Ok((0..log_domain_size)
 		.map(|i| {
 			DomainField::basis(i)
 				.expect("basis vector must exist because of FieldTooSmall check above")
 				.into()
 		})
-		.collect::<Vec<F>>();
+		.collect())
+}
 
-	s_evals.push(s0_evals);
+/// Computes the normalized subspace polynomial evaluations for the domain `shift + span(basis)`.
+///
+/// Returns `s_evals`, where `s_evals[i][j]` is $\hat{W}_i(\beta_{i + 1 + j})$, and `shift_evals`,
+/// where `shift_evals[i]` is $\hat{W}_i(\text{shift})$. Since the subspace polynomials are
+/// linear, the latter is the offset of all twiddle factors of the $i$-th layer.
+fn precompute_subspace_evals<F: BinaryField>(
+	basis: &[F],
+	shift: F,
+) -> Result<(Vec<Vec<F>>, Vec<F>), Error> {
+	let log_domain_size = basis.len();
+	let mut s_evals = Vec::with_capacity(log_domain_size);
+	let mut shift_evals = Vec::with_capacity(log_domain_size);
+
+	// normalization_consts[i] = W_i(β_i)
+	let mut normalization_consts = Vec::with_capacity(log_domain_size);
+
+	// W_0(X) = X
+	if let Some((&beta_0, s0_evals)) = basis.split_first() {
+		normalization_consts.push(beta_0);
+		s_evals.push(s0_evals.to_vec());
+		shift_evals.push(shift);
+	}
 
 	for _ in 1..log_domain_size {
-		let (norm_const_i, s_i_evals) = {
-			let norm_prev = *normalization_consts
-				.last()
-				.expect("normalization_consts is not empty");
-			let s_prev_evals = s_evals.last().expect("s_evals is not empty");
-
-			let norm_const_i = subspace_map(s_prev_evals[0], norm_prev);
-			let s_i_evals = s_prev_evals
-				.iter()
-				.skip(1)
-				.map(|&s_ij_prev| subspace_map(s_ij_prev, norm_prev))
-				.collect::<Vec<_>>();
-
-			(norm_const_i, s_i_evals)
-		};
+		let norm_prev = *normalization_consts
+			.last()
+			.expect("normalization_consts is not empty");
+		let s_prev_evals = s_evals.last().expect("s_evals is not empty");
+		let shift_prev_eval = *shift_evals.last().expect("shift_evals is not empty");
+
+		let norm_const_i = subspace_map(s_prev_evals[0], norm_prev);
+		let s_i_evals = s_prev_evals
+			.iter()
+			.skip(1)
+			.map(|&s_ij_prev| subspace_map(s_ij_prev, norm_prev))
+			.collect::<Vec<_>>();
 
 		normalization_consts.push(norm_const_i);
 		s_evals.push(s_i_evals);
+		shift_evals.push(subspace_map(shift_prev_eval, norm_prev));
 	}
 
-	for (norm_const_i, s_evals_i) in normalization_consts.iter().zip(s_evals.iter_mut()) {
-		let inv_norm_const = norm_const_i
-			.invert()
-			.expect("normalization constants are nonzero");
+	for ((norm_const_i, s_evals_i), shift_eval_i) in normalization_consts
+		.iter()
+		.zip(s_evals.iter_mut())
+		.zip(shift_evals.iter_mut())
+	{
+		// W_i(β_i) is zero exactly when β_i lies in the span of the previous basis elements.
+		let inv_norm_const = norm_const_i.invert().ok_or(Error::LinearlyDependentBasis)?;
 		for s_ij in s_evals_i.iter_mut() {
 			*s_ij *= inv_norm_const;
 		}
+		*shift_eval_i *= inv_norm_const;
 	}
 
-	Ok(s_evals)
+	Ok((s_evals, shift_evals))
 }
 
 fn subspace_map<F: Field>(elem: F, constant: F) -> F {
@@ -556,8 +623,9 @@ where
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 	use super::*;
+	use crate::novel_basis::{BasisConversion, NovelPolyBasis};
 	use assert_matches::assert_matches;
 	use binius_field::{
 		arch::packed_32::PackedBinaryField1x32b,
@@ -644,7 +712,7 @@ mod tests {
 		Ok(())
 	}
 
-	trait SimpleAdditiveNTT<F: BinaryField> {
+	pub(crate) trait SimpleAdditiveNTT<F: BinaryField> {
 		fn forward_transform_simple<FF>(&self, data: &mut [FF], coset: u32) -> Result<(), Error>
 		where
 			FF: ExtensionField<F>;
@@ -785,6 +853,100 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_additive_ntt_over_affine_domain() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let basis = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(8)
+			.collect::<Vec<_>>();
+		let shift = <BinaryField32b as Field>::random(&mut rng);
+		let ntt =
+			AdditiveNTTWithOTFCompute::<BinaryField32b>::with_affine_domain(&basis, shift).unwrap();
+		let novel_basis = NovelPolyBasis::with_basis(&basis).unwrap();
+
+		let data = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(1 << 6)
+			.collect::<Vec<_>>();
+		let mut monomial = data.clone();
+		novel_basis.novel_to_monomial(&mut monomial, 0).unwrap();
+
+		for coset in 0..4 {
+			let mut result = data.clone();
+			ntt.forward_transform_simple(&mut result, coset).unwrap();
+			for (i, &eval) in result.iter().enumerate() {
+				let x = shift + subset_sum(&basis, basis.len(), (coset as usize) << 6 | i);
+				let expected = monomial
+					.iter()
+					.rev()
+					.fold(BinaryField32b::ZERO, |acc, &coeff| acc * x + coeff);
+				assert_eq!(eval, expected);
+			}
+
+			ntt.inverse_transform_simple(&mut result, coset).unwrap();
+			assert_eq!(result, data);
+		}
+	}
+
+	#[test]
+	fn test_additive_ntt_over_affine_domain_with_extension_twiddles() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let basis = (0..8)
+			.map(|i| BinaryField8b::new(1 << i | 1))
+			.collect::<Vec<_>>();
+		let shift = BinaryField8b::new(0x5a);
+		let ntt =
+			AdditiveNTTWithOTFCompute::<BinaryField8b>::with_affine_domain(&basis, shift).unwrap();
+		let ntt_ext =
+			AdditiveNTTWithOTFCompute::<BinaryField32b>::with_affine_domain(&basis, shift).unwrap();
+		let ntt_ext_with_precompute =
+			AdditiveNTTWithPrecompute::<BinaryField32b>::with_affine_domain(&basis, shift).unwrap();
+
+		let data = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(1 << 6)
+			.collect::<Vec<_>>();
+
+		for coset in 0..4 {
+			let mut result = data.clone();
+			let mut result_ext = data.clone();
+			let mut result_ext_with_precompute = data.clone();
+			ntt.forward_transform_simple(&mut result, coset).unwrap();
+			ntt_ext
+				.forward_transform_simple(&mut result_ext, coset)
+				.unwrap();
+			ntt_ext_with_precompute
+				.forward_transform(&mut result_ext_with_precompute, coset, 0)
+				.unwrap();
+			assert_eq!(result, result_ext);
+			assert_eq!(result, result_ext_with_precompute);
+		}
+	}
+
+	#[test]
+	fn test_additive_ntt_fails_with_linearly_dependent_basis() {
+		let basis = [
+			BinaryField8b::new(1),
+			BinaryField8b::new(2),
+			BinaryField8b::new(3),
+		];
+		assert_matches!(
+			AdditiveNTTWithOTFCompute::<BinaryField8b>::with_affine_domain(
+				&basis,
+				BinaryField8b::ZERO
+			),
+			Err(Error::LinearlyDependentBasis)
+		);
+		assert_matches!(
+			AdditiveNTTWithPrecompute::<BinaryField8b>::with_affine_domain(
+				&basis,
+				BinaryField8b::ZERO
+			),
+			Err(Error::LinearlyDependentBasis)
+		);
+		assert_matches!(NovelPolyBasis::with_basis(&basis), Err(Error::LinearlyDependentBasis));
+	}
+
 	#[test]
 	fn test_additive_ntt_transform_over_larger_field() {
 		let mut rng = StdRng::seed_from_u64(0);
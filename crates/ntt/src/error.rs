@@ -18,4 +18,6 @@ pub enum Error {
 	StrideGreaterThanPackedWidth,
 	#[error("the batch size is greater than the number of elements")]
 	BatchTooLarge,
+	#[error("the domain basis elements must be linearly independent")]
+	LinearlyDependentBasis,
 }
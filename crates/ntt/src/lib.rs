@@ -2,6 +2,8 @@
 
 pub mod additive_ntt;
 pub mod error;
+pub mod novel_basis;
 
 pub use additive_ntt::*;
 pub use error::*;
+pub use novel_basis::*;
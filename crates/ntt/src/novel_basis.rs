@@ -0,0 +1,480 @@
+// Copyright 2024 Ulvetanna Inc.
+
+//! Conversion between the novel polynomial basis of [LCH14] and the monomial basis.
+//!
+//! The novel basis polynomial $X_j$ is $\prod_i \hat{W}_i(X)^{j_i}$, where $j_i$ are the bits of
+//! $j$ and $\hat{W}_i$ is the normalized subspace polynomial vanishing on the span of the first $i$
+//! domain basis elements. A polynomial of degree less than $2^k$ in novel basis splits as
+//! $P_0(X) + \hat{W}_{k-1}(X) P_1(X)$, where $P_0$ and $P_1$ are the halves of the coefficient
+//! vector. Subspace polynomials are linearized, so $\hat{W}_{k-1}$ has only $k$ non-zero
+//! coefficients and multiplying or dividing by it takes $O(k 2^k)$ operations. Applying the split
+//! recursively converts between the bases in $O(n \log^2 n)$ time.
+//!
+//! [LCH14]: <https://arxiv.org/abs/1404.3458>
+
+use super::error::Error;
+use binius_field::{
+	BinaryField, ExtensionField, Field, PackedField, PackedFieldIndexable, RepackedExtension,
+};
+use p3_util::log2_strict_usize;
+
+/// Conversion of polynomial coefficients between the novel polynomial basis of [LCH14] and the
+/// monomial basis.
+///
+/// The batched inputs are interleaved in the same layout as in [`crate::AdditiveNTT`], so the
+/// result of [`Self::monomial_to_novel`] can be passed directly to
+/// [`crate::AdditiveNTT::forward_transform`] of an NTT over the same domain.
+///
+/// [LCH14]: <https://arxiv.org/abs/1404.3458>
+pub trait BasisConversion<P: PackedField> {
+	/// Converts a batch of polynomials from novel basis to monomial basis in place.
+	fn novel_to_monomial(&self, data: &mut [P], log_batch_size: usize) -> Result<(), Error>;
+
+	/// Converts a batch of polynomials from monomial basis to novel basis in place.
+	fn monomial_to_novel(&self, data: &mut [P], log_batch_size: usize) -> Result<(), Error>;
+
+	fn novel_to_monomial_ext<PE>(&self, data: &mut [PE]) -> Result<(), Error>
+	where
+		PE: RepackedExtension<P>,
+		PE::Scalar: ExtensionField<P::Scalar>,
+	{
+		if !PE::Scalar::DEGREE.is_power_of_two() {
+			return Err(Error::PowerOfTwoExtensionDegreeRequired);
+		}
+
+		let log_batch_size = log2_strict_usize(PE::Scalar::DEGREE);
+		self.novel_to_monomial(PE::cast_bases_mut(data), log_batch_size)
+	}
+
+	fn monomial_to_novel_ext<PE>(&self, data: &mut [PE]) -> Result<(), Error>
+	where
+		PE: RepackedExtension<P>,
+		PE::Scalar: ExtensionField<P::Scalar>,
+	{
+		if !PE::Scalar::DEGREE.is_power_of_two() {
+			return Err(Error::PowerOfTwoExtensionDegreeRequired);
+		}
+
+		let log_batch_size = log2_strict_usize(PE::Scalar::DEGREE);
+		self.monomial_to_novel(PE::cast_bases_mut(data), log_batch_size)
+	}
+}
+
+/// The novel polynomial basis over a subspace of dimension `log_domain_size`, represented by the
+/// monomial coefficients of the normalized subspace polynomials.
+#[derive(Debug)]
+pub struct NovelPolyBasis<F: BinaryField> {
+	/// `subspace_polys[i][m]` is the coefficient of $X^{2^m}$ in $\hat{W}_i(X)$.
+	subspace_polys: Vec<Vec<F>>,
+}
+
+impl<F: BinaryField> NovelPolyBasis<F> {
+	pub fn new(log_domain_size: usize) -> Result<Self, Error> {
+		Self::with_domain_field::<F>(log_domain_size)
+	}
+
+	/// Creates the novel basis for the domain spanned by the canonical basis of `DomainField`,
+	/// converted to `F`, which is the domain used by the NTTs constructed with `with_domain_field`.
+	pub fn with_domain_field<DomainField: BinaryField + Into<F>>(
+		log_domain_size: usize,
+	) -> Result<Self, Error> {
+		if DomainField::N_BITS < log_domain_size {
+			return Err(Error::FieldTooSmall { log_domain_size });
+		}
+
+		let basis = (0..log_domain_size)
+			.map(|i| {
+				DomainField::basis(i)
+					.expect("basis vector must exist because of FieldTooSmall check above")
+					.into()
+			})
+			.collect::<Vec<F>>();
+		Self::with_basis(&basis)
+	}
+
+	/// Creates the novel basis for the subspace spanned by `basis`.
+	///
+	/// The novel basis does not depend on the shift of an affine domain, so this is the basis
+	/// used by the NTTs constructed with `with_affine_domain` for any shift.
+	///
+	/// ## Throws
+	///
+	/// * `Error::LinearlyDependentBasis` if the basis elements are not linearly independent
+	pub fn with_basis(basis: &[F]) -> Result<Self, Error> {
+		let mut subspace_polys = Vec::with_capacity(basis.len());
+
+		// Coefficients of the unnormalized subspace polynomial W_i, starting with W_0(X) = X.
+		let mut w_coeffs = vec![F::ONE];
+		for &beta_i in basis {
+			// W_i(β_i) is zero exactly when β_i lies in the span of the previous basis elements.
+			let norm_const = evaluate_linearized(&w_coeffs, beta_i);
+			let norm_const_inv = norm_const.invert().ok_or(Error::LinearlyDependentBasis)?;
+			subspace_polys.push(
+				w_coeffs
+					.iter()
+					.map(|&coeff| coeff * norm_const_inv)
+					.collect(),
+			);
+
+			// W_{i+1}(X) = W_i(X)^2 + W_i(β_i) W_i(X)
+			let mut next_coeffs = vec![F::ZERO; w_coeffs.len() + 1];
+			for (m, &coeff) in w_coeffs.iter().enumerate() {
+				next_coeffs[m] += norm_const * coeff;
+				next_coeffs[m + 1] += coeff.square();
+			}
+			w_coeffs = next_coeffs;
+		}
+
+		Ok(Self { subspace_polys })
+	}
+
+	pub fn log_domain_size(&self) -> usize {
+		self.subspace_polys.len()
+	}
+
+	/// Get the coefficient of $X^{2^m}$ in the normalized subspace polynomial $\hat{W}_i(X)$.
+	///
+	/// ## Preconditions
+	///
+	/// * `i` must be less than `self.log_domain_size()`
+	/// * `m` must be at most `i`
+	pub fn get_subspace_poly_coeff(&self, i: usize, m: usize) -> F {
+		self.subspace_polys[i][m]
+	}
+}
+
+impl<F, P> BasisConversion<P> for NovelPolyBasis<F>
+where
+	F: BinaryField,
+	P: PackedFieldIndexable<Scalar = F>,
+{
+	fn novel_to_monomial(&self, data: &mut [P], log_batch_size: usize) -> Result<(), Error> {
+		if data.is_empty() {
+			return Ok(());
+		}
+
+		let log_n = check_conversion_inputs(self.log_domain_size(), data, log_batch_size)?;
+		if log_batch_size >= P::LOG_WIDTH {
+			let row_len = 1 << (log_batch_size - P::LOG_WIDTH);
+			novel_to_monomial_rows(&self.subspace_polys, data, log_n, row_len);
+		} else {
+			let row_len = 1 << log_batch_size;
+			novel_to_monomial_rows(
+				&self.subspace_polys,
+				P::unpack_scalars_mut(data),
+				log_n,
+				row_len,
+			);
+		}
+		Ok(())
+	}
+
+	fn monomial_to_novel(&self, data: &mut [P], log_batch_size: usize) -> Result<(), Error> {
+		if data.is_empty() {
+			return Ok(());
+		}
+
+		let log_n = check_conversion_inputs(self.log_domain_size(), data, log_batch_size)?;
+		if log_batch_size >= P::LOG_WIDTH {
+			let row_len = 1 << (log_batch_size - P::LOG_WIDTH);
+			monomial_to_novel_rows(&self.subspace_polys, data, log_n, row_len);
+		} else {
+			let row_len = 1 << log_batch_size;
+			monomial_to_novel_rows(
+				&self.subspace_polys,
+				P::unpack_scalars_mut(data),
+				log_n,
+				row_len,
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Evaluates the linearized polynomial $\sum_m c_m X^{2^m}$ at `x`.
+fn evaluate_linearized<F: Field>(coeffs: &[F], x: F) -> F {
+	let mut x_pow = x;
+	let mut result = F::ZERO;
+	for &coeff in coeffs {
+		result += coeff * x_pow;
+		x_pow = x_pow.square();
+	}
+	result
+}
+
+fn check_conversion_inputs<P: PackedField>(
+	log_domain_size: usize,
+	data: &[P],
+	log_batch_size: usize,
+) -> Result<usize, Error> {
+	if !data.len().is_power_of_two() {
+		return Err(Error::PowerOfTwoLengthRequired);
+	}
+
+	let n = (data.len() * P::WIDTH) >> log_batch_size;
+	if n == 0 {
+		return Err(Error::BatchTooLarge);
+	}
+
+	let log_n = n.trailing_zeros() as usize;
+	if log_n > log_domain_size {
+		return Err(Error::DomainTooSmall {
+			log_required_domain_size: log_n,
+		});
+	}
+	Ok(log_n)
+}
+
+/// Converts from novel to monomial basis, where each coefficient is a row of `row_len` packed
+/// elements.
+///
+/// Proceeds bottom-up, replacing every block $(P_0, P_1)$ with $P_0 + \hat{W}_{l-1} P_1$.
+fn novel_to_monomial_rows<P: PackedField>(
+	subspace_polys: &[Vec<P::Scalar>],
+	data: &mut [P],
+	log_n: usize,
+	row_len: usize,
+) {
+	let mut acc = vec![P::zero(); row_len];
+	for l in 1..=log_n {
+		let half = 1 << (l - 1);
+		let subspace_poly = &subspace_polys[l - 1];
+
+		for block in data.chunks_exact_mut(row_len << l) {
+			// Output coefficient t only depends on the inputs at positions t and above, so the
+			// block can be updated in place in ascending order.
+			for t in 0..2 * half {
+				if t < half {
+					acc.copy_from_slice(&block[t * row_len..(t + 1) * row_len]);
+				} else {
+					acc.fill(P::zero());
+				}
+
+				for (m, &coeff) in subspace_poly.iter().enumerate() {
+					let shift = 1 << m;
+					if t < shift || t - shift >= half {
+						continue;
+					}
+
+					let src = half + t - shift;
+					for (acc_k, &src_k) in acc
+						.iter_mut()
+						.zip(&block[src * row_len..(src + 1) * row_len])
+					{
+						*acc_k += src_k * coeff;
+					}
+				}
+
+				block[t * row_len..(t + 1) * row_len].copy_from_slice(&acc);
+			}
+		}
+	}
+}
+
+/// Converts from monomial to novel basis, where each coefficient is a row of `row_len` packed
+/// elements.
+///
+/// Proceeds top-down, replacing every block with the remainder and the quotient of its division
+/// by $\hat{W}_{l-1}$.
+fn monomial_to_novel_rows<P: PackedField>(
+	subspace_polys: &[Vec<P::Scalar>],
+	data: &mut [P],
+	log_n: usize,
+	row_len: usize,
+) {
+	let mut quotient_coeff = vec![P::zero(); row_len];
+	for l in (1..=log_n).rev() {
+		let half = 1 << (l - 1);
+		let (&lead, lower) = subspace_polys[l - 1]
+			.split_last()
+			.expect("subspace polynomial W_i has i + 1 coefficients");
+		let lead_inv = lead
+			.invert()
+			.expect("subspace polynomials have non-zero leading coefficients");
+
+		for block in data.chunks_exact_mut(row_len << l) {
+			// Long division, where the quotient coefficient takes the place of the eliminated
+			// leading coefficient.
+			for t in (half..2 * half).rev() {
+				for (quotient_k, &block_k) in quotient_coeff
+					.iter_mut()
+					.zip(&block[t * row_len..(t + 1) * row_len])
+				{
+					*quotient_k = block_k * lead_inv;
+				}
+
+				for (m, &coeff) in lower.iter().enumerate() {
+					let dst = t - half + (1 << m);
+					for (dst_k, &quotient_k) in block[dst * row_len..(dst + 1) * row_len]
+						.iter_mut()
+						.zip(&quotient_coeff)
+					{
+						*dst_k -= quotient_k * coeff;
+					}
+				}
+
+				block[t * row_len..(t + 1) * row_len].copy_from_slice(&quotient_coeff);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{additive_ntt::tests::SimpleAdditiveNTT, AdditiveNTTWithOTFCompute};
+	use binius_field::{
+		AESTowerField8b, BinaryField16b, BinaryField32b, BinaryField8b, PackedBinaryField16x8b,
+		PackedBinaryField4x32b,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+	use std::iter::repeat_with;
+
+	fn evaluate_monomial<F: Field>(coeffs: &[F], x: F) -> F {
+		coeffs
+			.iter()
+			.rev()
+			.fold(F::ZERO, |eval, &coeff| eval * x + coeff)
+	}
+
+	fn subspace_point<F: BinaryField>(index: usize) -> F {
+		(0..F::N_BITS)
+			.filter(|b| (index >> b) & 1 == 1)
+			.map(|b| F::basis(b).unwrap())
+			.sum()
+	}
+
+	#[test]
+	fn test_subspace_polys_vanish_on_subspace() {
+		let basis = NovelPolyBasis::<BinaryField16b>::new(6).unwrap();
+		for i in 0..6 {
+			let coeffs = (0..=i)
+				.map(|m| basis.get_subspace_poly_coeff(i, m))
+				.collect::<Vec<_>>();
+			for index in 0..1 << i {
+				let point = subspace_point::<BinaryField16b>(index);
+				assert_eq!(evaluate_linearized(&coeffs, point), BinaryField16b::ZERO);
+			}
+			let beta_i = subspace_point::<BinaryField16b>(1 << i);
+			assert_eq!(evaluate_linearized(&coeffs, beta_i), BinaryField16b::ONE);
+		}
+	}
+
+	#[test]
+	fn test_novel_to_monomial_matches_ntt() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let log_n = 6;
+
+		let ntt = AdditiveNTTWithOTFCompute::<BinaryField16b>::new(log_n + 2).unwrap();
+		let basis = NovelPolyBasis::<BinaryField16b>::new(log_n + 2).unwrap();
+
+		let novel = repeat_with(|| <BinaryField16b as Field>::random(&mut rng))
+			.take(1 << log_n)
+			.collect::<Vec<_>>();
+		let mut monomial = novel.clone();
+		basis.novel_to_monomial(&mut monomial, 0).unwrap();
+
+		for coset in 0..4 {
+			let mut evals = novel.clone();
+			ntt.forward_transform_simple(&mut evals, coset).unwrap();
+			for (i, &eval) in evals.iter().enumerate() {
+				let point = subspace_point::<BinaryField16b>((coset as usize) << log_n | i);
+				assert_eq!(evaluate_monomial(&monomial, point), eval);
+			}
+		}
+	}
+
+	#[test]
+	fn test_roundtrip_with_domain_field() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let basis =
+			NovelPolyBasis::<AESTowerField8b>::with_domain_field::<BinaryField8b>(8).unwrap();
+
+		let data = repeat_with(|| <AESTowerField8b as Field>::random(&mut rng))
+			.take(1 << 8)
+			.collect::<Vec<_>>();
+		let mut result = data.clone();
+		basis.monomial_to_novel(&mut result, 0).unwrap();
+		assert_ne!(result, data);
+		basis.novel_to_monomial(&mut result, 0).unwrap();
+		assert_eq!(result, data);
+	}
+
+	#[test]
+	fn test_packed_conversion_matches_scalars() {
+		type Packed = PackedBinaryField16x8b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let basis = NovelPolyBasis::<BinaryField8b>::new(8).unwrap();
+
+		for log_batch_size in [0, 2, 4, 5] {
+			let mut data = repeat_with(|| Packed::random(&mut rng))
+				.take(1 << 4)
+				.collect::<Vec<_>>();
+			let mut expected = data.clone();
+
+			// Convert every interleaved polynomial of the batch separately.
+			let scalars = Packed::unpack_scalars_mut(&mut expected);
+			let batch_size = 1 << log_batch_size;
+			for batch_index in 0..batch_size {
+				let mut poly = scalars
+					.iter()
+					.skip(batch_index)
+					.step_by(batch_size)
+					.copied()
+					.collect::<Vec<_>>();
+				basis.novel_to_monomial(&mut poly, 0).unwrap();
+				for (i, coeff) in poly.into_iter().enumerate() {
+					scalars[i * batch_size + batch_index] = coeff;
+				}
+			}
+
+			basis.novel_to_monomial(&mut data, log_batch_size).unwrap();
+			assert_eq!(data, expected);
+
+			basis.monomial_to_novel(&mut data, log_batch_size).unwrap();
+			basis
+				.monomial_to_novel(&mut expected, log_batch_size)
+				.unwrap();
+			assert_eq!(data, expected);
+		}
+	}
+
+	#[test]
+	fn test_extension_conversion_matches_ntt() {
+		type Packed = PackedBinaryField4x32b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = AdditiveNTTWithOTFCompute::<BinaryField8b>::new(8).unwrap();
+		let basis = NovelPolyBasis::<BinaryField8b>::new(8).unwrap();
+
+		let monomial = repeat_with(|| Packed::random(&mut rng))
+			.take(1 << 4)
+			.collect::<Vec<_>>();
+
+		// monomial -> novel -> evaluations
+		let mut novel = monomial.clone();
+		BasisConversion::<PackedBinaryField16x8b>::monomial_to_novel_ext(&basis, &mut novel)
+			.unwrap();
+		let mut evals = Packed::unpack_scalars(&novel).to_vec();
+		ntt.forward_transform_simple(&mut evals, 0).unwrap();
+
+		let monomial_scalars = Packed::unpack_scalars(&monomial);
+		for (i, &eval) in evals.iter().enumerate() {
+			let point = BinaryField32b::from(subspace_point::<BinaryField8b>(i));
+			assert_eq!(evaluate_monomial(monomial_scalars, point), eval);
+		}
+	}
+
+	#[test]
+	fn test_conversion_fails_with_domain_too_small() {
+		let basis = NovelPolyBasis::<BinaryField8b>::new(4).unwrap();
+		let mut data = vec![BinaryField8b::ZERO; 1 << 5];
+		assert_eq!(
+			basis.novel_to_monomial(&mut data, 0),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 5
+			})
+		);
+	}
+}
@@ -9,6 +9,7 @@ use binius_core::{
 		EvaluationDomainFactory, IsomorphicEvaluationDomainFactory, MultilinearComposite,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		greedy_evalcheck::{self, GreedyEvalcheckProof, GreedyEvalcheckProveOutput},
 		zerocheck::{
 			self, ZerocheckBatchProof, ZerocheckBatchProveOutput, ZerocheckClaim, ZerocheckProver,
@@ -104,13 +105,14 @@ where
 		zerocheck_claim,
 		zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	);
 
 	let ZerocheckBatchProveOutput {
 		evalcheck_claims,
 		proof: zerocheck_proof,
-	} = zerocheck::batch_prove(zerocheck_prover, &mut challenger)?;
+	} = zerocheck::batch_prove(zerocheck_prover, BatchBindingOrder::BackLoaded, &mut challenger)?;
 
 	// Prove evaluation claims
 	let GreedyEvalcheckProveOutput {
@@ -190,8 +192,12 @@ where
 	// Run zerocheck protocol
 	let zerocheck_claim = ZerocheckClaim { poly: constraint };
 
-	let evalcheck_claims =
-		zerocheck::batch_verify([zerocheck_claim], zerocheck_proof, &mut challenger)?;
+	let evalcheck_claims = zerocheck::batch_verify(
+		[zerocheck_claim],
+		zerocheck_proof,
+		BatchBindingOrder::BackLoaded,
+		&mut challenger,
+	)?;
 
 	// Verify evaluation claims
 	let same_query_claims =
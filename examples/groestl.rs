@@ -25,6 +25,7 @@ use binius_core::{
 		IsomorphicEvaluationDomainFactory, MultilinearComposite, MultilinearExtension,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		greedy_evalcheck::{self, GreedyEvalcheckProof, GreedyEvalcheckProveOutput},
 		zerocheck::{
 			self, ZerocheckBatchProof, ZerocheckBatchProveOutput, ZerocheckClaim, ZerocheckProver,
@@ -787,13 +788,14 @@ where
 		zerocheck_claim,
 		zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	);
 
 	let ZerocheckBatchProveOutput {
 		evalcheck_claims,
 		proof: zerocheck_proof,
-	} = zerocheck::batch_prove(zerocheck_prover, &mut challenger)?;
+	} = zerocheck::batch_prove(zerocheck_prover, BatchBindingOrder::BackLoaded, &mut challenger)?;
 
 	// Evalcheck
 	let GreedyEvalcheckProveOutput {
@@ -889,8 +891,12 @@ where
 		)?,
 	};
 
-	let evalcheck_claims =
-		zerocheck::batch_verify([zerocheck_claim], zerocheck_proof, &mut challenger)?;
+	let evalcheck_claims = zerocheck::batch_verify(
+		[zerocheck_claim],
+		zerocheck_proof,
+		BatchBindingOrder::BackLoaded,
+		&mut challenger,
+	)?;
 
 	// Evalcheck
 	let same_query_claims =
@@ -18,6 +18,7 @@ use binius_core::{
 		MultivariatePoly,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		greedy_evalcheck::{self, GreedyEvalcheckProof, GreedyEvalcheckProveOutput},
 		zerocheck::{
 			self, ZerocheckBatchProof, ZerocheckBatchProveOutput, ZerocheckClaim, ZerocheckProver,
@@ -513,13 +514,14 @@ where
 		zerocheck_claim,
 		zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	);
 
 	let ZerocheckBatchProveOutput {
 		evalcheck_claims,
 		proof: zerocheck_proof,
-	} = zerocheck::batch_prove(zerocheck_prover, &mut challenger)?;
+	} = zerocheck::batch_prove(zerocheck_prover, BatchBindingOrder::BackLoaded, &mut challenger)?;
 
 	// Evalcheck
 	let GreedyEvalcheckProveOutput {
@@ -595,8 +597,12 @@ where
 		poly: CompositePolyOracle::new(log_size, zerocheck_column_oracles, mix_composition)?,
 	};
 
-	let evalcheck_claims =
-		zerocheck::batch_verify([zerocheck_claim], zerocheck_proof, &mut challenger)?;
+	let evalcheck_claims = zerocheck::batch_verify(
+		[zerocheck_claim],
+		zerocheck_proof,
+		BatchBindingOrder::BackLoaded,
+		&mut challenger,
+	)?;
 
 	// Evalcheck
 	let same_query_claims =
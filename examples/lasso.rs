@@ -9,6 +9,7 @@ use binius_core::{
 		EvaluationDomainFactory, IsomorphicEvaluationDomainFactory, MultilinearExtension,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		greedy_evalcheck::{self, GreedyEvalcheckProof},
 		lasso::{self, LassoBatch, LassoClaim, LassoWitness},
 		msetcheck, prodcheck,
@@ -352,6 +353,7 @@ where
 		lasso_zerocheck_claim,
 		lasso_zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	)?;
 
@@ -374,6 +376,7 @@ where
 		prodcheck_zerocheck_claim,
 		prodcheck_zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	)?;
 
@@ -382,7 +385,8 @@ where
 		lasso_zerocheck_prover.to_arc_dyn(),
 	];
 
-	let zerocheck_prove_output = zerocheck::batch_prove(provers, &mut challenger)?;
+	let zerocheck_prove_output =
+		zerocheck::batch_prove(provers, BatchBindingOrder::BackLoaded, &mut challenger)?;
 
 	// Greedy Evalcheck
 
@@ -524,6 +528,7 @@ where
 			reduced_lasso_claims.zerocheck_claim,
 		],
 		proof.zerocheck_proof,
+		BatchBindingOrder::BackLoaded,
 		&mut challenger,
 	)
 	.unwrap();
@@ -13,6 +13,7 @@ use binius_core::{
 		MultilinearComposite,
 	},
 	protocols::{
+		abstract_sumcheck::BatchBindingOrder,
 		greedy_evalcheck::{self, GreedyEvalcheckProof, GreedyEvalcheckProveOutput},
 		zerocheck::{
 			self, ZerocheckBatchProof, ZerocheckBatchProveOutput, ZerocheckClaim, ZerocheckProver,
@@ -224,13 +225,14 @@ where
 		zerocheck_claim,
 		zerocheck_witness,
 		&zc_challenges,
+		BatchBindingOrder::BackLoaded,
 		switchover_fn,
 	);
 
 	let ZerocheckBatchProveOutput {
 		evalcheck_claims,
 		proof: zerocheck_proof,
-	} = zerocheck::batch_prove(zerocheck_prover, &mut challenger)?;
+	} = zerocheck::batch_prove(zerocheck_prover, BatchBindingOrder::BackLoaded, &mut challenger)?;
 
 	// Prove evaluation claims
 	let GreedyEvalcheckProveOutput {
@@ -311,8 +313,12 @@ where
 		poly: CompositePolyOracle::new(log_size, zerocheck_column_oracles, mix_composition)?,
 	};
 
-	let evalcheck_claims =
-		zerocheck::batch_verify([zerocheck_claim], zerocheck_proof, &mut challenger)?;
+	let evalcheck_claims = zerocheck::batch_verify(
+		[zerocheck_claim],
+		zerocheck_proof,
+		BatchBindingOrder::BackLoaded,
+		&mut challenger,
+	)?;
 
 	// Evalcheck
 	let same_query_claims =